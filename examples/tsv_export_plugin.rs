@@ -0,0 +1,61 @@
+// An example export plugin that writes the table as tab-separated values.
+//
+// Build it with `cargo build --example tsv_export_plugin --target wasm32-unknown-unknown --release`
+// and copy `target/wasm32-unknown-unknown/release/examples/tsv_export_plugin.wasm` into the plugins
+// directory (`<config dir>/delay_time_calculator/plugins`).
+
+use serde_json::{json, Value};
+
+// Buffers handed to the app are never freed; each call gets a fresh instance anyway
+fn leak(bytes: Vec<u8>) -> u64 {
+    let bytes = bytes.leak();
+    ((bytes.as_ptr() as u64) << 32) | bytes.len() as u64
+}
+
+#[no_mangle]
+pub extern "C" fn alloc(len: u32) -> *mut u8 {
+    vec![0; len as usize].leak().as_mut_ptr()
+}
+
+#[no_mangle]
+pub extern "C" fn describe() -> u64 {
+    leak(
+        json!({ "name": "Tab-separated values", "extension": "tsv" })
+            .to_string()
+            .into_bytes(),
+    )
+}
+
+/// # Safety
+///
+/// `ptr` and `len` must describe a buffer from `alloc`, as the app passes them.
+#[no_mangle]
+pub unsafe extern "C" fn export(ptr: *const u8, len: u32) -> u64 {
+    let input = std::slice::from_raw_parts(ptr, len as usize);
+    let table: Value = serde_json::from_slice(input).unwrap_or_default();
+
+    let unit = table["unit"].as_str().unwrap_or_default();
+    let mut tsv = "Note".to_string();
+
+    for modifier in table["modifiers"].as_array().into_iter().flatten() {
+        tsv.push_str(&format!(
+            "\t{} ({})",
+            modifier.as_str().unwrap_or_default(),
+            unit
+        ));
+    }
+
+    tsv.push('\n');
+
+    for row in table["rows"].as_array().into_iter().flatten() {
+        tsv.push_str(row["note"].as_str().unwrap_or_default());
+
+        for value in row["values"].as_array().into_iter().flatten() {
+            tsv.push_str(&format!("\t{}", value));
+        }
+
+        tsv.push('\n');
+    }
+
+    leak(tsv.into_bytes())
+}
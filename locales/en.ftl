@@ -0,0 +1,482 @@
+# English, the base language. Every ID used in the app is defined here; translations fall back to
+# these for anything they leave out.
+
+## Table
+modifier-normal = Normal
+modifier-dotted = Dotted
+modifier-triplet = Triplet
+modifier-tuplet = Tuplet { $notes }:{ $in-time-of }
+note-quadruple-whole = Quadruple whole
+note-double-whole = Double whole
+note-whole = Whole
+note-half = Half
+note-quarter = Quarter
+note-eighth = Eighth
+note-sixteenth = Sixteenth
+note-thirty-second = Thirty-second
+note-sixty-fourth = Sixty-fourth
+note-hundred-twenty-eighth = Hundred twenty-eighth
+note-longa = Longa
+note-breve = Breve
+note-semibreve = Semibreve
+note-minim = Minim
+note-crotchet = Crotchet
+note-quaver = Quaver
+note-semiquaver = Semiquaver
+note-demisemiquaver = Demisemiquaver
+note-hemidemisemiquaver = Hemidemisemiquaver
+note-semihemidemisemiquaver = Semihemidemisemiquaver
+haas-zone-tooltip = In the Haas zone, so it widens rather than echoes
+
+## Tempo controls
+tap = Tap
+tap-tooltip = Tap (T)
+reset = Reset
+reset-tooltip = Reset taps (R)
+tap-window-tooltip = Taps averaged into the tempo
+decrease-tempo-tooltip = Decrease tempo (↓, Shift ×5, Alt ÷10)
+increase-tempo-tooltip = Increase tempo (↑, Shift ×5, Alt ÷10)
+halve = Halve
+halve-tooltip = Halve tempo (1)
+double = Double
+double-tooltip = Double tempo (2)
+round-tempo = Round
+round-tempo-tooltip = Round tempo to a whole BPM
+milliseconds-tooltip = Milliseconds (M)
+hertz-tooltip = Hertz (H)
+ticks-tooltip = Sequencer ticks
+custom-unit-tooltip = Your own unit, defined in Preferences
+both-units = ms + Hz
+both-units-tooltip = Milliseconds with Hertz alongside
+ppqn-tooltip = Ticks per quarter note (PPQN)
+samples-tooltip = Audio samples
+sample-rate-tooltip = Sample rate (Hz)
+both-sample-rates = 44.1k + 48k
+both-sample-rates-tooltip = In samples, show the values at 44.1 kHz and 48 kHz side by side, for sessions that mix music and video rates
+time-signature-tooltip = Time signature
+tuplet-placeholder = Tuplet
+tuplet-tooltip = Custom tuplet column, e.g. 5:4 or 7:8
+slider-tooltip = Hold Shift for fine steps
+averaging-taps = Averaging { $used } of { $total } taps
+ignoring-interval = , ignoring 1 off-beat interval
+ignoring-intervals = , ignoring { $count } off-beat intervals
+tap-sparkline-tooltip = The tempo of each tap interval, newest on the right; a slope means you're speeding up or slowing down
+tap-ignored = Tap ignored: too far off the beat
+tap-calibration-heading = Tap latency calibration
+tap-calibration-instructions = Tap along with the pulse, with the button or the T key. { $remaining } taps to go.
+tap-calibration-finished = Tap latency offset set to { $offset } ms
+invalid-tempo = Enter a number like 120 or 93.5, math like 174/2, or a quarter note like 500ms or 2Hz
+tempo-limited = Tempo limited to the { $min }–{ $max } BPM range
+octave-suggestion = { $tempo } – did you mean { $suggestion }?
+
+## Tempo sources
+tempos-in-file = Tempos in file:
+tempo-at-beat = { $tempo } BPM at beat { $beat }
+dismiss = Dismiss
+use-tempo = Use { $tempo } BPM
+from-clipboard = from the clipboard
+no-tempo-in-file = No tempo found in { $file }
+cannot-read-file = Couldn't read { $file }: { $error }
+
+## Clipboard and export
+copied = Copied { $what }
+copy-failed = Copy failed: { $error }
+copied-with-drift = { $value }, which is { $drift } ms off the exact delay
+rounding-drift-notice = Rounding puts some { $unit } values up to { $drift } ms off their exact delays. Copy them exactly from Preferences.
+clipboard-unavailable = Clipboard unavailable ({ $error }). The next copy will try again.
+export-failed = Export failed: { $error }
+session = Session
+session-notes = Session notes
+save-session = Save session…
+open-session = Open session…
+export-csv = Export CSV (½×, 1×, 2×)
+export-image = Export image…
+export-preset = Export preset…
+export-preset-tooltip = Write the last clicked cell into a plugin preset template
+export-with-plugin = Export with plugin…
+menu-copy-value = Copy value
+menu-copy-with-unit = Copy with unit
+menu-copy-seconds = Copy as seconds
+menu-copy-full-precision = Copy full precision
+menu-copy-normalized = Copy as automation value (0–1)
+normalized-value = automation value { $value }
+menu-copy-row = Copy row
+menu-copy-column = Copy column
+menu-set-active = Set as active cell
+copied-column = { $modifier } column
+copied-row = { $note } row
+copied-left-right = left and right times
+copied-groove-offsets = groove offsets
+copied-tap-times = tap times
+copied-echo-times = echo times
+image-title = Delay times at { $tempo } BPM ({ $unit })
+
+## Footer
+update-available = Version { $version } is available
+ask-check-for-updates = Check GitHub for a newer release at each launch?
+yes = Yes
+no = No
+release-notes = Release notes
+release-notes-heading = What's new in { $version }
+no-release-notes = This release has no notes.
+copy-release-link = Copy download link
+release-link = Download link
+preferences-tooltip = Preferences (Ctrl+, or ⌘,)
+history = History
+hide-history = Hide history
+note-glyphs = Note glyphs
+ratios = Ratios
+ratios-tooltip = Show each value as a multiple of a quarter note
+pulse = Pulse
+pulse-tooltip = Blink on every beat of the current tempo
+compare = Compare ½× / 2×
+compare-tooltip = Show half- and double-time tables beside this tempo without changing it
+compare-heading = { $factor } · { $tempo } BPM
+showing-tempo-slot = Showing tempo { $slot }
+tempo-slot = Tempo { $slot }
+switch-tempo-slot = Switch to { $slot }
+switch-tempo-slot-tooltip = Swap the table over to the other tempo, keeping this one in its place
+tempo-deltas = Deltas
+tempo-deltas-tooltip = Show how far each cell is from its value at tempo { $slot }
+auto-copy = Auto-copy
+auto-copy-tooltip = Re-copy the highlighted cell whenever the tempo changes; click a cell to choose it
+watch-clipboard = Watch clipboard
+watch-clipboard-tooltip = Offer tempos copied in other apps
+transpose = Transpose
+transpose-tooltip = Note values across, modifiers down
+pin = Pin
+unpin = Unpin
+pin-tooltip = Keep window on top (P)
+stage = Stage
+stage-tooltip = Full-screen tempo and chosen values (S)
+mini = Mini
+mini-tooltip = Collapse to a small floating strip
+expand = Expand
+leave-stage = S or Esc to leave
+tap-pad = Tap pad
+tap-pad-tooltip = Turn the window into one big tap target
+leave-tap-pad = Tap anywhere above. Esc to leave
+color-none = No tag
+color-red = Red
+color-orange = Orange
+color-yellow = Yellow
+color-green = Green
+color-blue = Blue
+color-purple = Purple
+
+## Preferences
+export-settings = Export settings…
+export-settings-tooltip = Save these preferences and your preset templates to one file, to set up another machine the same way
+import-settings = Import settings…
+settings-exported = Settings exported
+settings-imported = Settings imported
+templates-not-imported = Settings imported, but { $count } preset templates couldn't be saved
+preferences = Preferences
+done = Done
+cancel = Cancel
+theme = Theme
+language = Language
+ui-scale = Interface size
+window-opacity = Window opacity
+window-opacity-tooltip = Lets what is behind the window show through, e.g. while it stays on top of a DAW
+default-unit = Default unit
+custom-unit = Custom unit
+custom-unit-name = Name
+custom-unit-definition-tooltip = A name, and the milliseconds or Hertz the unit is a multiple of
+decimal-places = Decimal places
+note-labels = Note labels
+reset-taps = Reset taps
+ignore-taps = Ignore taps
+average-taps = Average taps
+tap-latency = Tap latency offset
+tap-latency-tooltip = Milliseconds taken off every interval between taps, for machines whose tapped tempos run slow
+calibrate = Calibrate
+startup-tempo = Startup tempo
+tempo-limits = Tempo limits
+tempo-limits-tooltip = Tempos typed, tapped, or received outside these are held at the nearest one
+nudge-buttons = Nudge buttons (BPM)
+automation-range = Automation range
+automation-range-tooltip = The plugin parameter's range, for copying delay times as 0–1 automation values
+scale-linear = Linear
+scale-logarithmic = Logarithmic
+preferred-range = Preferred tempos
+preferred-range-tooltip = Tapped tempos outside this range get a half- or double-time suggestion
+millisecond-notes = Always in milliseconds
+millisecond-notes-tooltip = These note values stay in ms in Hertz mode, e.g. for pre-delay
+stage-values = Stage mode values
+column-colors = Column colors
+color-by-range = Color cells by delay range
+color-by-range-tooltip = Purple up to 35 ms (Haas), orange up to 150 ms (slapback), blue beyond (echo)
+exact-copies = Copy exact values
+exact-copies-tooltip = Copies skip the table's rounding, so a pasted Hz value still gives the right delay
+decimal-separator = Decimal separator
+decimal-separator-tooltip = Used in copied numbers, for plugins and spreadsheets that expect a comma
+decimal-separator-dot = Dot (1.5)
+decimal-separator-comma = Comma (1,5)
+check-for-updates = Check for updates
+check-for-updates-tooltip = Look for a newer release on GitHub at launch
+keyboard-shortcuts = Keyboard shortcuts
+theme-system = System
+
+## Keyboard shortcuts
+key-tap = Tap
+key-reset = Reset taps
+key-nudge = Nudge tempo (Shift ×5, Alt ÷10)
+key-halve-double = Halve / double tempo
+key-units = Milliseconds / Hertz
+key-pin = Keep window on top
+key-stage = Stage mode
+key-focus = Next / previous text field
+key-scroll = Nudge tempo
+key-preferences = Preferences
+key-escape = Close menus and preferences, or leave stage mode
+keys-scroll = Scroll on tempo
+
+## Tap settings
+tap-window-last = Last { $count } taps
+tap-window-all = All taps
+tap-timeout-never = Never
+tap-timeout-seconds = After { $seconds } s
+tap-tolerance-off = Never
+tap-tolerance-percent = Off by more than { $percent }%
+tap-averaging-mean = Evenly
+tap-averaging-weighted = Favoring recent taps
+
+## Tools
+tool-none = No tool
+tool-speaker-alignment = Speaker alignment
+tool-timecode-chase = Timecode chase
+tool-midi-tap = MIDI tap
+tool-audio-tap = Audio tap
+tool-feedback-decay = Feedback decay
+tool-ping-pong = Ping-pong
+tool-midi-clock = MIDI clock
+tool-section-length = Section length
+tool-lfo-rates = LFO rates
+tool-haas = Haas offsets
+tool-comb-tuning = Comb filter tuning
+tool-buffer-latency = Buffer latency
+tool-tempo-match = Tempo match
+tool-groove-offsets = Groove offsets
+tool-tempo-ramp = Tempo ramp
+tool-multitap = Multitap
+tool-sidechain-pump = Sidechain pump
+tool-loop-length = Loop length to BPM
+tool-polyrhythm = Polyrhythm
+tool-flam-offsets = Flam offsets
+tool-count-in = Count-in
+tool-turntable-pitch = Turntable pitch
+tool-envelope-times = Gate and envelope times
+tool-echo-schedule = Echo schedule
+tool-copy = Copy
+tool-refresh = Refresh
+tool-disconnect = Disconnect
+tool-use-current = Use current
+tool-needs-feature = { $tool } needs a build with the `{ $feature }` feature enabled
+tool-delay-ms = Delay (ms)
+tool-sample-rate = Sample rate (Hz)
+tool-load = Load
+
+## Speaker alignment
+speed-of-sound = Speed of sound: { $speed } m/s
+distance = Distance
+temperature-celsius = Temp (°C)
+
+## Feedback decay
+use-quarter-note = Use 1/4
+feedback-percent = Feedback (%)
+feedback-decay-beats = { $beats } beats
+feedback-decay-result = { $repeats } audible repeats, below { $threshold } dB after { $ms } ms{ $beats }
+feedback-out-of-range = Feedback must be between 0 and 100%
+feedback-decay-prompt = Enter a delay time and feedback amount
+
+## Ping-pong
+base-note = Base note
+haas-offset-ms = Haas offset (ms)
+ping-pong-prompt = Enter a tempo to see suggestions
+left-ms = L { $ms } ms
+right-ms = R { $ms } ms
+pair-ping-pong = Ping-pong
+pair-straight-dotted = Straight + dotted
+pair-straight-triplet = Straight + triplet
+pair-haas-widening = Haas widening
+pair-haas-spread = Haas spread
+
+## Section length
+bars = Bars
+in-time-signature = in { $time-signature }
+frame-rate = Frame rate
+section-duration = { $duration } ({ $frames } frames at { $fps } fps)
+bars-prompt = Enter a number of bars
+section-length-prompt = Enter a tempo to see the duration
+
+## LFO rates
+lfo-rates-prompt = Enter a tempo to see LFO rates
+fraction-of-bar = 1/{ $fraction } bar
+one-bar = 1 bar
+bar-count = { $bars } bars
+lfo-rates-heading = One LFO cycle per… (in { $time-signature })
+
+## Haas offsets
+haas-prompt = Enter a tempo to see Haas offsets
+haas-heading = Subdivisions within the { $min }–{ $max } ms Haas zone
+haas-none = None at this tempo
+
+## Timecode chase
+fps-drop-frame = 29.97 fps drop
+show-command-other = command { $byte }
+show-control-cue = cue { $cue }
+show-control-at = at { $time }
+mtc-msc-input = MTC / MSC input
+timecode = Timecode: { $timecode }
+timecode-waiting = Timecode: waiting for MTC…
+bar-and-beat = Bar { $bar }, beat { $beat }
+show-control = Show control: { $command }
+
+## MIDI tap
+midi-input = MIDI input
+trigger-learning = Trigger: press a pad or pedal…
+trigger = Trigger: { $trigger }
+learn = Learn
+trigger-note = Note { $note }
+
+## MIDI clock
+midi-output = MIDI output
+midi-clock-prompt = Select an output to send clock
+midi-clock-stopped = Sending clock, transport stopped
+midi-clock-running = Sending clock, transport running
+transport-start = Start
+transport-stop = Stop
+send-mtc = Also send MTC (25 fps)
+
+## Audio tap
+audio-input = Audio input
+listening-for-hits = Listening for hits
+
+## Comb filter tuning
+comb-pitch = { $hz } Hz, { $note } { $cents } cents
+delay-prompt = Enter a delay time
+comb-delay = { $hz } Hz, { $ms } ms
+note-prompt = Enter a note like A4, C#3, or Eb2
+note = Note
+
+## Buffer latency
+sample-count = { $samples } samples
+round-trip = { $ms } ms round trip
+nearest-straight-note = ≈ 1/{ $division } note ({ $percent }%)
+
+## Tempo match
+track-bpm = Track BPM
+target-bpm = Target BPM
+apply-target = Apply target
+tempo-match-prompt = Enter the track's tempo and the tempo to match
+pitch-shift = Pitch { $percent }%, or { $semitones } semitones with the tempo locked to pitch
+time-stretch = Stretch to { $percent }% of the original length (×{ $ratio })
+delay-times-at = Delay times at { $tempo } BPM
+
+## Groove offsets
+swing-percent = Swing (%)
+copy-csv = Copy CSV
+swing-range = Swing runs from 50% (straight) to just under 100%
+groove-offsets-prompt = Enter a tempo to see groove offsets
+sixteenth-step = 16th { $step }
+tick-offset = { $ticks } ticks
+
+## Tempo ramp
+ramp-from = From
+ramp-to = to
+ramp-bpm-over = BPM over
+ramp-bars-of = bars of { $time-signature }
+per-bar = Per bar
+per-beat = Per beat
+tempo-ramp-prompt = Enter start and end tempos and a number of bars
+ramp-bar-step = Bar { $step }: { $tempo }
+ramp-beat-step = Beat { $step }: { $tempo }
+total-duration = Total duration { $duration }
+
+## Multitap
+add-tap = Add tap
+hide-timeline = Hide timeline
+timeline = Timeline
+tap-time = at { $ms } ms ({ $samples } samples)
+tap-step = Tap { $tap }: { $note } { $modifier }
+remove = Remove
+multitap-prompt = Enter a tempo to see tap times
+
+## Sidechain pump
+pump-every = Pump every
+attack-ms = Attack (ms)
+sidechain-prompt = Enter a tempo to see envelope times
+attack-prompt = Enter an attack time
+attack-too-long = Attack is longer than the note
+volume-shaper-length = Volume shaper length
+release-next-note = Release, back on the next note
+release-halfway = Release, back halfway
+
+## Loop length
+loop-length = Loop length
+loop-holding = holding
+length-beats = beats
+length-bars = bars
+loop-tempo = { $tempo } BPM
+loop-tempo-in = { $tempo } BPM in { $time-signature }
+loop-length-prompt = Enter the loop's duration and how many beats or bars it holds
+
+## Polyrhythm
+polyrhythm = Polyrhythm
+polyrhythm-prompt = Enter a tempo to see the streams
+polyrhythm-ratio-prompt = Enter a ratio like 3:2 or 5:4
+stream-notes = { $count } notes
+polyrhythm-cycle = { $notes } notes against { $beats } beats, repeating every { $ms } ms
+
+## Flam offsets
+flam-offsets-heading = Short offsets for flams and grace notes (in { $time-signature })
+flam-offsets-prompt = Enter a tempo to see offsets in bars
+offset-fixed = Fixed
+offset-humanization = Humanization
+offset-flams = Flams
+offset-strum-spreads = Strum spreads
+
+## Count-in
+count-in-heading = Count-in in { $time-signature }
+count-in-prompt = Enter a tempo to see the count-in
+frame-count = { $frames } frames
+click-count = { $clicks } clicks
+
+## Turntable pitch
+turntable-record-at = Record at
+turntable-pitched = pitched
+turntable-plays-at = Plays at { $tempo } BPM
+turntable-out-of-range = The pitch fader only reaches { $range }
+turntable-prompt = Enter the record's tempo and the pitch fader's setting
+turntable-match = Set the fader to { $pitch }% to match { $tempo } BPM
+turntable-beyond-range = Matching { $tempo } BPM takes { $pitch }%, beyond the { $range } fader
+turntable-match-prompt = Enter a tempo to see the pitch that matches it
+
+## Gate and envelope times
+envelope-times-prompt = Enter a tempo to see gate and release times
+gate-lengths = Gate lengths
+release-times = Release times
+gate-stutters = Stutters
+gate-tight = Tight gating
+gate-choppy = Choppy gating
+gate-pumping = Pumping gates
+release-plucks = Plucks
+release-stabs = Stabs
+release-leads = Leads
+release-pads = Pads
+release-swells = Swells
+
+## Echo schedule
+echo-repeats = Repeats
+echo-repeat = Repeat { $repeat }
+echo-repeats-prompt = Enter between 1 and { $max } repeats
+echo-schedule-prompt = Enter a tempo to see when each repeat lands
+echo-schedule-heading = Measured from a throw at 1:1, in bars:beats of { $time-signature }
+
+## Tray
+tray-show-window = Show window
+tray-quick-tempo = Quick tempo…
+tray-nothing-copied = Nothing copied yet
+tray-latest = Latest: { $value }
+tray-quit = Quit
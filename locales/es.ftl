@@ -0,0 +1,482 @@
+# Spanish
+
+## Table
+modifier-normal = Normal
+modifier-dotted = Con puntillo
+modifier-triplet = Tresillo
+modifier-tuplet = Grupillo { $notes }:{ $in-time-of }
+# Spanish has one set of names, so both systems use it
+note-quadruple-whole = Longa
+note-double-whole = Cuadrada
+note-whole = Redonda
+note-half = Blanca
+note-quarter = Negra
+note-eighth = Corchea
+note-sixteenth = Semicorchea
+note-thirty-second = Fusa
+note-sixty-fourth = Semifusa
+note-hundred-twenty-eighth = Garrapatea
+note-longa = Longa
+note-breve = Cuadrada
+note-semibreve = Redonda
+note-minim = Blanca
+note-crotchet = Negra
+note-quaver = Corchea
+note-semiquaver = Semicorchea
+note-demisemiquaver = Fusa
+note-hemidemisemiquaver = Semifusa
+note-semihemidemisemiquaver = Garrapatea
+haas-zone-tooltip = En la zona Haas, así que ensancha en vez de sonar como eco
+
+## Tempo controls
+tap = Pulsar
+tap-tooltip = Pulsar (T)
+reset = Reiniciar
+reset-tooltip = Reiniciar pulsaciones (R)
+tap-window-tooltip = Pulsaciones promediadas en el tempo
+decrease-tempo-tooltip = Bajar el tempo (↓, Mayús ×5, Alt ÷10)
+increase-tempo-tooltip = Subir el tempo (↑, Mayús ×5, Alt ÷10)
+halve = Mitad
+halve-tooltip = Tempo a la mitad (1)
+double = Doble
+double-tooltip = Tempo al doble (2)
+round-tempo = Redondear
+round-tempo-tooltip = Redondear el tempo a un BPM entero
+milliseconds-tooltip = Milisegundos (M)
+hertz-tooltip = Hercios (H)
+custom-unit-tooltip = Tu propia unidad, definida en Preferencias
+both-units = ms + Hz
+both-units-tooltip = Milisegundos con hercios al lado
+ticks-tooltip = Ticks del secuenciador
+ppqn-tooltip = Ticks por negra (PPQN)
+samples-tooltip = Muestras de audio
+sample-rate-tooltip = Frecuencia de muestreo (Hz)
+both-sample-rates = 44.1k + 48k
+both-sample-rates-tooltip = En muestras, mostrar los valores a 44,1 kHz y 48 kHz lado a lado, para sesiones que mezclan frecuencias de música y vídeo
+time-signature-tooltip = Compás
+tuplet-placeholder = Grupillo
+tuplet-tooltip = Columna de grupillo propia, p. ej. 5:4 o 7:8
+slider-tooltip = Mantén Mayús para pasos finos
+averaging-taps = Promediando { $used } de { $total } pulsaciones
+ignoring-interval = , ignorando 1 intervalo fuera de tiempo
+ignoring-intervals = , ignorando { $count } intervalos fuera de tiempo
+tap-sparkline-tooltip = El tempo de cada intervalo entre pulsaciones, el más reciente a la derecha; una pendiente indica que aceleras o frenas
+tap-ignored = Pulsación ignorada: muy fuera de tiempo
+tap-calibration-heading = Calibración de la latencia de pulsación
+tap-calibration-instructions = Pulsa al ritmo del pulso, con el botón o la tecla T. Faltan { $remaining } pulsaciones.
+tap-calibration-finished = Compensación de latencia fijada en { $offset } ms
+invalid-tempo = Escribe un número como 120 o 93.5, una operación como 174/2 o una negra como 500ms o 2Hz
+tempo-limited = Tempo limitado al rango de { $min }–{ $max } BPM
+octave-suggestion = { $tempo } – ¿querías decir { $suggestion }?
+
+## Tempo sources
+tempos-in-file = Tempos en el archivo:
+tempo-at-beat = { $tempo } BPM en el tiempo { $beat }
+dismiss = Descartar
+use-tempo = Usar { $tempo } BPM
+from-clipboard = del portapapeles
+no-tempo-in-file = No se encontró tempo en { $file }
+cannot-read-file = No se pudo leer { $file }: { $error }
+
+## Clipboard and export
+copied = Copiado: { $what }
+copy-failed = Error al copiar: { $error }
+copied-with-drift = { $value }, a { $drift } ms del retardo exacto
+rounding-drift-notice = El redondeo aleja algunos valores en { $unit } hasta { $drift } ms de su retardo exacto. Cópialos exactos desde Preferencias.
+clipboard-unavailable = Portapapeles no disponible ({ $error }). Se volverá a intentar en la próxima copia.
+export-failed = Error al exportar: { $error }
+session = Sesión
+session-notes = Notas de la sesión
+save-session = Guardar sesión…
+open-session = Abrir sesión…
+export-csv = Exportar CSV (½×, 1×, 2×)
+export-image = Exportar imagen…
+export-preset = Exportar preset…
+export-preset-tooltip = Escribir la última celda pulsada en una plantilla de preset
+export-with-plugin = Exportar con plugin…
+menu-copy-value = Copiar valor
+menu-copy-with-unit = Copiar con unidad
+menu-copy-seconds = Copiar en segundos
+menu-copy-normalized = Copiar como valor de automatización (0–1)
+normalized-value = valor de automatización { $value }
+menu-copy-full-precision = Copiar con precisión completa
+menu-copy-row = Copiar fila
+menu-copy-column = Copiar columna
+menu-set-active = Usar como celda activa
+copied-column = columna { $modifier }
+copied-row = fila { $note }
+copied-left-right = tiempos izquierdo y derecho
+copied-groove-offsets = desfases de groove
+copied-tap-times = tiempos de los taps
+copied-echo-times = tiempos de eco
+image-title = Tiempos de retardo a { $tempo } BPM ({ $unit })
+
+## Footer
+update-available = La versión { $version } está disponible
+ask-check-for-updates = ¿Buscar una versión nueva en GitHub en cada inicio?
+yes = Sí
+no = No
+release-notes = Notas de la versión
+release-notes-heading = Novedades de { $version }
+no-release-notes = Esta versión no tiene notas.
+copy-release-link = Copiar enlace de descarga
+release-link = Enlace de descarga
+preferences-tooltip = Preferencias (Ctrl+, o ⌘,)
+history = Historial
+hide-history = Ocultar historial
+note-glyphs = Figuras
+ratios = Proporciones
+ratios-tooltip = Mostrar cada valor como múltiplo de una negra
+pulse = Pulso
+pulse-tooltip = Parpadear en cada pulso del tempo actual
+compare = Comparar ½× / 2×
+compare-tooltip = Mostrar tablas a mitad y al doble de tempo junto a este tempo sin cambiarlo
+compare-heading = { $factor } · { $tempo } BPM
+showing-tempo-slot = Mostrando el tempo { $slot }
+tempo-slot = Tempo { $slot }
+switch-tempo-slot = Cambiar a { $slot }
+switch-tempo-slot-tooltip = Pasar la tabla al otro tempo, guardando este en su lugar
+tempo-deltas = Diferencias
+tempo-deltas-tooltip = Mostrar cuánto se aleja cada celda de su valor en el tempo { $slot }
+auto-copy = Copia automática
+auto-copy-tooltip = Volver a copiar la celda resaltada cada vez que cambie el tempo; haz clic en una celda para elegirla
+watch-clipboard = Vigilar portapapeles
+watch-clipboard-tooltip = Ofrecer tempos copiados en otras aplicaciones
+transpose = Trasponer
+transpose-tooltip = Figuras en columnas, modificadores en filas
+pin = Fijar
+unpin = Soltar
+pin-tooltip = Mantener la ventana encima (P)
+stage = Escenario
+stage-tooltip = Tempo y valores elegidos a pantalla completa (S)
+mini = Mini
+mini-tooltip = Reducir a una pequeña barra flotante
+expand = Ampliar
+leave-stage = S o Esc para salir
+tap-pad = Pad de tap
+tap-pad-tooltip = Convertir la ventana en un gran botón de tap
+leave-tap-pad = Pulsa en cualquier parte de arriba. Esc para salir
+color-none = Sin etiqueta
+color-red = Rojo
+color-orange = Naranja
+color-yellow = Amarillo
+color-green = Verde
+color-blue = Azul
+color-purple = Morado
+
+## Preferences
+export-settings = Exportar ajustes…
+export-settings-tooltip = Guardar estas preferencias y tus plantillas de presets en un archivo, para configurar otro equipo igual
+import-settings = Importar ajustes…
+settings-exported = Ajustes exportados
+settings-imported = Ajustes importados
+templates-not-imported = Ajustes importados, pero no se pudieron guardar { $count } plantillas de presets
+preferences = Preferencias
+done = Listo
+cancel = Cancelar
+theme = Tema
+language = Idioma
+ui-scale = Tamaño de la interfaz
+window-opacity = Opacidad de la ventana
+window-opacity-tooltip = Deja ver lo que hay detrás de la ventana, por ejemplo mientras está encima de un DAW
+default-unit = Unidad predeterminada
+custom-unit = Unidad personalizada
+custom-unit-name = Nombre
+custom-unit-definition-tooltip = Un nombre, y los milisegundos o hercios de los que la unidad es múltiplo
+decimal-places = Decimales
+note-labels = Nombres de figuras
+reset-taps = Reiniciar pulsaciones
+ignore-taps = Ignorar pulsaciones
+average-taps = Promediar pulsaciones
+tap-latency = Compensación de latencia
+tap-latency-tooltip = Milisegundos que se restan a cada intervalo entre pulsaciones, para equipos cuyos tempos pulsados salen lentos
+calibrate = Calibrar
+startup-tempo = Tempo inicial
+tempo-limits = Límites de tempo
+tempo-limits-tooltip = Los tempos escritos, marcados o recibidos fuera de estos se quedan en el más cercano
+nudge-buttons = Botones de ajuste (BPM)
+automation-range = Rango de automatización
+automation-range-tooltip = El rango del parámetro del plugin, para copiar tiempos como valores de automatización de 0–1
+scale-linear = Lineal
+scale-logarithmic = Logarítmica
+preferred-range = Tempos preferidos
+preferred-range-tooltip = Los tempos marcados fuera de este rango reciben una sugerencia a mitad o al doble
+millisecond-notes = Siempre en milisegundos
+millisecond-notes-tooltip = Estas figuras siguen en ms en modo hercios, p. ej. para el pre-delay
+stage-values = Valores del modo escenario
+column-colors = Colores de columna
+color-by-range = Colorear celdas por rango de retardo
+color-by-range-tooltip = Morado hasta 35 ms (Haas), naranja hasta 150 ms (slapback), azul a partir de ahí (eco)
+exact-copies = Copiar valores exactos
+exact-copies-tooltip = Las copias se saltan el redondeo de la tabla, así un valor en Hz pegado sigue dando el retardo correcto
+decimal-separator = Separador decimal
+decimal-separator-tooltip = Se usa en los números copiados, para plugins y hojas de cálculo que esperan una coma
+decimal-separator-dot = Punto (1.5)
+decimal-separator-comma = Coma (1,5)
+check-for-updates = Buscar actualizaciones
+check-for-updates-tooltip = Buscar una versión nueva en GitHub al iniciar
+keyboard-shortcuts = Atajos de teclado
+theme-system = Sistema
+
+## Keyboard shortcuts
+key-tap = Pulsar
+key-reset = Reiniciar pulsaciones
+key-nudge = Ajustar el tempo (Mayús ×5, Alt ÷10)
+key-halve-double = Tempo a la mitad / al doble
+key-units = Milisegundos / Hercios
+key-pin = Mantener la ventana encima
+key-stage = Modo escenario
+key-focus = Campo de texto siguiente / anterior
+key-scroll = Ajustar el tempo
+key-preferences = Preferencias
+key-escape = Cerrar menús y preferencias, o salir del modo escenario
+keys-scroll = Rueda sobre el tempo
+
+## Tap settings
+tap-window-last = Últimas { $count } pulsaciones
+tap-window-all = Todas las pulsaciones
+tap-timeout-never = Nunca
+tap-timeout-seconds = Tras { $seconds } s
+tap-tolerance-off = Nunca
+tap-tolerance-percent = Desviadas más de un { $percent }%
+tap-averaging-mean = Por igual
+tap-averaging-weighted = Favoreciendo las recientes
+
+## Tools
+tool-none = Sin herramienta
+tool-speaker-alignment = Alineación de altavoces
+tool-timecode-chase = Seguimiento de código de tiempo
+tool-midi-tap = Pulsación MIDI
+tool-audio-tap = Pulsación por audio
+tool-feedback-decay = Caída de la realimentación
+tool-ping-pong = Ping-pong
+tool-midi-clock = Reloj MIDI
+tool-section-length = Duración de sección
+tool-lfo-rates = Velocidades de LFO
+tool-haas = Desfases Haas
+tool-comb-tuning = Afinación de filtro peine
+tool-buffer-latency = Latencia del búfer
+tool-tempo-match = Igualar tempo
+tool-groove-offsets = Desfases de groove
+tool-tempo-ramp = Rampa de tempo
+tool-multitap = Multitap
+tool-sidechain-pump = Bombeo por sidechain
+tool-loop-length = Duración de loop a BPM
+tool-polyrhythm = Polirritmo
+tool-flam-offsets = Desplazamientos de flam
+tool-count-in = Conteo previo
+tool-turntable-pitch = Pitch del tocadiscos
+tool-envelope-times = Tiempos de gate y envolvente
+tool-echo-schedule = Calendario de ecos
+tool-copy = Copiar
+tool-refresh = Actualizar
+tool-disconnect = Desconectar
+tool-use-current = Usar el actual
+tool-needs-feature = { $tool } necesita una compilación con la función `{ $feature }` activada
+tool-delay-ms = Retardo (ms)
+tool-sample-rate = Frecuencia de muestreo (Hz)
+tool-load = Cargar
+
+## Speaker alignment
+speed-of-sound = Velocidad del sonido: { $speed } m/s
+distance = Distancia
+temperature-celsius = Temp. (°C)
+
+## Feedback decay
+use-quarter-note = Usar 1/4
+feedback-percent = Realimentación (%)
+feedback-decay-beats = { $beats } tiempos
+feedback-decay-result = { $repeats } repeticiones audibles, por debajo de { $threshold } dB tras { $ms } ms{ $beats }
+feedback-out-of-range = La realimentación debe estar entre 0 y 100 %
+feedback-decay-prompt = Introduce un tiempo de retardo y una cantidad de realimentación
+
+## Ping-pong
+base-note = Figura base
+haas-offset-ms = Desfase Haas (ms)
+ping-pong-prompt = Introduce un tempo para ver sugerencias
+left-ms = I { $ms } ms
+right-ms = D { $ms } ms
+pair-ping-pong = Ping-pong
+pair-straight-dotted = Normal + con puntillo
+pair-straight-triplet = Normal + tresillo
+pair-haas-widening = Ensanche Haas
+pair-haas-spread = Apertura Haas
+
+## Section length
+bars = Compases
+in-time-signature = en { $time-signature }
+frame-rate = Fotogramas por segundo
+section-duration = { $duration } ({ $frames } fotogramas a { $fps } fps)
+bars-prompt = Introduce un número de compases
+section-length-prompt = Introduce un tempo para ver la duración
+
+## LFO rates
+lfo-rates-prompt = Introduce un tempo para ver velocidades de LFO
+fraction-of-bar = 1/{ $fraction } de compás
+one-bar = 1 compás
+bar-count = { $bars } compases
+lfo-rates-heading = Un ciclo de LFO cada… (en { $time-signature })
+
+## Haas offsets
+haas-prompt = Introduce un tempo para ver desfases Haas
+haas-heading = Subdivisiones dentro de la zona Haas de { $min }–{ $max } ms
+haas-none = Ninguna a este tempo
+
+## Timecode chase
+fps-drop-frame = 29,97 fps drop
+show-command-other = comando { $byte }
+show-control-cue = cue { $cue }
+show-control-at = a las { $time }
+mtc-msc-input = Entrada MTC / MSC
+timecode = Código de tiempo: { $timecode }
+timecode-waiting = Código de tiempo: esperando MTC…
+bar-and-beat = Compás { $bar }, tiempo { $beat }
+show-control = Show control: { $command }
+
+## MIDI tap
+midi-input = Entrada MIDI
+trigger-learning = Disparador: pulsa un pad o pedal…
+trigger = Disparador: { $trigger }
+learn = Aprender
+trigger-note = Nota { $note }
+
+## MIDI clock
+midi-output = Salida MIDI
+midi-clock-prompt = Elige una salida para enviar el reloj
+midi-clock-stopped = Enviando reloj, transporte detenido
+midi-clock-running = Enviando reloj, transporte en marcha
+transport-start = Iniciar
+transport-stop = Detener
+send-mtc = Enviar también MTC (25 fps)
+
+## Audio tap
+audio-input = Entrada de audio
+listening-for-hits = Escuchando golpes
+
+## Comb filter tuning
+comb-pitch = { $hz } Hz, { $note } { $cents } cents
+delay-prompt = Introduce un tiempo de retardo
+comb-delay = { $hz } Hz, { $ms } ms
+note-prompt = Introduce una nota como A4, C#3 o Eb2
+note = Nota
+
+## Buffer latency
+sample-count = { $samples } muestras
+round-trip = { $ms } ms ida y vuelta
+nearest-straight-note = ≈ figura de 1/{ $division } ({ $percent } %)
+
+## Tempo match
+track-bpm = BPM de la pista
+target-bpm = BPM objetivo
+apply-target = Aplicar objetivo
+tempo-match-prompt = Introduce el tempo de la pista y el tempo que quieres igualar
+pitch-shift = Tono { $percent } %, o { $semitones } semitonos con el tempo ligado al tono
+time-stretch = Estirar al { $percent } % de la duración original (×{ $ratio })
+delay-times-at = Tiempos de retardo a { $tempo } BPM
+
+## Groove offsets
+swing-percent = Swing (%)
+copy-csv = Copiar CSV
+swing-range = El swing va del 50 % (recto) hasta justo por debajo del 100 %
+groove-offsets-prompt = Introduce un tempo para ver los desfases de groove
+sixteenth-step = Semicorchea { $step }
+tick-offset = { $ticks } ticks
+
+## Tempo ramp
+ramp-from = De
+ramp-to = a
+ramp-bpm-over = BPM en
+ramp-bars-of = compases de { $time-signature }
+per-bar = Por compás
+per-beat = Por tiempo
+tempo-ramp-prompt = Introduce los tempos inicial y final y un número de compases
+ramp-bar-step = Compás { $step }: { $tempo }
+ramp-beat-step = Tiempo { $step }: { $tempo }
+total-duration = Duración total { $duration }
+
+## Multitap
+add-tap = Añadir tap
+hide-timeline = Ocultar línea de tiempo
+timeline = Línea de tiempo
+tap-time = a { $ms } ms ({ $samples } muestras)
+tap-step = Tap { $tap }: { $note } { $modifier }
+remove = Quitar
+multitap-prompt = Introduce un tempo para ver los tiempos de los taps
+
+## Sidechain pump
+pump-every = Bombear cada
+attack-ms = Ataque (ms)
+sidechain-prompt = Introduce un tempo para ver los tiempos de envolvente
+attack-prompt = Introduce un tiempo de ataque
+attack-too-long = El ataque dura más que la figura
+volume-shaper-length = Duración del modelador de volumen
+release-next-note = Relajación, de vuelta en la siguiente figura
+release-halfway = Relajación, de vuelta a mitad
+
+## Loop length
+loop-length = Duración del loop
+loop-holding = que contiene
+length-beats = tiempos
+length-bars = compases
+loop-tempo = { $tempo } BPM
+loop-tempo-in = { $tempo } BPM en { $time-signature }
+loop-length-prompt = Introduce la duración del loop y cuántos tiempos o compases contiene
+
+## Polyrhythm
+polyrhythm = Polirritmo
+polyrhythm-prompt = Introduce un tempo para ver las voces
+polyrhythm-ratio-prompt = Introduce una proporción como 3:2 o 5:4
+stream-notes = { $count } notas
+polyrhythm-cycle = { $notes } notas contra { $beats } tiempos, repitiéndose cada { $ms } ms
+
+## Flam offsets
+flam-offsets-heading = Desplazamientos cortos para flams y notas de adorno (en { $time-signature })
+flam-offsets-prompt = Introduce un tempo para ver los desplazamientos en compases
+offset-fixed = Fijo
+offset-humanization = Humanización
+offset-flams = Flams
+offset-strum-spreads = Rasgueos
+
+## Count-in
+count-in-heading = Conteo previo en { $time-signature }
+count-in-prompt = Introduce un tempo para ver el conteo previo
+frame-count = { $frames } fotogramas
+click-count = { $clicks } clics
+
+## Turntable pitch
+turntable-record-at = Disco a
+turntable-pitched = con pitch al
+turntable-plays-at = Suena a { $tempo } BPM
+turntable-out-of-range = El fader de pitch solo llega a { $range }
+turntable-prompt = Introduce el tempo del disco y la posición del fader de pitch
+turntable-match = Pon el fader en { $pitch }% para igualar { $tempo } BPM
+turntable-beyond-range = Igualar { $tempo } BPM requiere { $pitch }%, más allá del fader de { $range }
+turntable-match-prompt = Introduce un tempo para ver el pitch que lo iguala
+
+## Gate and envelope times
+envelope-times-prompt = Introduce un tempo para ver los tiempos de gate y release
+gate-lengths = Duraciones de gate
+release-times = Tiempos de release
+gate-stutters = Stutters
+gate-tight = Gate ajustado
+gate-choppy = Gate entrecortado
+gate-pumping = Gates con bombeo
+release-plucks = Plucks
+release-stabs = Stabs
+release-leads = Leads
+release-pads = Pads
+release-swells = Crescendos
+
+## Echo schedule
+echo-repeats = Repeticiones
+echo-repeat = Repetición { $repeat }
+echo-repeats-prompt = Introduce entre 1 y { $max } repeticiones
+echo-schedule-prompt = Introduce un tempo para ver dónde cae cada repetición
+echo-schedule-heading = Medido desde un envío en 1:1, en compases:tiempos de { $time-signature }
+
+## Tray
+tray-show-window = Mostrar ventana
+tray-quick-tempo = Tempo rápido…
+tray-nothing-copied = Aún no se ha copiado nada
+tray-latest = Último: { $value }
+tray-quit = Salir
@@ -0,0 +1,5245 @@
+// The window: its state, messages, and views
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use iced::application;
+use iced::keyboard::key::Named;
+use iced::keyboard::{self, Key, Modifiers};
+use iced::mouse::ScrollDelta;
+use iced::time::Instant;
+use iced::widget::{
+    button, canvas, checkbox, column, container, mouse_area, opaque, pick_list, radio, row,
+    scrollable, slider, stack, text, text_input, tooltip, Column, Row, Text,
+};
+use iced::window::{self, Level, Settings};
+use iced::{Border, Color, Element, Length, Point, Renderer, Size, Subscription, Task, Theme};
+use round::round;
+
+use crate::automation::{ParameterRange, ParameterScale, PARAMETER_SCALES};
+use crate::color_tag::{ColorTag, COLOR_TAGS};
+use crate::custom_unit::{CustomBase, CUSTOM_BASES};
+use crate::delay_range::DelayRange;
+use crate::i18n::{self, tr, tr_with, Language, LANGUAGES};
+use crate::midi_file::TempoEvent;
+use crate::note_glyph::{self, NoteGlyph};
+use crate::plugins::ExportPlugin;
+use crate::presets::PresetTemplate;
+use crate::settings::{DecimalSeparator, ThemeChoice, DECIMAL_SEPARATORS};
+use crate::sparkline::{Sparkline, SPARKLINE_HEIGHT, SPARKLINE_WIDTH};
+use crate::tap::{
+    TapAveraging, TapTempo, TapTimeout, TapTolerance, TapWindow, TAP_AVERAGINGS, TAP_TIMEOUTS,
+    TAP_TOLERANCES, TAP_WINDOWS,
+};
+use crate::tap_calibration::{TapCalibration, CALIBRATION_TEMPO};
+use crate::tempo_action::TempoAction;
+use crate::time_signature::{TimeSignature, TIME_SIGNATURES};
+use crate::tools::audio_tap::{self, AudioTap};
+use crate::tools::buffer_latency::{self, BufferLatency};
+use crate::tools::comb_tuning::{self, CombTuning};
+use crate::tools::count_in::{self, CountIn};
+use crate::tools::echo_schedule::{self, EchoSchedule};
+use crate::tools::envelope_times::{self, EnvelopeTimes};
+use crate::tools::feedback_decay::{self, FeedbackDecay};
+use crate::tools::flam_offsets::{self, FlamOffsets};
+use crate::tools::groove_offsets::{self, GrooveOffsets};
+use crate::tools::haas::{self, Haas, HAAS_ZONE_MS};
+use crate::tools::lfo_rates::{self, LfoRates};
+use crate::tools::loop_length::{self, LoopLength};
+use crate::tools::midi_clock::{self, MidiClock};
+use crate::tools::midi_tap::{self, MidiTap};
+use crate::tools::multitap::{self, Multitap};
+use crate::tools::ping_pong::{self, PingPong};
+use crate::tools::polyrhythm::{self, Polyrhythm};
+use crate::tools::section_length::{self, SectionLength};
+use crate::tools::sidechain_pump::{self, SidechainPump};
+use crate::tools::speaker_alignment::{self, SpeakerAlignment};
+use crate::tools::tempo_match::{self, TempoMatch};
+use crate::tools::tempo_ramp::{self, TempoRamp};
+use crate::tools::timecode_chase::{self, TimecodeChase};
+use crate::tools::turntable_pitch::{self, TurntablePitch};
+use crate::tools::{Tool, TOOLS};
+use crate::{
+    cell_value, clipboard, custom_unit, delay_time, delay_times, export, file_dialog, focus,
+    http_api, image_export, midi_file, other_sample_rates, parse_tempo, pipe, plugins, presets,
+    project_file, quarter_ratio, rounding_drift_ms, sample_rate_label, session, settings, tray,
+    update_check, DelayTable, NoteNames, NoteValue, RhythmicModifier, Unit, DEFAULT_TEMPO,
+    NOTE_NAMES, NOTE_VALUES, NOT_APPLICABLE, PPQNS, RHYTHMIC_MODIFIER, ROUND_LIMIT, SAMPLE_RATES,
+    SPACING,
+};
+
+const INITIAL_WINDOW_SIZE: Size = Size {
+    width: 650.0,
+    height: 600.0,
+};
+const MINI_WINDOW_SIZE: Size = Size {
+    width: 520.0,
+    height: 60.0,
+};
+const NOTE_GLYPH_HEIGHT: f32 = 30.0;
+const SYSTEM_THEME_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const TEMPO_HISTORY_LIMIT: usize = 20;
+const MAX_TAP_LATENCY_MS: f64 = 100.0;
+const TEMPO_HISTORY_WIDTH: f32 = 110.0;
+const TITLE: &str = "Delay Time Calculator";
+const COLOR_TAG_BORDER_WIDTH: f32 = 4.0;
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+const PREFERENCE_LABEL_WIDTH: u16 = 160;
+const CELL_MENU_WIDTH: u16 = 220;
+// Shown in preferences with the actions translated, and the keys too where they're words; keep in
+// sync with `handle_key_press`
+const KEYBINDINGS: [(&str, &str); 11] = [
+    ("T", "key-tap"),
+    ("R", "key-reset"),
+    ("↑ / ↓", "key-nudge"),
+    ("1 / 2", "key-halve-double"),
+    ("M / H", "key-units"),
+    ("P", "key-pin"),
+    ("S", "key-stage"),
+    ("Tab / Shift+Tab", "key-focus"),
+    ("keys-scroll", "key-scroll"),
+    ("Ctrl+, or ⌘,", "key-preferences"),
+    ("Esc", "key-escape"),
+];
+const CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// Bare numbers outside this range are more likely to be something other than a tempo
+const PLAIN_NUMBER_TEMPO_RANGE: RangeInclusive<f64> = 20.0..=400.0;
+const TOAST_POLL_INTERVAL: Duration = Duration::from_millis(250);
+// Table values fade in over this after a unit switch, so the change reads as the same delays in new
+// units rather than new delays
+const UNIT_FADE_DURATION: Duration = Duration::from_millis(250);
+// Typed preferences are written at most this often instead of on every keystroke
+const SETTINGS_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+const PULSE_SIZE: f32 = 14.0;
+const SECONDARY_UNIT_SIZE: u16 = 12;
+const STAGE_TEMPO_SIZE: u16 = 160;
+const STAGE_LABEL_SIZE: u16 = 48;
+const STAGE_VALUE_SIZE: u16 = 96;
+
+// `Position::SpecificWith` only takes a function pointer, so the saved position is handed over here
+static SAVED_WINDOW_POSITION: OnceLock<Point> = OnceLock::new();
+
+pub fn run() -> iced::Result {
+    let geometry = settings::load().window;
+
+    let position = match geometry.and_then(|geometry| geometry.position) {
+        Some((x, y)) => {
+            let _ = SAVED_WINDOW_POSITION.set(Point::new(x, y));
+            window::Position::SpecificWith(saved_window_position)
+        }
+        None => window::Position::default(),
+    };
+
+    iced::application(Tap::title, Tap::update, Tap::view)
+        .theme(Tap::theme)
+        .subscription(Tap::subscription)
+        .scale_factor(Tap::scale_factor)
+        .style(Tap::style)
+        .window(Settings {
+            size: geometry.map_or(INITIAL_WINDOW_SIZE, |geometry| {
+                Size::new(geometry.width, geometry.height)
+            }),
+            position,
+            // Window limits can't be changed after launch, so the minimum has to allow mini mode
+            min_size: Some(Size { ..MINI_WINDOW_SIZE }),
+            max_size: None,
+            // Lets the opacity setting show through; fully opaque backgrounds look the same
+            transparent: true,
+            ..Settings::default()
+        })
+        // Closing is handled in `update` so the window geometry can be saved first
+        .exit_on_close_request(false)
+        .antialiasing(true)
+        .run_with(|| {
+            // Made once the window's loop is running, which macOS needs for a menu bar item
+            let tray = if tray::IS_SUPPORTED {
+                Task::done(Message::CreateTray)
+            } else {
+                Task::none()
+            };
+            (Tap::default(), Task::batch([discover_plugins(), tray]))
+        })
+}
+
+// Each plugin gets seconds to describe itself, so they're asked off the UI thread once the window
+// is up rather than before it opens
+fn discover_plugins() -> Task<Message> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let (sender, receiver) = iced::futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(plugins::discover());
+        });
+        Task::perform(
+            async move { receiver.await.unwrap_or_default() },
+            Message::PluginsDiscovered,
+        )
+    }
+
+    // The browser has no plugins directory, so there's nothing to wait for
+    #[cfg(target_arch = "wasm32")]
+    Task::done(Message::PluginsDiscovered(plugins::discover()))
+}
+
+fn show_window() -> Task<Message> {
+    window::get_latest().and_then(|id| {
+        window::change_mode(id, window::Mode::Windowed).chain(window::gain_focus(id))
+    })
+}
+
+// Falls back to centring when the saved position no longer fits, e.g. after the monitor it was on
+// is unplugged or the resolution drops
+fn saved_window_position(window: Size, monitor: Size) -> Point {
+    match SAVED_WINDOW_POSITION.get() {
+        Some(position)
+            if position.x >= 0.0
+                && position.y >= 0.0
+                && position.x + window.width <= monitor.width
+                && position.y + window.height <= monitor.height =>
+        {
+            *position
+        }
+        _ => Point::new(
+            ((monitor.width - window.width) / 2.0).max(0.0),
+            ((monitor.height - window.height) / 2.0).max(0.0),
+        ),
+    }
+}
+
+struct Toast {
+    message: String,
+    is_error: bool,
+    cell: Option<(usize, NoteValue)>,
+    shown_at: Instant,
+}
+
+struct Tap {
+    tap_tempo: TapTempo,
+    tempo: Option<f64>,
+    tempo_input_text: String,
+    tempo_input_is_invalid: bool,
+    tempo_was_clamped: bool,
+    // Typed text that hasn't been rounded and clamped by a submit yet
+    tempo_input_is_edited: bool,
+    // Set by a submit, so auto-copy catches a typed or slid tempo once it's finished even though
+    // the keystrokes or drag already changed it
+    tempo_was_submitted: bool,
+    text_focus: focus::TextFocus,
+    // Only tapped tempos get octave suggestions; typed ones are what was meant
+    tempo_is_tapped: bool,
+    unit: Unit,
+    time_signature: TimeSignature,
+    tuplet_text: String,
+    custom_tuplet: Option<RhythmicModifier>,
+    delay_table: Option<DelayTable>,
+    // Only built while they're shown, and like the main table only when their inputs change
+    delta_table: Option<DelayTable>,
+    // In `compare_tempos` order, with the normal tempo left to the main table
+    comparison_tables: [Option<DelayTable>; 3],
+    clipboard: clipboard::Handle,
+    // Kept until a copy goes through, since a toast alone is easy to miss
+    clipboard_error: Option<String>,
+    export_error: Option<String>,
+    color_tag: ColorTag,
+    tool: Tool,
+    speaker_alignment: SpeakerAlignment,
+    timecode_chase: TimecodeChase,
+    midi_tap: MidiTap,
+    feedback_decay: FeedbackDecay,
+    ping_pong: PingPong,
+    midi_clock: MidiClock,
+    section_length: SectionLength,
+    lfo_rates: LfoRates,
+    haas: Haas,
+    comb_tuning: CombTuning,
+    buffer_latency: BufferLatency,
+    tempo_match: TempoMatch,
+    groove_offsets: GrooveOffsets,
+    tempo_ramp: TempoRamp,
+    multitap: Multitap,
+    sidechain_pump: SidechainPump,
+    loop_length: LoopLength,
+    polyrhythm: Polyrhythm,
+    flam_offsets: FlamOffsets,
+    count_in: CountIn,
+    turntable_pitch: TurntablePitch,
+    envelope_times: EnvelopeTimes,
+    echo_schedule: EchoSchedule,
+    audio_tap: AudioTap,
+    always_on_top: bool,
+    is_mini: bool,
+    is_staged: bool,
+    show_tap_pad: bool,
+    window_size: Size,
+    window_position: Option<Point>,
+    mini_note_value: NoteValue,
+    show_note_glyphs: bool,
+    show_quarter_ratios: bool,
+    show_comparison: bool,
+    // The tempo the table isn't showing, switched to and from without retyping either
+    tempo_slot: TempoSlot,
+    other_tempo: Option<f64>,
+    other_tempo_text: String,
+    show_tempo_deltas: bool,
+    // A newer release than this build, found at launch
+    available_update: Option<update_check::Release>,
+    show_release_notes: bool,
+    show_pulse: bool,
+    // Lit for the first half of each beat
+    pulse_is_lit: bool,
+    // Held arrow keys repeat faster than the table is worth rebuilding, so their nudges are summed
+    // and applied once a frame
+    pending_nudge: f64,
+    // Set while the table's values fade in after a unit switch
+    unit_switched_at: Option<Instant>,
+    value_opacity: f32,
+    settings_save_pending: bool,
+    show_preferences: bool,
+    // The cell whose right-click menu is open
+    cell_menu: Option<(usize, NoteValue)>,
+    startup_tempo_text: String,
+    tap_latency_text: String,
+    tap_calibration: Option<TapCalibration>,
+    // Scaling while the slider is dragged would move it out from under the pointer, so the new
+    // scale waits for the release
+    ui_scale_draft: f64,
+    nudge_steps_text: String,
+    preferred_range_text: String,
+    min_tempo_text: String,
+    max_tempo_text: String,
+    automation_min_text: String,
+    custom_unit_factor_text: String,
+    automation_max_text: String,
+    export_plugins: Vec<ExportPlugin>,
+    preset_templates: Vec<PresetTemplate>,
+    serve_address: Option<String>,
+    pipe: bool,
+    // Whether there's a curl to check for updates with
+    can_check_for_updates: bool,
+    settings: settings::Settings,
+    settings_path: Option<PathBuf>,
+    system_theme: Theme,
+    // Most recent first
+    tempo_history: VecDeque<f64>,
+    show_tempo_history: bool,
+    session_notes: String,
+    // Held modifiers pick the tempo step size for the stepper buttons and scroll wheel
+    modifiers: Modifiers,
+    toast: Option<Toast>,
+    // Offered as a picker when a dropped MIDI file changes tempo along the way
+    file_tempo_events: Vec<TempoEvent>,
+    // The last clipboard contents seen, so each copy is only offered once
+    last_clipboard_text: Option<String>,
+    clipboard_tempo: Option<f64>,
+    // While there's a tray icon, closing the window only hides it
+    tray: Option<tray::Tray>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tap,
+    Reset,
+    SetTapWindow(TapWindow),
+    ModifyTempo(TempoAction),
+    QueueNudge(f64),
+    ApplyPendingNudge,
+    FadeInValues(Instant),
+    StoreTempo(String),
+    SubmitTempo,
+    SetUnit(Unit),
+    SetPpqn(u16),
+    SetSampleRate(u32),
+    ShowBothSampleRates(bool),
+    SetTimeSignature(TimeSignature),
+    StoreTuplet(String),
+    StoreSessionNotes(String),
+    SaveSession,
+    SessionSaveChosen(Option<PathBuf>),
+    OpenSession,
+    ExportSettings,
+    SettingsExportChosen(Option<PathBuf>),
+    ImportSettings,
+    SettingsImportChosen(Option<PathBuf>),
+    SessionOpenChosen(Option<PathBuf>),
+    // Index into the table's columns, then the row
+    CopyToClipboard(usize, NoteValue),
+    // Index into the table's columns
+    CopyColumn(usize),
+    CopyRow(NoteValue),
+    // Description, originating cell, and how the background write went
+    Copied(String, Option<(usize, NoteValue)>, Result<(), String>),
+    ExportHalfAndDoubleTime,
+    ExportFileChosen(Option<PathBuf>),
+    ExportImage,
+    ImageFileChosen(Option<PathBuf>),
+    SetColorTag(ColorTag),
+    SelectTool(Tool),
+    SpeakerAlignment(speaker_alignment::Message),
+    TimecodeChase(timecode_chase::Message),
+    MidiTap(midi_tap::Message),
+    FeedbackDecay(feedback_decay::Message),
+    PingPong(ping_pong::Message),
+    MidiClock(midi_clock::Message),
+    SectionLength(section_length::Message),
+    LfoRates(lfo_rates::Message),
+    Haas(haas::Message),
+    CombTuning(comb_tuning::Message),
+    BufferLatency(buffer_latency::Message),
+    TempoMatch(tempo_match::Message),
+    GrooveOffsets(groove_offsets::Message),
+    TempoRamp(tempo_ramp::Message),
+    Multitap(multitap::Message),
+    SidechainPump(sidechain_pump::Message),
+    LoopLength(loop_length::Message),
+    Polyrhythm(polyrhythm::Message),
+    FlamOffsets(flam_offsets::Message),
+    CountIn(count_in::Message),
+    TurntablePitch(turntable_pitch::Message),
+    EnvelopeTimes(envelope_times::Message),
+    EchoSchedule(echo_schedule::Message),
+    AudioTap(audio_tap::Message),
+    ToggleAlwaysOnTop,
+    ToggleMiniMode,
+    SelectMiniNoteValue(NoteValue),
+    ToggleStageMode,
+    ToggleTapPad,
+    ToggleStageValue(NoteValue, RhythmicModifier, bool),
+    Escape,
+    ShowNoteGlyphs(bool),
+    ShowQuarterRatios(bool),
+    ShowComparison(bool),
+    StoreOtherTempo(String),
+    SwitchTempoSlot,
+    ShowTempoDeltas(bool),
+    CheckForUpdates(bool),
+    UpdateCheckFinished(Result<update_check::Release, String>),
+    ToggleReleaseNotes,
+    CopyReleaseLink,
+    DismissUpdate,
+    ShowPulse(bool),
+    Pulse,
+    // A value from a half- or double-time comparison table
+    CopyValue(f64, Unit),
+    PinToMilliseconds(NoteValue, bool),
+    OpenCellMenu(usize, NoteValue),
+    CloseCellMenu,
+    CellMenu(CellAction),
+    TogglePreferences,
+    ClosePreferences,
+    SetDefaultUnit(Unit),
+    StoreCustomUnitName(String),
+    SetCustomUnitBase(CustomBase),
+    StoreCustomUnitFactor(String),
+    SetPrecision(i32),
+    DragUiScale(f64),
+    SetUiScale,
+    DragWindowOpacity(f32),
+    SaveWindowOpacity,
+    SetNoteNames(NoteNames),
+    SetTapTimeout(TapTimeout),
+    SetTapTolerance(TapTolerance),
+    SetTapAveraging(TapAveraging),
+    StoreTapLatency(String),
+    StartTapCalibration,
+    CancelTapCalibration,
+    StoreStartupTempo(String),
+    StoreNudgeSteps(String),
+    StorePreferredRange(String),
+    StoreMinTempo(String),
+    StoreMaxTempo(String),
+    StoreAutomationMin(String),
+    StoreAutomationMax(String),
+    SetAutomationScale(ParameterScale),
+    SetModifierColor(RhythmicModifier, ColorTag),
+    ColorByRange(bool),
+    TransposeTable(bool),
+    ExportWithPlugin(ExportPlugin),
+    PluginsDiscovered(Vec<ExportPlugin>),
+    PluginExportFileChosen(ExportPlugin, Option<PathBuf>),
+    ExportFinished(Result<(), String>),
+    ExportPreset(PresetTemplate),
+    PresetFileChosen(PresetTemplate, Option<PathBuf>),
+    SetTheme(ThemeChoice),
+    SetLanguage(Language),
+    DetectSystemTheme,
+    ToggleTempoHistory,
+    RestoreTempo(f64),
+    SlideTempo(f64),
+    FileDropped(PathBuf),
+    HttpApi(http_api::Event),
+    Pipe(String),
+    PickFileTempo(f64),
+    ApplyOctaveSuggestion(f64),
+    DismissFileTempos,
+    AutoCopy(bool),
+    ExactCopies(bool),
+    SetDecimalSeparator(DecimalSeparator),
+    WatchClipboard(bool),
+    PollClipboard,
+    UseClipboardTempo(f64),
+    DismissClipboardTempo,
+    ModifiersChanged(Modifiers),
+    WindowResized(Size),
+    WindowMoved(Point),
+    CloseRequested(window::Id),
+    CreateTray,
+    Tray(tray::Event),
+    FocusNext,
+    FocusPrevious,
+    CheckFocus,
+    FocusChanged(focus::TextFocus),
+    KeyPressed(Key, Modifiers),
+    Shortcut(focus::TextFocus, Key, Modifiers),
+    CommitTempoInput,
+    ExpireToast,
+    FlushSettings,
+}
+
+// Everything the app reads from the machine at startup, gathered in one place so the app can be
+// built without touching the real clipboard, config directory, or desktop theme
+struct Environment {
+    clipboard: clipboard::Handle,
+    settings: settings::Settings,
+    // Where settings are saved, if anywhere
+    settings_path: Option<PathBuf>,
+    preset_templates: Vec<PresetTemplate>,
+    system_theme: Theme,
+    // Where to serve the HTTP API, if anywhere
+    serve_address: Option<String>,
+    // Whether to take commands on stdin
+    pipe: bool,
+    can_check_for_updates: bool,
+}
+
+impl Environment {
+    fn system() -> Self {
+        Self {
+            clipboard: clipboard::Handle::system(),
+            settings: settings::load(),
+            settings_path: settings::path(),
+            preset_templates: presets::discover(),
+            system_theme: settings::system_theme(),
+            serve_address: http_api::serve_address(std::env::args()),
+            pipe: pipe::is_enabled(std::env::args()),
+            can_check_for_updates: update_check::is_available(),
+        }
+    }
+}
+
+impl Default for Tap {
+    fn default() -> Self {
+        Tap::new(Environment::system())
+    }
+}
+
+// Commands on stdin change the GUI's tempo the same way its own controls do
+impl pipe::Target for Tap {
+    fn tempo(&self) -> Option<f64> {
+        self.tempo
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit.clone()
+    }
+
+    fn set_tempo(&mut self, tempo: f64) {
+        let _ = self.update(Message::ModifyTempo(TempoAction::SetTo(tempo)));
+    }
+
+    fn tap(&mut self) {
+        let _ = self.update(Message::Tap);
+    }
+}
+
+impl Tap {
+    fn new(environment: Environment) -> Self {
+        let tempo = environment.settings.startup_tempo();
+        let unit = environment.settings.startup_unit();
+        i18n::set_language(environment.settings.language);
+
+        Self {
+            tap_tempo: TapTempo::new(
+                environment.settings.tap_window,
+                environment.settings.tap_timeout,
+                environment.settings.tap_tolerance,
+                environment.settings.tap_averaging,
+                environment.settings.tap_latency_offset_ms,
+            ),
+            tempo: Some(tempo),
+            tempo_input_text: tempo.to_string(),
+            tempo_input_is_invalid: false,
+            tempo_input_is_edited: false,
+            text_focus: focus::TextFocus::default(),
+            tempo_was_clamped: false,
+            tempo_was_submitted: false,
+            tempo_is_tapped: false,
+            unit: unit.clone(),
+            time_signature: TimeSignature::default(),
+            tuplet_text: String::new(),
+            custom_tuplet: None,
+            delay_table: Some(DelayTable::new(tempo, unit, RHYTHMIC_MODIFIER.to_vec())),
+            delta_table: None,
+            comparison_tables: Default::default(),
+            clipboard: environment.clipboard,
+            clipboard_error: None,
+            export_error: None,
+            color_tag: ColorTag::None,
+            tool: Tool::None,
+            speaker_alignment: SpeakerAlignment::default(),
+            timecode_chase: TimecodeChase::default(),
+            midi_tap: MidiTap::default(),
+            feedback_decay: FeedbackDecay::default(),
+            ping_pong: PingPong::default(),
+            midi_clock: MidiClock::default(),
+            section_length: SectionLength::default(),
+            lfo_rates: LfoRates,
+            haas: Haas,
+            comb_tuning: CombTuning::default(),
+            buffer_latency: BufferLatency::default(),
+            tempo_match: TempoMatch::default(),
+            groove_offsets: GrooveOffsets::default(),
+            tempo_ramp: TempoRamp::default(),
+            multitap: Multitap::default(),
+            sidechain_pump: SidechainPump::default(),
+            loop_length: LoopLength::default(),
+            polyrhythm: Polyrhythm::default(),
+            flam_offsets: FlamOffsets,
+            count_in: CountIn::default(),
+            turntable_pitch: TurntablePitch::default(),
+            envelope_times: EnvelopeTimes,
+            echo_schedule: EchoSchedule::default(),
+            audio_tap: AudioTap::default(),
+            always_on_top: false,
+            is_mini: false,
+            is_staged: false,
+            show_tap_pad: false,
+            window_size: environment
+                .settings
+                .window
+                .map_or(INITIAL_WINDOW_SIZE, |geometry| {
+                    Size::new(geometry.width, geometry.height)
+                }),
+            window_position: environment
+                .settings
+                .window
+                .and_then(|geometry| geometry.position)
+                .map(|(x, y)| Point::new(x, y)),
+            mini_note_value: NoteValue::Quarter,
+            show_note_glyphs: false,
+            show_quarter_ratios: false,
+            show_comparison: false,
+            tempo_slot: TempoSlot::A,
+            other_tempo: None,
+            other_tempo_text: String::new(),
+            show_tempo_deltas: false,
+            available_update: None,
+            show_release_notes: false,
+            show_pulse: false,
+            pulse_is_lit: false,
+            pending_nudge: 0.0,
+            unit_switched_at: None,
+            value_opacity: 1.0,
+            settings_save_pending: false,
+            cell_menu: None,
+            show_preferences: false,
+            startup_tempo_text: tempo.to_string(),
+            tap_latency_text: environment.settings.tap_latency_offset_ms.to_string(),
+            tap_calibration: None,
+            ui_scale_draft: environment.settings.ui_scale(),
+            nudge_steps_text: nudge_steps_text(&environment.settings.nudge_steps),
+            preferred_range_text: tempo_range_text(environment.settings.preferred_tempo_range),
+            custom_unit_factor_text: environment.settings.custom_unit.factor.to_string(),
+            min_tempo_text: environment.settings.tempo_range().0.to_string(),
+            max_tempo_text: environment.settings.tempo_range().1.to_string(),
+            automation_min_text: environment.settings.automation_range.min_ms.to_string(),
+            automation_max_text: environment.settings.automation_range.max_ms.to_string(),
+            export_plugins: Vec::new(),
+            preset_templates: environment.preset_templates,
+            serve_address: environment.serve_address,
+            pipe: environment.pipe,
+            can_check_for_updates: environment.can_check_for_updates,
+            settings: environment.settings,
+            settings_path: environment.settings_path,
+            system_theme: environment.system_theme,
+            tempo_history: VecDeque::new(),
+            show_tempo_history: false,
+            session_notes: String::new(),
+            modifiers: Modifiers::empty(),
+            toast: None,
+            file_tempo_events: Vec::new(),
+            last_clipboard_text: None,
+            clipboard_tempo: None,
+            tray: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        match self.color_tag {
+            ColorTag::None => TITLE.to_string(),
+            color_tag => format!("{} [{}]", TITLE, color_tag),
+        }
+    }
+
+    fn theme(&self) -> Theme {
+        let theme = match &self.settings.theme {
+            ThemeChoice::System => self.system_theme.clone(),
+            ThemeChoice::Theme(theme) => theme.clone(),
+        };
+
+        self.color_tag.tint(theme)
+    }
+
+    fn scale_factor(&self) -> f64 {
+        self.settings.ui_scale()
+    }
+
+    // Only the window's own background fades, so anything drawn on it stays readable
+    fn style(&self, theme: &Theme) -> application::Appearance {
+        let appearance = application::DefaultStyle::default_style(theme);
+
+        application::Appearance {
+            background_color: appearance
+                .background_color
+                .scale_alpha(self.settings.window_opacity()),
+            ..appearance
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let previous = (self.tempo, self.unit.clone());
+        // Tempos still being typed or dragged aren't copied until they're submitted
+        let is_unfinished = matches!(message, Message::StoreTempo(_) | Message::SlideTempo(_));
+        let task = self.handle_message(message);
+        self.refresh_delay_table();
+        self.midi_clock.set_tempo(self.tempo);
+
+        if self.unit != previous.1 {
+            self.unit_switched_at = Some(Instant::now());
+            self.value_opacity = 0.0;
+        }
+
+        if self.unit != previous.1 && !self.settings.exact_copies {
+            if let Some(drift) = self.largest_rounding_drift() {
+                self.show_toast(
+                    tr_with(
+                        "rounding-drift-notice",
+                        &[
+                            ("unit", &self.unit.to_string()),
+                            ("drift", &round(drift, ROUND_LIMIT)),
+                        ],
+                    ),
+                    false,
+                    None,
+                );
+            }
+        }
+
+        let was_submitted = std::mem::take(&mut self.tempo_was_submitted);
+        let has_changed = (self.tempo, &self.unit) != (previous.0, &previous.1);
+        if self.settings.auto_copy && !is_unfinished && (has_changed || was_submitted) {
+            let (rhythmic_modifier, note_value) = self.settings.selected_cell;
+            if let Some(index) = self
+                .rhythmic_modifiers()
+                .iter()
+                .position(|modifier| *modifier == rhythmic_modifier)
+            {
+                return Task::batch([task, self.copy_cell(index, note_value)]);
+            }
+        }
+
+        task
+    }
+
+    fn refresh_delay_table(&mut self) {
+        let rhythmic_modifiers = self.rhythmic_modifiers();
+        refresh_table(
+            &mut self.delay_table,
+            self.tempo,
+            &self.unit,
+            &rhythmic_modifiers,
+        );
+        refresh_table(
+            &mut self.delta_table,
+            self.other_tempo.filter(|_| self.show_tempo_deltas),
+            &self.unit,
+            &rhythmic_modifiers,
+        );
+
+        let comparison_tempos = self
+            .tempo
+            .filter(|_| self.show_comparison)
+            .map(compare_tempos);
+        for (index, comparison_table) in self.comparison_tables.iter_mut().enumerate() {
+            let tempo = comparison_tempos
+                .map(|tempos| tempos[index])
+                .filter(|(factor, _)| *factor != ComparisonFactor::Normal)
+                .map(|(_, tempo)| tempo);
+            refresh_table(comparison_table, tempo, &self.unit, &rhythmic_modifiers);
+        }
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Tap if self.tap_calibration.is_some() => {
+                if let Some(calibration) = &mut self.tap_calibration {
+                    calibration.tap();
+                    if let Some(offset_ms) = calibration.offset_ms() {
+                        let offset_ms = offset_ms.clamp(-MAX_TAP_LATENCY_MS, MAX_TAP_LATENCY_MS);
+                        self.set_tap_latency(offset_ms);
+                        self.tap_latency_text = round(offset_ms, ROUND_LIMIT).to_string();
+                        self.tap_calibration = None;
+                        self.show_toast(
+                            tr_with(
+                                "tap-calibration-finished",
+                                &[("offset", &round(offset_ms, ROUND_LIMIT))],
+                            )
+                            .to_string(),
+                            false,
+                            None,
+                        );
+                    }
+                }
+            }
+            Message::Tap => {
+                if self.tap_tempo.is_expired() {
+                    self.finish_tap_run();
+                }
+
+                self.tempo = self.tap_tempo.tap();
+                self.tempo_is_tapped = true;
+                log::debug!("Tap {}, tempo {:?}", self.tap_tempo.tap_count(), self.tempo);
+                if self.tap_tempo.last_tap_was_rejected() {
+                    log::info!("Tap ignored as a mis-tap");
+                    self.show_toast(tr("tap-ignored").to_string(), true, None);
+                }
+                self.tempo_input_is_invalid = false;
+                self.tempo_input_is_edited = false;
+                self.tempo_was_clamped = false;
+                match self.tempo {
+                    Some(tempo) => self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string(),
+                    None => self.tempo_input_text = NOT_APPLICABLE.to_string(),
+                }
+            }
+            Message::SetTapWindow(window) => {
+                self.tap_tempo.set_window(window);
+                self.settings.tap_window = window;
+                self.save_settings();
+
+                if let Some(tempo) = self.tap_tempo.tempo() {
+                    self.tempo = Some(tempo);
+                    self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+                }
+            }
+            Message::Reset => self.finish_tap_run(),
+            Message::ModifyTempo(action) => match action.apply(self.tempo) {
+                Some(tempo) if action.is_nudge() => {
+                    let tempo = self.clamp_tempo(tempo);
+                    self.tempo = Some(tempo);
+                    self.tempo_is_tapped = false;
+                    self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+                    self.tempo_input_is_edited = false;
+                }
+                Some(tempo) => self.submit_tempo(tempo),
+                None => {}
+            },
+            Message::QueueNudge(step) => self.pending_nudge += step,
+            Message::ApplyPendingNudge => {
+                let step = std::mem::take(&mut self.pending_nudge);
+                if step != 0.0 {
+                    return self.handle_message(Message::ModifyTempo(TempoAction::NudgeBy(step)));
+                }
+            }
+            Message::FadeInValues(now) => {
+                if let Some(unit_switched_at) = self.unit_switched_at {
+                    let progress = now
+                        .saturating_duration_since(unit_switched_at)
+                        .as_secs_f32()
+                        / UNIT_FADE_DURATION.as_secs_f32();
+                    if progress >= 1.0 {
+                        self.unit_switched_at = None;
+                        self.value_opacity = 1.0;
+                    } else {
+                        self.value_opacity = progress;
+                    }
+                }
+            }
+            Message::StoreTempo(text) => {
+                self.tempo_input_text = text;
+                self.tempo_input_is_edited = true;
+                self.tempo = parse_tempo(&self.tempo_input_text);
+                self.tempo_input_is_invalid = self.tempo.is_none();
+                self.tempo_was_clamped = false;
+            }
+            Message::SubmitTempo => match parse_tempo(&self.tempo_input_text) {
+                Some(tempo) => self.submit_tempo(tempo),
+                None => {
+                    self.tempo = None;
+                    self.tempo_input_is_invalid = true;
+                }
+            },
+            Message::SetUnit(unit) => self.unit = unit,
+            Message::SetPpqn(ppqn) => {
+                self.settings.ppqn = ppqn;
+                self.save_settings();
+                if let Unit::Ticks(_) = self.unit {
+                    self.unit = Unit::Ticks(ppqn);
+                }
+            }
+            Message::SetSampleRate(sample_rate) => {
+                self.settings.sample_rate = sample_rate;
+                self.save_settings();
+                if let Unit::Samples(_) = self.unit {
+                    self.unit = Unit::Samples(sample_rate);
+                }
+            }
+            Message::ShowBothSampleRates(show_both_sample_rates) => {
+                self.settings.show_both_sample_rates = show_both_sample_rates;
+                self.save_settings();
+            }
+            Message::SetTimeSignature(time_signature) => self.time_signature = time_signature,
+            Message::StoreTuplet(text) => {
+                self.custom_tuplet = RhythmicModifier::parse_tuplet(&text);
+                self.tuplet_text = text;
+            }
+            Message::StoreSessionNotes(notes) => self.session_notes = notes,
+            Message::SaveSession => {
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![(tr("session").to_string(), session::EXTENSION.to_string())],
+                        format!("session.{}", session::EXTENSION),
+                    ),
+                    Message::SessionSaveChosen,
+                );
+            }
+            Message::SessionSaveChosen(path) => {
+                if let Some(path) = path {
+                    self.export_error = session::save(&path, &self.session())
+                        .err()
+                        .map(|error| tr_with("export-failed", &[("error", &error)]));
+                }
+            }
+            Message::OpenSession => {
+                return Task::perform(
+                    file_dialog::open_file(vec![(
+                        tr("session").to_string(),
+                        session::EXTENSION.to_string(),
+                    )]),
+                    Message::SessionOpenChosen,
+                );
+            }
+            Message::SessionOpenChosen(path) => {
+                if let Some(path) = path {
+                    self.open_session(&path);
+                }
+            }
+            Message::ExportSettings => {
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![("JSON".to_string(), "json".to_string())],
+                        "delay_time_calculator_settings.json".to_string(),
+                    ),
+                    Message::SettingsExportChosen,
+                );
+            }
+            Message::SettingsExportChosen(path) => {
+                if let Some(path) = path {
+                    let bundle = settings::Bundle {
+                        settings: self.settings.clone(),
+                        preset_templates: presets::user_templates(),
+                    };
+                    match settings::export_bundle(&path, &bundle) {
+                        Ok(()) => self.show_toast(tr("settings-exported").to_string(), false, None),
+                        Err(error) => self.show_toast(
+                            tr_with("export-failed", &[("error", &error)]),
+                            true,
+                            None,
+                        ),
+                    }
+                }
+            }
+            Message::ImportSettings => {
+                return Task::perform(
+                    file_dialog::open_file(vec![("JSON".to_string(), "json".to_string())]),
+                    Message::SettingsImportChosen,
+                );
+            }
+            Message::SettingsImportChosen(path) => {
+                if let Some(path) = path {
+                    match settings::import_bundle(&path) {
+                        Ok(bundle) => self.import_settings(bundle),
+                        Err(error) => self.show_toast(
+                            tr_with(
+                                "cannot-read-file",
+                                &[("file", &path.display()), ("error", &error)],
+                            ),
+                            true,
+                            None,
+                        ),
+                    }
+                }
+            }
+            Message::CopyToClipboard(index, note_value) => {
+                // The last cell clicked is the one auto-copy follows and presets are made from
+                if let Some(rhythmic_modifier) = self.rhythmic_modifiers().get(index) {
+                    self.settings.selected_cell = (*rhythmic_modifier, note_value);
+                    self.save_settings();
+                }
+
+                return self.copy_cell(index, note_value);
+            }
+            Message::CopyColumn(index) => {
+                if let Some(delay_times) = self
+                    .delay_table
+                    .as_ref()
+                    .and_then(|delay_table| delay_table.columns.get(index))
+                {
+                    let values = NOTE_VALUES.map(|note_value| {
+                        cell_value(
+                            delay_times,
+                            note_value,
+                            &self.unit,
+                            &self.settings.millisecond_notes,
+                        )
+                        .0
+                    });
+                    return self.copy_numbers(
+                        &values,
+                        tr_with(
+                            "copied-column",
+                            &[("modifier", &self.rhythmic_modifiers()[index])],
+                        ),
+                        None,
+                    );
+                }
+            }
+            Message::CopyRow(note_value) => {
+                if let Some(delay_table) = &self.delay_table {
+                    let values: Vec<_> = delay_table
+                        .columns
+                        .iter()
+                        .map(|delay_times| {
+                            cell_value(
+                                delay_times,
+                                note_value,
+                                &self.unit,
+                                &self.settings.millisecond_notes,
+                            )
+                            .0
+                        })
+                        .collect();
+                    return self.copy_numbers(
+                        &values,
+                        tr_with("copied-row", &[("note", &note_value)]),
+                        None,
+                    );
+                }
+            }
+            Message::Copied(description, cell, result) => match result {
+                Ok(()) => {
+                    log::debug!("Copied {}", description);
+                    self.clipboard_error = None;
+                    if let Some(tray) = &self.tray {
+                        tray.set_latest(&tr_with("tray-latest", &[("value", &description)]));
+                    }
+                    self.show_toast(tr_with("copied", &[("what", &description)]), false, cell);
+                }
+                Err(error) => {
+                    log::warn!("Copying {} failed: {}", description, error);
+                    self.show_toast(tr_with("copy-failed", &[("error", &error)]), true, cell);
+                    self.clipboard_error = Some(error);
+                }
+            },
+            Message::ExportHalfAndDoubleTime => {
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![("CSV".to_string(), "csv".to_string())],
+                        "delay_times.csv".to_string(),
+                    ),
+                    Message::ExportFileChosen,
+                );
+            }
+            Message::ExportImage => {
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![
+                            ("SVG".to_string(), "svg".to_string()),
+                            ("PNG".to_string(), "png".to_string()),
+                        ],
+                        "delay_times.svg".to_string(),
+                    ),
+                    Message::ImageFileChosen,
+                );
+            }
+            Message::ImageFileChosen(path) => {
+                if let (Some(path), Some(delay_table)) = (path, &self.delay_table) {
+                    self.export_error = image_export::write_table_image(
+                        &path,
+                        delay_table,
+                        &self.settings.millisecond_notes,
+                        self.show_note_glyphs,
+                    )
+                    .err()
+                    .map(|error| tr_with("export-failed", &[("error", &error)]));
+                }
+            }
+            Message::ExportFileChosen(path) => {
+                if let (Some(path), Some(tempo)) = (path, self.tempo) {
+                    self.export_error =
+                        export::write_half_and_double_time_csv(&path, tempo, &self.unit)
+                            .err()
+                            .map(|error| tr_with("export-failed", &[("error", &error)]));
+                }
+            }
+            Message::SetColorTag(color_tag) => self.color_tag = color_tag,
+            Message::SelectTool(tool) => self.tool = tool,
+            Message::SpeakerAlignment(message) => self.speaker_alignment.update(message),
+            Message::TimecodeChase(message) => self.timecode_chase.update(message),
+            Message::MidiTap(message) => {
+                if self.midi_tap.update(message) {
+                    return self.update(Message::Tap);
+                }
+            }
+            Message::FeedbackDecay(message) => self.feedback_decay.update(message),
+            Message::PingPong(message) => {
+                if let Some((left, right)) = self.ping_pong.update(message) {
+                    return self.copy_numbers(
+                        &[left, right],
+                        tr("copied-left-right").to_string(),
+                        None,
+                    );
+                }
+            }
+            Message::MidiClock(message) => self.midi_clock.update(message, self.tempo),
+            Message::SectionLength(message) => self.section_length.update(message),
+            Message::LfoRates(message) => {
+                if let Some(rate) = self.lfo_rates.update(message) {
+                    return self.copy_numbers(
+                        &[rate],
+                        format!("{} Hz", round(rate, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::Haas(message) => {
+                if let Some(offset) = self.haas.update(message) {
+                    return self.copy_numbers(
+                        &[offset],
+                        format!("{} ms", round(offset, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::CombTuning(message) => self.comb_tuning.update(message),
+            Message::BufferLatency(message) => self.buffer_latency.update(message),
+            Message::TempoMatch(message) => {
+                if let Some(tempo) = self.tempo_match.update(message) {
+                    self.submit_tempo(tempo);
+                }
+            }
+            Message::GrooveOffsets(message) => {
+                if let Some(csv) = self.groove_offsets.update(message) {
+                    return self.copy_to_clipboard(
+                        csv,
+                        tr("copied-groove-offsets").to_string(),
+                        None,
+                    );
+                }
+            }
+            Message::TempoRamp(message) => self.tempo_ramp.update(message),
+            Message::AudioTap(message) => {
+                if self.audio_tap.update(message) {
+                    return self.update(Message::Tap);
+                }
+            }
+            Message::Multitap(message) => {
+                if let Some(tap_times) = self.multitap.update(message) {
+                    return self.copy_numbers(&tap_times, tr("copied-tap-times").to_string(), None);
+                }
+            }
+            Message::SidechainPump(message) => {
+                if let Some(time) = self.sidechain_pump.update(message) {
+                    return self.copy_numbers(
+                        &[time],
+                        format!("{} ms", round(time, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::LoopLength(message) => {
+                if let Some(tempo) = self.loop_length.update(message) {
+                    self.submit_tempo(tempo);
+                }
+            }
+            Message::Polyrhythm(message) => {
+                if let Some(ms) = self.polyrhythm.update(message) {
+                    return self.copy_numbers(
+                        &[ms],
+                        format!("{} ms", round(ms, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::FlamOffsets(message) => {
+                if let Some(ms) = self.flam_offsets.update(message) {
+                    return self.copy_numbers(
+                        &[ms],
+                        format!("{} ms", round(ms, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::CountIn(message) => {
+                if let Some(seconds) = self.count_in.update(message) {
+                    return self.copy_numbers(
+                        &[seconds],
+                        format!("{} s", round(seconds, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::TurntablePitch(message) => {
+                if let Some(tempo) = self.turntable_pitch.update(message) {
+                    self.submit_tempo(tempo);
+                }
+            }
+            Message::EnvelopeTimes(message) => {
+                if let Some(ms) = self.envelope_times.update(message) {
+                    return self.copy_numbers(
+                        &[ms],
+                        format!("{} ms", round(ms, ROUND_LIMIT)),
+                        None,
+                    );
+                }
+            }
+            Message::EchoSchedule(message) => {
+                if let Some(schedule) = self.echo_schedule.update(message) {
+                    // A line per repeat, its time then where it lands
+                    let text = schedule
+                        .iter()
+                        .map(|(ms, (bar, beat))| {
+                            format!(
+                                "{}\t{}:{}",
+                                self.format_number(*ms),
+                                bar,
+                                self.format_number(*beat)
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    return self.copy_to_clipboard(text, tr("copied-echo-times").to_string(), None);
+                }
+            }
+            Message::ToggleAlwaysOnTop => {
+                self.always_on_top = !self.always_on_top;
+                let level = self.window_level();
+                return window::get_latest().and_then(move |id| window::change_level(id, level));
+            }
+            Message::ToggleMiniMode => {
+                self.is_mini = !self.is_mini;
+                let size = if self.is_mini {
+                    MINI_WINDOW_SIZE
+                } else {
+                    self.window_size
+                };
+                let level = self.window_level();
+                return window::get_latest().and_then(move |id| {
+                    window::resize(id, size).chain(window::change_level(id, level))
+                });
+            }
+            Message::SelectMiniNoteValue(note_value) => self.mini_note_value = note_value,
+            Message::ToggleStageMode => {
+                self.is_staged = !self.is_staged;
+                let mode = if self.is_staged {
+                    window::Mode::Fullscreen
+                } else {
+                    window::Mode::Windowed
+                };
+                return window::get_latest().and_then(move |id| window::change_mode(id, mode));
+            }
+            Message::ToggleTapPad => self.show_tap_pad = !self.show_tap_pad,
+            Message::OpenCellMenu(index, note_value) => self.cell_menu = Some((index, note_value)),
+            Message::CloseCellMenu => self.cell_menu = None,
+            Message::CellMenu(action) => {
+                if let Some((index, note_value)) = self.cell_menu.take() {
+                    return self.cell_action(action, index, note_value);
+                }
+            }
+            Message::PinToMilliseconds(note_value, is_pinned) => {
+                let millisecond_notes = &mut self.settings.millisecond_notes;
+                millisecond_notes.retain(|pinned| *pinned != note_value);
+                if is_pinned {
+                    millisecond_notes.push(note_value);
+                }
+                self.save_settings();
+            }
+            Message::ToggleStageValue(note_value, rhythmic_modifier, is_shown) => {
+                self.settings.stage_values = toggle_stage_value(
+                    &self.settings.stage_values,
+                    (note_value, rhythmic_modifier),
+                    is_shown,
+                );
+                self.save_settings();
+            }
+            Message::Escape => {
+                if self.cell_menu.take().is_some() || self.tap_calibration.take().is_some() {
+                    return Task::none();
+                }
+                if self.is_staged {
+                    return self.update(Message::ToggleStageMode);
+                }
+                self.show_tap_pad = false;
+                self.show_preferences = false;
+            }
+            Message::ShowNoteGlyphs(show_note_glyphs) => self.show_note_glyphs = show_note_glyphs,
+            Message::TogglePreferences => self.show_preferences = !self.show_preferences,
+            Message::ClosePreferences => self.show_preferences = false,
+            Message::SetDefaultUnit(unit) => {
+                self.settings.default_unit = unit;
+                self.save_settings();
+            }
+            Message::StoreCustomUnitName(name) => {
+                self.set_custom_unit(custom_unit::CustomUnit {
+                    name,
+                    ..self.settings.custom_unit.clone()
+                });
+            }
+            Message::SetCustomUnitBase(base) => {
+                self.set_custom_unit(custom_unit::CustomUnit {
+                    base,
+                    ..self.settings.custom_unit.clone()
+                });
+            }
+            Message::StoreCustomUnitFactor(text) => {
+                if let Some(factor) = text
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|factor: &f64| factor.is_finite() && *factor > 0.0)
+                {
+                    self.set_custom_unit(custom_unit::CustomUnit {
+                        factor,
+                        ..self.settings.custom_unit.clone()
+                    });
+                }
+                self.custom_unit_factor_text = text;
+            }
+            Message::SetPrecision(precision) => {
+                self.settings.precision = precision;
+                self.save_settings();
+            }
+            Message::DragUiScale(ui_scale) => self.ui_scale_draft = ui_scale,
+            Message::SetUiScale => {
+                self.settings.ui_scale = self.ui_scale_draft;
+                self.save_settings();
+            }
+            Message::DragWindowOpacity(opacity) => self.settings.window_opacity = opacity,
+            Message::SaveWindowOpacity => self.save_settings(),
+            Message::SetNoteNames(note_names) => {
+                self.settings.note_names = note_names;
+                self.save_settings();
+            }
+            Message::SetTapAveraging(averaging) => {
+                self.tap_tempo.set_averaging(averaging);
+                self.settings.tap_averaging = averaging;
+                self.save_settings();
+
+                if let Some(tempo) = self.tap_tempo.tempo() {
+                    self.tempo = Some(tempo);
+                    self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+                }
+            }
+            Message::StoreTapLatency(text) => {
+                if let Some(offset_ms) = parse_tap_latency(&text) {
+                    self.set_tap_latency(offset_ms);
+                }
+                self.tap_latency_text = text;
+            }
+            Message::StartTapCalibration => {
+                self.tap_calibration = Some(TapCalibration::default());
+                self.pulse_is_lit = false;
+            }
+            Message::CancelTapCalibration => self.tap_calibration = None,
+            Message::SetTapTolerance(tolerance) => {
+                self.tap_tempo.set_tolerance(tolerance);
+                self.settings.tap_tolerance = tolerance;
+                self.save_settings();
+
+                if let Some(tempo) = self.tap_tempo.tempo() {
+                    self.tempo = Some(tempo);
+                    self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+                }
+            }
+            Message::SetTapTimeout(timeout) => {
+                self.tap_tempo.set_timeout(timeout);
+                self.settings.tap_timeout = timeout;
+                self.save_settings();
+            }
+            Message::StoreStartupTempo(text) => {
+                if let Some(tempo) = parse_tempo(&text) {
+                    self.settings.startup_tempo = tempo;
+                    self.queue_settings_save();
+                }
+                self.startup_tempo_text = text;
+            }
+            Message::StoreNudgeSteps(text) => {
+                if let Some(nudge_steps) = parse_nudge_steps(&text) {
+                    self.settings.nudge_steps = nudge_steps;
+                    self.queue_settings_save();
+                }
+                self.nudge_steps_text = text;
+            }
+            Message::StorePreferredRange(text) => {
+                if let Some(range) = parse_tempo_range(&text) {
+                    self.settings.preferred_tempo_range = range;
+                    self.queue_settings_save();
+                }
+                self.preferred_range_text = text;
+            }
+            Message::StoreMinTempo(text) => {
+                if let Some(min_tempo) = parse_tempo(&text) {
+                    self.set_tempo_limits(min_tempo, self.settings.tempo_range().1);
+                }
+                self.min_tempo_text = text;
+            }
+            Message::StoreMaxTempo(text) => {
+                if let Some(max_tempo) = parse_tempo(&text) {
+                    self.set_tempo_limits(self.settings.tempo_range().0, max_tempo);
+                }
+                self.max_tempo_text = text;
+            }
+            Message::StoreAutomationMin(text) => {
+                if let Ok(min_ms) = text.trim().parse() {
+                    self.set_automation_range(ParameterRange {
+                        min_ms,
+                        ..self.settings.automation_range
+                    });
+                }
+                self.automation_min_text = text;
+            }
+            Message::StoreAutomationMax(text) => {
+                if let Ok(max_ms) = text.trim().parse() {
+                    self.set_automation_range(ParameterRange {
+                        max_ms,
+                        ..self.settings.automation_range
+                    });
+                }
+                self.automation_max_text = text;
+            }
+            Message::SetAutomationScale(scale) => self.set_automation_range(ParameterRange {
+                scale,
+                ..self.settings.automation_range
+            }),
+            Message::SetModifierColor(rhythmic_modifier, color_tag) => {
+                self.settings
+                    .modifier_colors
+                    .retain(|(colored_modifier, _)| *colored_modifier != rhythmic_modifier);
+                if color_tag != ColorTag::None {
+                    self.settings
+                        .modifier_colors
+                        .push((rhythmic_modifier, color_tag));
+                }
+                self.save_settings();
+            }
+            Message::ColorByRange(color_by_range) => {
+                self.settings.color_by_range = color_by_range;
+                self.save_settings();
+            }
+            Message::ShowQuarterRatios(show_quarter_ratios) => {
+                self.show_quarter_ratios = show_quarter_ratios
+            }
+            Message::ShowComparison(show_comparison) => self.show_comparison = show_comparison,
+            Message::StoreOtherTempo(text) => {
+                self.other_tempo = parse_tempo(&text);
+                self.other_tempo_text = text;
+            }
+            Message::SwitchTempoSlot => {
+                let other_tempo = self.other_tempo;
+                self.other_tempo = self.tempo;
+                self.other_tempo_text = self
+                    .tempo
+                    .map_or_else(String::new, |tempo| round(tempo, ROUND_LIMIT).to_string());
+                self.tempo_slot = self.tempo_slot.other();
+                self.tap_tempo.reset();
+
+                match other_tempo {
+                    Some(tempo) => self.submit_tempo(tempo),
+                    None => {
+                        self.tempo = None;
+                        self.tempo_input_text.clear();
+                    }
+                }
+            }
+            Message::ShowTempoDeltas(show_tempo_deltas) => {
+                self.show_tempo_deltas = show_tempo_deltas
+            }
+            Message::ExactCopies(exact_copies) => {
+                self.settings.exact_copies = exact_copies;
+                self.save_settings();
+            }
+            Message::SetDecimalSeparator(decimal_separator) => {
+                self.settings.decimal_separator = decimal_separator;
+                self.save_settings();
+            }
+            Message::CheckForUpdates(check_for_updates) => {
+                self.settings.check_for_updates = Some(check_for_updates);
+                self.save_settings();
+            }
+            Message::UpdateCheckFinished(Ok(release)) => {
+                if update_check::is_newer(&release.tag_name, env!("CARGO_PKG_VERSION")) {
+                    log::info!("Update available: {}", release.tag_name);
+                    self.available_update = Some(release);
+                }
+            }
+            Message::UpdateCheckFinished(Err(error)) => {
+                log::info!("Couldn't check for updates: {}", error)
+            }
+            Message::ToggleReleaseNotes => self.show_release_notes = !self.show_release_notes,
+            Message::CopyReleaseLink => {
+                if let Some(release) = &self.available_update {
+                    return self.copy_to_clipboard(
+                        release.html_url.clone(),
+                        tr("release-link").to_string(),
+                        None,
+                    );
+                }
+            }
+            Message::DismissUpdate => {
+                self.available_update = None;
+                self.show_release_notes = false;
+            }
+            Message::ShowPulse(show_pulse) => {
+                self.show_pulse = show_pulse;
+                self.pulse_is_lit = false;
+            }
+            Message::Pulse => self.pulse_is_lit = !self.pulse_is_lit,
+            Message::CopyValue(value, unit) => {
+                return self.copy_numbers(
+                    &[value],
+                    format!("{} {}", round(value, self.settings.precision()), unit),
+                    None,
+                );
+            }
+            Message::TransposeTable(transpose_table) => {
+                self.settings.transpose_table = transpose_table;
+                self.save_settings();
+            }
+            Message::ToggleTempoHistory => self.show_tempo_history = !self.show_tempo_history,
+            Message::ModifiersChanged(modifiers) => self.modifiers = modifiers,
+            // Neither mini mode's strip nor stage mode's full screen is worth remembering; leaving
+            // them restores the full size
+            Message::WindowResized(size) => {
+                if !self.is_mini && !self.is_staged {
+                    self.window_size = size;
+                }
+            }
+            Message::WindowMoved(position) => self.window_position = Some(position),
+            Message::CloseRequested(id) => {
+                self.store_window_geometry();
+                self.save_settings();
+                if self.tray.is_some() {
+                    return window::change_mode(id, window::Mode::Hidden);
+                }
+                return window::close(id);
+            }
+            Message::CreateTray => match tray::Tray::new(TITLE, tr("tray-nothing-copied")) {
+                Ok(tray) => self.tray = Some(tray),
+                Err(error) => log::warn!("Couldn't add the tray icon: {}", error),
+            },
+            Message::Tray(tray::Event::ShowWindow) => return show_window(),
+            Message::Tray(tray::Event::QuickTempo) => {
+                let mini_mode = if self.is_mini {
+                    Task::none()
+                } else {
+                    self.update(Message::ToggleMiniMode)
+                };
+                return show_window()
+                    .chain(mini_mode)
+                    .chain(text_input::focus(focus::tempo_input_id()))
+                    .chain(focus::check().map(Message::FocusChanged));
+            }
+            Message::Tray(tray::Event::Quit) => {
+                self.store_window_geometry();
+                self.save_settings();
+                return iced::exit();
+            }
+            Message::FocusNext => {
+                return iced::widget::focus_next().chain(focus::check().map(Message::FocusChanged))
+            }
+            Message::FocusPrevious => {
+                return iced::widget::focus_previous()
+                    .chain(focus::check().map(Message::FocusChanged))
+            }
+            Message::CheckFocus => return focus::check().map(Message::FocusChanged),
+            Message::FocusChanged(text_focus) => {
+                let tempo_input_lost_focus = self.text_focus.tempo_input && !text_focus.tempo_input;
+                self.text_focus = text_focus;
+                if tempo_input_lost_focus && self.tempo_input_is_edited {
+                    return self.update(Message::SubmitTempo);
+                }
+            }
+            // Focus is looked up fresh for every key, since a field can also lose it by leaving the
+            // view
+            Message::KeyPressed(key, modifiers) => {
+                return focus::check()
+                    .map(move |text_focus| Message::Shortcut(text_focus, key.clone(), modifiers))
+            }
+            Message::Shortcut(text_focus, key, modifiers) => {
+                let focus_changed = self.update(Message::FocusChanged(text_focus));
+                let shortcut = if text_focus.any_input {
+                    handle_key_press_while_typing(key, modifiers)
+                } else {
+                    handle_key_press(key, modifiers)
+                };
+                return match shortcut {
+                    Some(message) => focus_changed.chain(self.update(message)),
+                    None => focus_changed,
+                };
+            }
+            Message::CommitTempoInput => {
+                self.text_focus = focus::TextFocus::default();
+                return self.update(Message::SubmitTempo).chain(focus::blur());
+            }
+            Message::HttpApi(http_api::Event::SetTempo(tempo)) => self.submit_tempo(tempo),
+            Message::HttpApi(http_api::Event::Failed(error)) => self.show_toast(error, true, None),
+            Message::Pipe(line) => {
+                if !line.trim().is_empty() {
+                    let response = pipe::respond(&line, self);
+                    pipe::write_line(&response);
+                }
+            }
+            Message::FileDropped(path) => {
+                let file_name = path
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                if session::is_session_file(&path) {
+                    self.open_session(&path);
+                    return Task::none();
+                }
+
+                let events = if project_file::is_project_file(&path) {
+                    project_file::tempo_events(&path)
+                } else {
+                    midi_file::tempo_events(&path)
+                };
+
+                match events {
+                    Ok(events) => match events.as_slice() {
+                        [] => self.show_toast(
+                            tr_with("no-tempo-in-file", &[("file", &file_name)]),
+                            true,
+                            None,
+                        ),
+                        [event] => self.submit_tempo(event.tempo),
+                        _ => self.file_tempo_events = events,
+                    },
+                    Err(error) => self.show_toast(
+                        tr_with(
+                            "cannot-read-file",
+                            &[("file", &file_name), ("error", &error)],
+                        ),
+                        true,
+                        None,
+                    ),
+                }
+            }
+            Message::PickFileTempo(tempo) => {
+                self.submit_tempo(tempo);
+                self.file_tempo_events.clear();
+            }
+            Message::DismissFileTempos => self.file_tempo_events.clear(),
+            Message::ApplyOctaveSuggestion(tempo) => {
+                self.tap_tempo.reset();
+                self.submit_tempo(tempo);
+            }
+            Message::AutoCopy(auto_copy) => {
+                self.settings.auto_copy = auto_copy;
+                self.save_settings();
+            }
+            Message::WatchClipboard(watch_clipboard) => {
+                self.settings.watch_clipboard = watch_clipboard;
+                self.save_settings();
+                self.clipboard_tempo = None;
+            }
+            Message::PollClipboard => {
+                let Ok(text) = self.clipboard.get_text() else {
+                    return Task::none();
+                };
+
+                if self.last_clipboard_text.as_ref() != Some(&text) {
+                    self.clipboard_tempo =
+                        clipboard_tempo(&text).filter(|tempo| Some(*tempo) != self.tempo);
+                    self.last_clipboard_text = Some(text);
+                }
+            }
+            Message::UseClipboardTempo(tempo) => {
+                self.submit_tempo(tempo);
+                self.clipboard_tempo = None;
+            }
+            Message::DismissClipboardTempo => self.clipboard_tempo = None,
+            Message::FlushSettings => self.save_settings(),
+            Message::ExpireToast => {
+                if self
+                    .toast
+                    .as_ref()
+                    .is_some_and(|toast| toast.shown_at.elapsed() >= TOAST_DURATION)
+                {
+                    self.toast = None;
+                }
+            }
+            // Limited like submitted tempos, since history can hold tempos from before the limits
+            // changed
+            Message::RestoreTempo(tempo) | Message::SlideTempo(tempo) => {
+                let tempo = self.clamp_tempo(tempo);
+                self.tempo = Some(tempo);
+                self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+                self.tempo_input_is_invalid = false;
+                self.tempo_input_is_edited = false;
+            }
+            Message::PluginsDiscovered(export_plugins) => self.export_plugins = export_plugins,
+            Message::ExportWithPlugin(plugin) => {
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![(plugin.name.clone(), plugin.extension.clone())],
+                        format!("delay_times.{}", plugin.extension),
+                    ),
+                    move |path| Message::PluginExportFileChosen(plugin.clone(), path),
+                );
+            }
+            Message::PluginExportFileChosen(plugin, path) => {
+                if let (Some(path), Some(tempo)) = (path, self.tempo) {
+                    let table = plugins::ExportTable::new(tempo, &self.unit);
+                    // Like discovery, a plugin can take seconds, which would stall the executor
+                    let (sender, receiver) = iced::futures::channel::oneshot::channel();
+                    std::thread::spawn(move || {
+                        let result = plugins::export(&plugin, &table).and_then(|bytes| {
+                            std::fs::write(path, bytes).map_err(|error| error.to_string())
+                        });
+                        let _ = sender.send(result);
+                    });
+                    return Task::perform(
+                        async move {
+                            receiver
+                                .await
+                                .unwrap_or_else(|error| Err(error.to_string()))
+                        },
+                        Message::ExportFinished,
+                    );
+                }
+            }
+            Message::ExportPreset(template) => {
+                let file_name = preset_file_name(
+                    self.settings.selected_cell,
+                    self.tempo.unwrap_or_default(),
+                    &template.extension,
+                );
+
+                return Task::perform(
+                    file_dialog::save_file(
+                        vec![(template.name.clone(), template.extension.clone())],
+                        file_name,
+                    ),
+                    move |path| Message::PresetFileChosen(template.clone(), path),
+                );
+            }
+            Message::PresetFileChosen(template, path) => {
+                if let (Some(path), Some(tempo)) = (path, self.tempo) {
+                    let (rhythmic_modifier, note_value) = self.settings.selected_cell;
+                    let delay_times = delay_times(tempo, &rhythmic_modifier, &Unit::Milliseconds);
+                    let preset = template.render(&presets::PresetValues {
+                        tempo,
+                        note_value,
+                        rhythmic_modifier,
+                        delay_ms: delay_time(&delay_times, &note_value),
+                    });
+                    return Task::perform(
+                        async move { std::fs::write(path, preset).map_err(|error| error.to_string()) },
+                        Message::ExportFinished,
+                    );
+                }
+            }
+            Message::SetTheme(theme_choice) => {
+                self.settings.theme = theme_choice;
+                self.save_settings();
+            }
+            Message::SetLanguage(language) => {
+                i18n::set_language(language);
+                self.settings.language = language;
+                self.save_settings();
+            }
+            Message::DetectSystemTheme => self.system_theme = settings::system_theme(),
+            Message::ExportFinished(result) => {
+                self.export_error = result
+                    .err()
+                    .map(|error| tr_with("export-failed", &[("error", &error)]));
+            }
+        }
+
+        Task::none()
+    }
+
+    fn session(&self) -> session::Session {
+        session::Session {
+            tempo: self.tempo,
+            unit: self.unit.clone(),
+            time_signature: self.time_signature,
+            tuplet: self.tuplet_text.clone(),
+            color_tag: self.color_tag,
+            transpose_table: self.settings.transpose_table,
+            show_note_glyphs: self.show_note_glyphs,
+            show_quarter_ratios: self.show_quarter_ratios,
+            show_comparison: self.show_comparison,
+            millisecond_notes: self.settings.millisecond_notes.clone(),
+            selected_cell: self.settings.selected_cell,
+            notes: self.session_notes.clone(),
+        }
+    }
+
+    // Window geometry, scale, and opacity stay behind, since they belong to this machine's screens
+    fn import_settings(&mut self, bundle: settings::Bundle) {
+        let settings = settings::Settings {
+            window: self.settings.window,
+            ui_scale: self.settings.ui_scale,
+            window_opacity: self.settings.window_opacity,
+            ..bundle.settings
+        };
+
+        let failed_templates = bundle
+            .preset_templates
+            .iter()
+            .filter(|template| presets::install(template).is_err())
+            .count();
+
+        i18n::set_language(settings.language);
+        self.tap_tempo = TapTempo::new(
+            settings.tap_window,
+            settings.tap_timeout,
+            settings.tap_tolerance,
+            settings.tap_averaging,
+            settings.tap_latency_offset_ms,
+        );
+        self.startup_tempo_text = settings.startup_tempo().to_string();
+        self.nudge_steps_text = nudge_steps_text(&settings.nudge_steps);
+        self.tap_latency_text = settings.tap_latency_offset_ms.to_string();
+        self.preferred_range_text = tempo_range_text(settings.preferred_tempo_range);
+        self.custom_unit_factor_text = settings.custom_unit.factor.to_string();
+        self.min_tempo_text = settings.tempo_range().0.to_string();
+        self.max_tempo_text = settings.tempo_range().1.to_string();
+        self.automation_min_text = settings.automation_range.min_ms.to_string();
+        self.automation_max_text = settings.automation_range.max_ms.to_string();
+        self.settings = settings;
+        self.preset_templates = presets::discover();
+        if let Unit::Custom(_) = self.unit {
+            self.unit = self.settings.custom_as_unit();
+        }
+        self.save_settings();
+
+        if failed_templates == 0 {
+            self.show_toast(tr("settings-imported").to_string(), false, None);
+        } else {
+            self.show_toast(
+                tr_with("templates-not-imported", &[("count", &failed_templates)]),
+                true,
+                None,
+            );
+        }
+    }
+
+    fn open_session(&mut self, path: &Path) {
+        match session::load(path) {
+            Ok(session) => self.apply_session(session),
+            Err(error) => {
+                let file_name = path
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                self.show_toast(
+                    tr_with(
+                        "cannot-read-file",
+                        &[("file", &file_name), ("error", &error)],
+                    ),
+                    true,
+                    None,
+                );
+            }
+        }
+    }
+
+    // The table layout choices are settings too, so opening a session carries them into later
+    // launches the same as changing them by hand would
+    fn apply_session(&mut self, session: session::Session) {
+        match session
+            .tempo
+            .filter(|tempo| tempo.is_finite() && *tempo > 0.0)
+        {
+            Some(tempo) => self.submit_tempo(tempo),
+            None => {
+                self.tempo = None;
+                self.tempo_input_text = String::new();
+            }
+        }
+        self.unit = self.settings.resolve_unit(session.unit);
+        self.time_signature = session.time_signature;
+        self.custom_tuplet = RhythmicModifier::parse_tuplet(&session.tuplet);
+        self.tuplet_text = session.tuplet;
+        self.color_tag = session.color_tag;
+        self.show_note_glyphs = session.show_note_glyphs;
+        self.show_quarter_ratios = session.show_quarter_ratios;
+        self.show_comparison = session.show_comparison;
+        self.session_notes = session.notes;
+        self.settings.transpose_table = session.transpose_table;
+        self.settings.millisecond_notes = session.millisecond_notes;
+        self.settings.selected_cell = session.selected_cell;
+        self.save_settings();
+    }
+
+    // The fixed modifiers, plus the user's tuplet column when one has been entered
+    fn rhythmic_modifiers(&self) -> Vec<RhythmicModifier> {
+        RHYTHMIC_MODIFIER
+            .iter()
+            .copied()
+            .chain(self.custom_tuplet)
+            .collect()
+    }
+
+    fn submit_tempo(&mut self, tempo: f64) {
+        let tempo = self.clamp_tempo(tempo);
+        log::info!("Tempo set to {}", tempo);
+        self.tempo = Some(tempo);
+        self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
+        self.tempo_input_is_invalid = false;
+        self.tempo_input_is_edited = false;
+        self.tempo_is_tapped = false;
+        self.tempo_was_submitted = true;
+        self.record_tempo(tempo);
+    }
+
+    fn clamp_tempo(&mut self, tempo: f64) -> f64 {
+        let (min_tempo, max_tempo) = self.settings.tempo_range();
+        let clamped = tempo.clamp(min_tempo, max_tempo);
+        self.tempo_was_clamped = clamped != tempo;
+        if self.tempo_was_clamped {
+            log::info!("Tempo {} limited to {}", tempo, clamped);
+        }
+        clamped
+    }
+
+    // A tap run is only committed once it's finished
+    fn finish_tap_run(&mut self) {
+        if self.tap_tempo.tap_count() > 1 {
+            if let Some(tempo) = self.tempo {
+                self.record_tempo(tempo);
+            }
+        }
+        self.tap_tempo.reset();
+    }
+
+    fn set_tap_latency(&mut self, offset_ms: f64) {
+        self.tap_tempo.set_latency_offset(offset_ms);
+        self.settings.tap_latency_offset_ms = offset_ms;
+        self.queue_settings_save();
+    }
+
+    fn record_tempo(&mut self, tempo: f64) {
+        let tempo = round(tempo, ROUND_LIMIT);
+
+        if self.tempo_history.front() == Some(&tempo) {
+            return;
+        }
+
+        self.tempo_history.push_front(tempo);
+        self.tempo_history.truncate(TEMPO_HISTORY_LIMIT);
+    }
+
+    // A settings file that can't be written isn't worth interrupting the user over
+    fn save_settings(&mut self) {
+        self.settings_save_pending = false;
+        if let Some(path) = &self.settings_path {
+            let _ = settings::save(&self.settings, path);
+        }
+    }
+
+    // For text fields, which change on every keystroke
+    fn queue_settings_save(&mut self) {
+        self.settings_save_pending = true;
+    }
+
+    fn cell(&self, index: usize, note_value: NoteValue) -> Option<(f64, Unit)> {
+        let delay_times = self.delay_table.as_ref()?.columns.get(index)?;
+        let (value, unit) = cell_value(
+            delay_times,
+            note_value,
+            &self.unit,
+            &self.settings.millisecond_notes,
+        );
+        Some((value, unit.clone()))
+    }
+
+    fn rounding_drift(&self, value: f64, unit: &Unit) -> Option<f64> {
+        let tempo = self.delay_table.as_ref()?.tempo;
+        rounding_drift_ms(value, unit, self.settings.precision(), tempo)
+    }
+
+    // Checks every cell after a unit switch, since a reciprocal can round far worse than the
+    // milliseconds it came from
+    fn largest_rounding_drift(&self) -> Option<f64> {
+        let delay_table = self.delay_table.as_ref()?;
+
+        delay_table
+            .columns
+            .iter()
+            .flat_map(|delay_times| {
+                NOTE_VALUES.map(|note_value| {
+                    cell_value(
+                        delay_times,
+                        note_value,
+                        &self.unit,
+                        &self.settings.millisecond_notes,
+                    )
+                })
+            })
+            .filter_map(|(value, unit)| self.rounding_drift(value, unit))
+            .reduce(f64::max)
+    }
+
+    fn cell_action(
+        &mut self,
+        action: CellAction,
+        index: usize,
+        note_value: NoteValue,
+    ) -> Task<Message> {
+        let Some((value, unit)) = self.cell(index, note_value) else {
+            return Task::none();
+        };
+        let rounded = round(value, self.settings.precision());
+        let copied = if self.settings.exact_copies {
+            value
+        } else {
+            rounded
+        };
+        // The confirmation owns up to rounding that moves the delay audibly
+        let description = |text: String| match self.rounding_drift(value, &unit) {
+            Some(drift) if !self.settings.exact_copies => tr_with(
+                "copied-with-drift",
+                &[("value", &text), ("drift", &round(drift, ROUND_LIMIT))],
+            ),
+            _ => text,
+        };
+
+        match action {
+            CellAction::CopyValue => self.copy_numbers(
+                &[copied],
+                description(format!("{} {}", rounded, unit)),
+                Some((index, note_value)),
+            ),
+            CellAction::CopyWithUnit => {
+                let text = format!("{} {}", self.format_number(copied), unit);
+                let description = description(format!("{} {}", rounded, unit));
+                self.copy_to_clipboard(text, description, Some((index, note_value)))
+            }
+            CellAction::CopySeconds => {
+                let Some(tempo) = self
+                    .delay_table
+                    .as_ref()
+                    .map(|delay_table| delay_table.tempo)
+                else {
+                    return Task::none();
+                };
+                let seconds = unit.to_milliseconds(value, tempo) / 1000.0;
+                // Three more places keep the detail the table shows in milliseconds
+                let rounded = round(seconds, self.settings.precision() + 3);
+                let copied = if self.settings.exact_copies {
+                    seconds
+                } else {
+                    rounded
+                };
+                self.copy_numbers(
+                    &[copied],
+                    format!("{} s", rounded),
+                    Some((index, note_value)),
+                )
+            }
+            CellAction::CopyFullPrecision => self.copy_cell(index, note_value),
+            CellAction::CopyNormalized => {
+                let normalized = self.delay_table.as_ref().and_then(|delay_table| {
+                    let ms = unit.to_milliseconds(value, delay_table.tempo);
+                    self.settings.automation_range.normalize(ms)
+                });
+                match normalized {
+                    Some(normalized) => {
+                        let normalized = round(normalized, settings::MAX_PRECISION);
+                        self.copy_numbers(
+                            &[normalized],
+                            tr_with("normalized-value", &[("value", &normalized)]),
+                            Some((index, note_value)),
+                        )
+                    }
+                    None => Task::none(),
+                }
+            }
+            CellAction::CopyRow => self.update(Message::CopyRow(note_value)),
+            CellAction::CopyColumn => self.update(Message::CopyColumn(index)),
+            CellAction::SetActive => {
+                if let Some(rhythmic_modifier) = self.rhythmic_modifiers().get(index) {
+                    self.settings.selected_cell = (*rhythmic_modifier, note_value);
+                    self.save_settings();
+                }
+                Task::none()
+            }
+        }
+    }
+
+    // Carrying the new definition in the unit is enough for the tables to notice it
+    fn set_custom_unit(&mut self, custom_unit: custom_unit::CustomUnit) {
+        self.settings.custom_unit = custom_unit;
+        if let Unit::Custom(_) = self.unit {
+            self.unit = self.settings.custom_as_unit();
+        }
+        if let Unit::Custom(_) = self.settings.default_unit {
+            self.settings.default_unit = self.settings.custom_as_unit();
+        }
+        self.queue_settings_save();
+    }
+
+    // Like the automation range, limits that leave no tempos are left for the user to finish typing
+    fn set_tempo_limits(&mut self, min_tempo: f64, max_tempo: f64) {
+        if min_tempo < max_tempo {
+            self.settings.min_tempo = min_tempo;
+            self.settings.max_tempo = max_tempo;
+            self.queue_settings_save();
+        }
+    }
+
+    // Ranges that can't normalize anything are left for the user to finish typing
+    fn set_automation_range(&mut self, range: ParameterRange) {
+        if range.is_valid() {
+            self.settings.automation_range = range;
+            self.queue_settings_save();
+        }
+    }
+
+    fn copy_cell(&mut self, index: usize, note_value: NoteValue) -> Task<Message> {
+        let Some((value, unit)) = self.cell(index, note_value) else {
+            return Task::none();
+        };
+
+        self.copy_numbers(
+            &[value],
+            format!("{} {}", round(value, self.settings.precision()), unit),
+            Some((index, note_value)),
+        )
+    }
+
+    // One number per line; descriptions keep the dot
+    fn copy_numbers(
+        &mut self,
+        numbers: &[f64],
+        description: String,
+        cell: Option<(usize, NoteValue)>,
+    ) -> Task<Message> {
+        let text = numbers
+            .iter()
+            .map(|number| self.format_number(*number))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.copy_to_clipboard(text, description, cell)
+    }
+
+    // Copied numbers get the decimal separator from preferences
+    fn format_number(&self, number: f64) -> String {
+        self.settings.decimal_separator.format(number)
+    }
+
+    // `cell` anchors the confirmation to the table cell that was clicked, if there was one
+    fn copy_to_clipboard(
+        &mut self,
+        text: String,
+        description: String,
+        cell: Option<(usize, NoteValue)>,
+    ) -> Task<Message> {
+        // The watcher shouldn't offer the app's own values back to it
+        self.last_clipboard_text = Some(text.clone());
+
+        Task::perform(self.clipboard.clone().set_text(text), move |result| {
+            Message::Copied(description.clone(), cell, result)
+        })
+    }
+
+    fn store_window_geometry(&mut self) {
+        self.settings.window = Some(settings::WindowGeometry {
+            width: self.window_size.width,
+            height: self.window_size.height,
+            position: self
+                .window_position
+                .map(|position| (position.x, position.y)),
+        });
+    }
+
+    fn show_toast(&mut self, message: String, is_error: bool, cell: Option<(usize, NoteValue)>) {
+        self.toast = Some(Toast {
+            message,
+            is_error,
+            cell,
+            shown_at: Instant::now(),
+        });
+    }
+
+    // Mini mode always floats, then hands the level back to the pin setting on the way out
+    fn window_level(&self) -> Level {
+        if self.always_on_top || self.is_mini {
+            Level::AlwaysOnTop
+        } else {
+            Level::Normal
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        if self.is_staged {
+            return self.stage_view();
+        }
+
+        if self.is_mini {
+            return self.mini_view();
+        }
+
+        if let Some(calibration) = &self.tap_calibration {
+            return self.framed(self.tap_calibration_view(calibration));
+        }
+
+        if self.show_preferences {
+            return self.framed(self.preferences_view());
+        }
+
+        if self.show_tap_pad {
+            return self.framed(self.tap_pad_view());
+        }
+
+        if let Some(release) = self
+            .available_update
+            .as_ref()
+            .filter(|_| self.show_release_notes)
+        {
+            return self.framed(self.release_notes_view(release));
+        }
+
+        let ticks = Unit::Ticks(self.settings.ppqn);
+        let samples = Unit::Samples(self.settings.sample_rate);
+        let custom = self.settings.custom_as_unit();
+
+        let step = tempo_step(self.modifiers);
+
+        // Largest decrease first, mirroring the increases after them
+        let nudge_buttons = self
+            .settings
+            .nudge_steps
+            .iter()
+            .rev()
+            .map(|step| -step)
+            .chain(self.settings.nudge_steps.iter().copied())
+            .map(|step| {
+                let label = if step < 0.0 {
+                    format!("−{}", -step)
+                } else {
+                    format!("+{}", step)
+                };
+                button(text(label))
+                    .on_press(Message::ModifyTempo(TempoAction::NudgeBy(step)))
+                    .into()
+            });
+
+        let controls_row = Row::new()
+            .push_maybe(self.show_pulse.then(|| self.pulse_indicator()))
+            .extend(vec![
+                with_tooltip(tap_button(), tr("tap-tooltip")),
+                with_tooltip(
+                    button(tr("reset"))
+                        .style(|theme: &Theme, status| {
+                            if self.tap_tempo.tap_count() > 0 {
+                                let palette = theme.extended_palette();
+                                button::Style::default()
+                                    .with_background(palette.success.strong.color)
+                            } else {
+                                button::primary(theme, status)
+                            }
+                        })
+                        .on_press(Message::Reset),
+                    tr("reset-tooltip"),
+                ),
+                with_tooltip(
+                    pick_list(
+                        TAP_WINDOWS,
+                        Some(self.tap_tempo.window()),
+                        Message::SetTapWindow,
+                    ),
+                    tr("tap-window-tooltip"),
+                ),
+                with_tooltip(
+                    button("−").on_press(Message::ModifyTempo(TempoAction::NudgeBy(-step))),
+                    tr("decrease-tempo-tooltip"),
+                ),
+                row![self.tempo_input(step)]
+                    .push_maybe(self.tap_sparkline())
+                    .spacing(SPACING / 2)
+                    .align_y(iced::Alignment::Center)
+                    .into(),
+                with_tooltip(
+                    button("+").on_press(Message::ModifyTempo(TempoAction::NudgeBy(step))),
+                    tr("increase-tempo-tooltip"),
+                ),
+                with_tooltip(
+                    button(tr("halve")).on_press(Message::ModifyTempo(TempoAction::Halve)),
+                    tr("halve-tooltip"),
+                ),
+                with_tooltip(
+                    button(tr("double")).on_press(Message::ModifyTempo(TempoAction::Double)),
+                    tr("double-tooltip"),
+                ),
+                with_tooltip(
+                    button(tr("round-tempo"))
+                        .on_press(Message::ModifyTempo(TempoAction::RoundToInt)),
+                    tr("round-tempo-tooltip"),
+                ),
+                Row::with_children(nudge_buttons)
+                    .spacing(SPACING / 3)
+                    .into(),
+                with_tooltip(
+                    unit_radio(
+                        Unit::Milliseconds.to_string(),
+                        Unit::Milliseconds,
+                        &self.unit,
+                    ),
+                    tr("milliseconds-tooltip"),
+                ),
+                with_tooltip(
+                    unit_radio(Unit::Hertz.to_string(), Unit::Hertz, &self.unit),
+                    tr("hertz-tooltip"),
+                ),
+                with_tooltip(
+                    unit_radio(ticks.to_string(), ticks, &self.unit),
+                    tr("ticks-tooltip"),
+                ),
+                with_tooltip(
+                    unit_radio(custom.to_string(), custom, &self.unit),
+                    tr("custom-unit-tooltip"),
+                ),
+                with_tooltip(
+                    unit_radio(tr("both-units").to_string(), Unit::Both, &self.unit),
+                    tr("both-units-tooltip"),
+                ),
+                with_tooltip(
+                    pick_list(PPQNS, Some(self.settings.ppqn), Message::SetPpqn).width(70),
+                    tr("ppqn-tooltip"),
+                ),
+                with_tooltip(
+                    unit_radio(samples.to_string(), samples, &self.unit),
+                    tr("samples-tooltip"),
+                ),
+                with_tooltip(
+                    pick_list(
+                        SAMPLE_RATES,
+                        Some(self.settings.sample_rate),
+                        Message::SetSampleRate,
+                    )
+                    .width(90),
+                    tr("sample-rate-tooltip"),
+                ),
+                with_tooltip(
+                    checkbox(
+                        tr("both-sample-rates"),
+                        self.settings.show_both_sample_rates,
+                    )
+                    .on_toggle(Message::ShowBothSampleRates),
+                    tr("both-sample-rates-tooltip"),
+                ),
+                with_tooltip(
+                    pick_list(
+                        TIME_SIGNATURES,
+                        Some(self.time_signature),
+                        Message::SetTimeSignature,
+                    ),
+                    tr("time-signature-tooltip"),
+                ),
+                with_tooltip(
+                    text_input(tr("tuplet-placeholder"), &self.tuplet_text)
+                        .on_input(Message::StoreTuplet)
+                        .style(|theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if self.custom_tuplet.is_none() && !self.tuplet_text.trim().is_empty() {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(70),
+                    tr("tuplet-tooltip"),
+                ),
+            ])
+            .spacing(SPACING)
+            // Too many controls for one line at the default window width
+            .wrap();
+
+        let table_options = TableOptions {
+            unit: &self.unit,
+            precision: self.settings.precision(),
+            show_note_glyphs: self.show_note_glyphs,
+            note_names: self.settings.note_names,
+            show_quarter_ratios: self.show_quarter_ratios,
+            transpose: self.settings.transpose_table,
+            selected_cell: self
+                .settings
+                .auto_copy
+                .then_some(self.settings.selected_cell),
+            millisecond_notes: &self.settings.millisecond_notes,
+            modifier_colors: &self.settings.modifier_colors,
+            color_by_range: self.settings.color_by_range,
+            show_both_sample_rates: self.settings.show_both_sample_rates,
+            delta_table: self.delta_table.as_ref(),
+            is_comparison: false,
+            value_opacity: self.value_opacity,
+        };
+        let main_table = || {
+            table(
+                self.delay_table.as_ref(),
+                &self.rhythmic_modifiers(),
+                table_options,
+                self.toast.as_ref(),
+            )
+            .height(Length::Fill)
+        };
+
+        let table: Element<_> = match (self.show_comparison, &self.delay_table) {
+            (true, Some(delay_table)) => {
+                let heading = |factor: &str, tempo: f64| {
+                    text(tr_with(
+                        "compare-heading",
+                        &[("factor", &factor), ("tempo", &round(tempo, ROUND_LIMIT))],
+                    ))
+                };
+                let comparison_options = TableOptions {
+                    selected_cell: None,
+                    delta_table: None,
+                    is_comparison: true,
+                    ..table_options
+                };
+
+                let comparisons = compare_tempos(delay_table.tempo)
+                    .into_iter()
+                    .zip(&self.comparison_tables)
+                    .map(|((factor, tempo), comparison_table)| {
+                        let table = match comparison_table {
+                            Some(comparison_table) => table(
+                                Some(comparison_table),
+                                &comparison_table.rhythmic_modifiers,
+                                comparison_options,
+                                None,
+                            )
+                            .height(Length::Fill),
+                            None => main_table(),
+                        };
+                        column![heading(factor.label(), tempo), table]
+                            .spacing(SPACING / 2)
+                            .width(Length::Fill)
+                            .height(Length::Fill)
+                            .into()
+                    });
+
+                // Three full tables don't fit side by side once they're transposed
+                if self.settings.transpose_table {
+                    Column::with_children(comparisons)
+                        .spacing(SPACING)
+                        .height(Length::Fill)
+                        .into()
+                } else {
+                    Row::with_children(comparisons)
+                        .spacing(SPACING * 2)
+                        .height(Length::Fill)
+                        .into()
+                }
+            }
+            _ => main_table().into(),
+        };
+        // Releasing the slider submits like the text field does, so the settled tempo is recorded.
+        // It spans the tempo limits from preferences.
+        let (min_tempo, max_tempo) = self.settings.tempo_range();
+        let tempo_slider = slider(
+            min_tempo..=max_tempo,
+            self.tempo
+                .unwrap_or(DEFAULT_TEMPO)
+                .clamp(min_tempo, max_tempo),
+            Message::SlideTempo,
+        )
+        .step(1.0)
+        .shift_step(0.1)
+        .on_release(Message::SubmitTempo);
+
+        let other_slot = self.tempo_slot.other();
+        let other_tempo_is_invalid =
+            self.other_tempo.is_none() && !self.other_tempo_text.trim().is_empty();
+        let tempo_slot_row = row![
+            text(tr_with("showing-tempo-slot", &[("slot", &self.tempo_slot)])),
+            text(tr_with("tempo-slot", &[("slot", &other_slot)])),
+            text_input("", &self.other_tempo_text)
+                .on_input(Message::StoreOtherTempo)
+                .style(move |theme: &Theme, status| {
+                    let mut style = text_input::default(theme, status);
+                    if other_tempo_is_invalid {
+                        style.border.color = theme.extended_palette().danger.base.color;
+                    }
+                    style
+                })
+                .width(80),
+            with_tooltip(
+                button(text(tr_with("switch-tempo-slot", &[("slot", &other_slot)])))
+                    .on_press(Message::SwitchTempoSlot),
+                tr("switch-tempo-slot-tooltip"),
+            ),
+            with_tooltip(
+                checkbox(tr("tempo-deltas"), self.show_tempo_deltas)
+                    .on_toggle(Message::ShowTempoDeltas),
+                tr_with("tempo-deltas-tooltip", &[("slot", &other_slot)]),
+            ),
+        ]
+        .spacing(SPACING)
+        .align_y(iced::Alignment::Center);
+
+        let mut column = column![
+            controls_row,
+            with_tooltip(tempo_slider, tr("slider-tooltip")),
+            tempo_slot_row,
+        ]
+        .spacing(SPACING);
+
+        if self.tap_tempo.tap_count() > 1 {
+            let rejected = match self.tap_tempo.rejected_count() {
+                0 => String::new(),
+                1 => tr("ignoring-interval").to_string(),
+                count => tr_with("ignoring-intervals", &[("count", &count)]),
+            };
+            let averaging = tr_with(
+                "averaging-taps",
+                &[
+                    ("used", &self.tap_tempo.used_count()),
+                    ("total", &self.tap_tempo.tap_count()),
+                ],
+            );
+            column = column.push(text(averaging + &rejected));
+        }
+
+        if self.tempo_input_is_invalid {
+            column = column.push(text(tr("invalid-tempo")).style(text::danger));
+        }
+
+        let octave_suggestion = self
+            .tempo
+            .filter(|_| self.tempo_is_tapped)
+            .and_then(|tempo| {
+                octave_suggestion(tempo, self.settings.preferred_tempo_range)
+                    .map(|suggestion| (tempo, suggestion))
+            });
+
+        if let Some((tempo, suggestion)) = octave_suggestion {
+            let (tempo, suggestion) = (round(tempo, ROUND_LIMIT), round(suggestion, ROUND_LIMIT));
+            column = column.push(
+                row![
+                    text(tr_with(
+                        "octave-suggestion",
+                        &[("tempo", &tempo), ("suggestion", &suggestion)],
+                    )),
+                    button(text(tr_with("use-tempo", &[("tempo", &suggestion)])))
+                        .on_press(Message::ApplyOctaveSuggestion(suggestion)),
+                ]
+                .spacing(SPACING / 2)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+
+        if self.tempo_was_clamped {
+            let (min_tempo, max_tempo) = self.settings.tempo_range();
+            column = column.push(
+                text(tr_with(
+                    "tempo-limited",
+                    &[("min", &min_tempo), ("max", &max_tempo)],
+                ))
+                .style(text::danger),
+            );
+        }
+
+        if !self.file_tempo_events.is_empty() {
+            let picker = Row::new()
+                .push(text(tr("tempos-in-file")))
+                .extend(self.file_tempo_events.iter().map(|event| {
+                    let label = match event.beat {
+                        Some(beat) => tr_with(
+                            "tempo-at-beat",
+                            &[
+                                ("tempo", &round(event.tempo, ROUND_LIMIT)),
+                                ("beat", &round(beat + 1.0, 2)),
+                            ],
+                        ),
+                        None => format!("{} BPM", round(event.tempo, ROUND_LIMIT)),
+                    };
+                    button(text(label))
+                        .on_press(Message::PickFileTempo(event.tempo))
+                        .into()
+                }))
+                .push(button(tr("dismiss")).on_press(Message::DismissFileTempos))
+                .spacing(SPACING / 2)
+                .align_y(iced::Alignment::Center);
+
+            column = column.push(picker.wrap());
+        }
+
+        if self.can_check_for_updates && self.settings.check_for_updates.is_none() {
+            column = column.push(
+                row![
+                    text(tr("ask-check-for-updates")),
+                    button(tr("yes")).on_press(Message::CheckForUpdates(true)),
+                    button(tr("no")).on_press(Message::CheckForUpdates(false)),
+                ]
+                .spacing(SPACING / 2)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+
+        if let Some(release) = &self.available_update {
+            column = column.push(
+                row![
+                    text(tr_with(
+                        "update-available",
+                        &[("version", &release.tag_name)]
+                    )),
+                    button(tr("release-notes")).on_press(Message::ToggleReleaseNotes),
+                    button(tr("dismiss")).on_press(Message::DismissUpdate),
+                ]
+                .spacing(SPACING / 2)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+
+        if let Some(error) = &self.clipboard_error {
+            column = column.push(
+                text(tr_with("clipboard-unavailable", &[("error", error)])).style(text::danger),
+            );
+        }
+
+        if let Some(tempo) = self.clipboard_tempo {
+            column = column.push(
+                row![
+                    button(text(tr_with(
+                        "use-tempo",
+                        &[("tempo", &round(tempo, ROUND_LIMIT))],
+                    )))
+                    .on_press(Message::UseClipboardTempo(tempo)),
+                    button(tr("dismiss")).on_press(Message::DismissClipboardTempo),
+                    text(tr("from-clipboard")),
+                ]
+                .spacing(SPACING / 2)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+
+        let footer_row = Row::new()
+            .push(pick_list(
+                COLOR_TAGS,
+                Some(self.color_tag),
+                Message::SetColorTag,
+            ))
+            .push(with_tooltip(
+                button("⚙").on_press(Message::TogglePreferences),
+                tr("preferences-tooltip"),
+            ))
+            .push(pick_list(TOOLS, Some(self.tool), Message::SelectTool))
+            .push(
+                button(if self.show_tempo_history {
+                    tr("hide-history")
+                } else {
+                    tr("history")
+                })
+                .on_press(Message::ToggleTempoHistory),
+            )
+            .push(
+                checkbox(tr("note-glyphs"), self.show_note_glyphs)
+                    .on_toggle(Message::ShowNoteGlyphs),
+            )
+            .push(with_tooltip(
+                checkbox(tr("ratios"), self.show_quarter_ratios)
+                    .on_toggle(Message::ShowQuarterRatios),
+                tr("ratios-tooltip"),
+            ))
+            .push(with_tooltip(
+                checkbox(tr("pulse"), self.show_pulse).on_toggle(Message::ShowPulse),
+                tr("pulse-tooltip"),
+            ))
+            .push(with_tooltip(
+                checkbox(tr("compare"), self.show_comparison).on_toggle(Message::ShowComparison),
+                tr("compare-tooltip"),
+            ))
+            .push(with_tooltip(
+                checkbox(tr("auto-copy"), self.settings.auto_copy).on_toggle(Message::AutoCopy),
+                tr("auto-copy-tooltip"),
+            ))
+            .push(with_tooltip(
+                checkbox(tr("watch-clipboard"), self.settings.watch_clipboard)
+                    .on_toggle(Message::WatchClipboard),
+                tr("watch-clipboard-tooltip"),
+            ))
+            .push(with_tooltip(
+                checkbox(tr("transpose"), self.settings.transpose_table)
+                    .on_toggle(Message::TransposeTable),
+                tr("transpose-tooltip"),
+            ))
+            .push(with_tooltip(
+                button(if self.always_on_top {
+                    tr("unpin")
+                } else {
+                    tr("pin")
+                })
+                .style(|theme: &Theme, status| {
+                    if self.always_on_top {
+                        let palette = theme.extended_palette();
+                        button::Style::default().with_background(palette.success.strong.color)
+                    } else {
+                        button::primary(theme, status)
+                    }
+                })
+                .on_press(Message::ToggleAlwaysOnTop),
+                tr("pin-tooltip"),
+            ))
+            .push(with_tooltip(
+                button(tr("stage")).on_press(Message::ToggleStageMode),
+                tr("stage-tooltip"),
+            ))
+            .push(with_tooltip(
+                button(tr("tap-pad")).on_press(Message::ToggleTapPad),
+                tr("tap-pad-tooltip"),
+            ))
+            .push(with_tooltip(
+                button(tr("mini")).on_press(Message::ToggleMiniMode),
+                tr("mini-tooltip"),
+            ))
+            .push(
+                text_input(tr("session-notes"), &self.session_notes)
+                    .on_input(Message::StoreSessionNotes)
+                    .width(160),
+            )
+            .push(button(tr("save-session")).on_press(Message::SaveSession))
+            .push(button(tr("open-session")).on_press(Message::OpenSession))
+            .push(
+                button(tr("export-csv")).on_press_maybe(
+                    self.tempo
+                        .is_some()
+                        .then_some(Message::ExportHalfAndDoubleTime),
+                ),
+            )
+            .push(
+                button(tr("export-image"))
+                    .on_press_maybe(self.delay_table.as_ref().map(|_| Message::ExportImage)),
+            )
+            .push_maybe((!self.export_plugins.is_empty()).then(|| {
+                pick_list(
+                    self.export_plugins.as_slice(),
+                    None::<ExportPlugin>,
+                    Message::ExportWithPlugin,
+                )
+                .placeholder(tr("export-with-plugin"))
+            }))
+            .push(with_tooltip(
+                pick_list(
+                    self.preset_templates.as_slice(),
+                    None::<PresetTemplate>,
+                    Message::ExportPreset,
+                )
+                .placeholder(tr("export-preset")),
+                tr("export-preset-tooltip"),
+            ))
+            .push_maybe(
+                self.export_error
+                    .as_ref()
+                    .map(|error| text(error).style(text::danger)),
+            )
+            .spacing(SPACING);
+
+        let tool_panel = match self.tool {
+            Tool::None => None,
+            Tool::SpeakerAlignment => {
+                Some(self.speaker_alignment.view().map(Message::SpeakerAlignment))
+            }
+            Tool::TimecodeChase => Some(
+                self.timecode_chase
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::TimecodeChase),
+            ),
+            Tool::MidiTap => Some(self.midi_tap.view().map(Message::MidiTap)),
+            Tool::FeedbackDecay => Some(
+                self.feedback_decay
+                    .view(self.tempo)
+                    .map(Message::FeedbackDecay),
+            ),
+            Tool::PingPong => Some(self.ping_pong.view(self.tempo).map(Message::PingPong)),
+            Tool::MidiClock => Some(self.midi_clock.view().map(Message::MidiClock)),
+            Tool::SectionLength => Some(
+                self.section_length
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::SectionLength),
+            ),
+            Tool::LfoRates => Some(
+                self.lfo_rates
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::LfoRates),
+            ),
+            Tool::Haas => Some(self.haas.view(self.tempo).map(Message::Haas)),
+            Tool::CombTuning => Some(self.comb_tuning.view().map(Message::CombTuning)),
+            Tool::BufferLatency => Some(
+                self.buffer_latency
+                    .view(self.tempo)
+                    .map(Message::BufferLatency),
+            ),
+            Tool::TempoMatch => Some(self.tempo_match.view(self.tempo).map(Message::TempoMatch)),
+            Tool::GrooveOffsets => Some(
+                self.groove_offsets
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::GrooveOffsets),
+            ),
+            Tool::TempoRamp => Some(
+                self.tempo_ramp
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::TempoRamp),
+            ),
+            Tool::AudioTap => Some(self.audio_tap.view().map(Message::AudioTap)),
+            Tool::Multitap => Some(self.multitap.view(self.tempo).map(Message::Multitap)),
+            Tool::SidechainPump => Some(
+                self.sidechain_pump
+                    .view(self.tempo)
+                    .map(Message::SidechainPump),
+            ),
+            Tool::LoopLength => Some(
+                self.loop_length
+                    .view(self.time_signature)
+                    .map(Message::LoopLength),
+            ),
+            Tool::Polyrhythm => Some(self.polyrhythm.view(self.tempo).map(Message::Polyrhythm)),
+            Tool::FlamOffsets => Some(
+                self.flam_offsets
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::FlamOffsets),
+            ),
+            Tool::CountIn => Some(
+                self.count_in
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::CountIn),
+            ),
+            Tool::TurntablePitch => Some(
+                self.turntable_pitch
+                    .view(self.tempo)
+                    .map(Message::TurntablePitch),
+            ),
+            Tool::EnvelopeTimes => Some(
+                self.envelope_times
+                    .view(self.tempo)
+                    .map(Message::EnvelopeTimes),
+            ),
+            Tool::EchoSchedule => Some(
+                self.echo_schedule
+                    .view(self.tempo, self.time_signature)
+                    .map(Message::EchoSchedule),
+            ),
+        };
+
+        let column = column
+            .push(table)
+            .push_maybe(tool_panel)
+            .push(footer_row.wrap());
+
+        let content = Row::new()
+            .push(column)
+            .push_maybe(
+                self.show_tempo_history
+                    .then(|| self.tempo_history_sidebar()),
+            )
+            .spacing(SPACING);
+
+        let content = stack![content].push_maybe(
+            self.cell_menu
+                .map(|(index, note_value)| self.cell_menu_view(index, note_value)),
+        );
+
+        self.framed(content.into())
+    }
+
+    // Only worth drawing once there are a couple of intervals to compare
+    fn tap_sparkline(&self) -> Option<Element<'_, Message>> {
+        let tempos = self.tap_tempo.interval_tempos();
+        if tempos.len() < 2 {
+            return None;
+        }
+
+        Some(with_tooltip(
+            canvas(Sparkline { tempos })
+                .width(SPARKLINE_WIDTH)
+                .height(SPARKLINE_HEIGHT),
+            tr("tap-sparkline-tooltip"),
+        ))
+    }
+
+    // A dot that blinks on the beat, to check a tempo against playback by eye
+    fn pulse_indicator(&self) -> Element<'_, Message> {
+        let is_lit = self.pulse_is_lit;
+
+        container(
+            container(text(""))
+                .width(PULSE_SIZE)
+                .height(PULSE_SIZE)
+                .style(move |theme: &Theme| {
+                    let palette = theme.extended_palette();
+                    container::Style {
+                        background: Some(
+                            if is_lit {
+                                palette.success.base.color
+                            } else {
+                                palette.background.strong.color
+                            }
+                            .into(),
+                        ),
+                        border: Border::default().rounded(PULSE_SIZE / 2.0),
+                        ..container::Style::default()
+                    }
+                }),
+        )
+        .padding([8, 0])
+        .into()
+    }
+
+    // Centered rather than at the cursor, since iced has no popup positioned at a point. Clicking
+    // outside it closes it.
+    fn cell_menu_view(&self, index: usize, note_value: NoteValue) -> Element<'_, Message> {
+        let title = match (
+            self.rhythmic_modifiers().get(index),
+            self.cell(index, note_value),
+        ) {
+            (Some(rhythmic_modifier), Some((value, unit))) => format!(
+                "{} · {} {}",
+                stage_label(note_value, rhythmic_modifier),
+                round(value, self.settings.precision()),
+                unit
+            ),
+            _ => String::new(),
+        };
+
+        let actions = CELL_ACTIONS.map(|action| {
+            button(text(action.label()))
+                .on_press(Message::CellMenu(action))
+                .width(Length::Fill)
+                .style(button::secondary)
+                .into()
+        });
+
+        let menu = container(
+            column![
+                text(title),
+                Column::with_children(actions).spacing(SPACING / 3)
+            ]
+            .spacing(SPACING)
+            .width(CELL_MENU_WIDTH),
+        )
+        .padding(SPACING)
+        .style(container::rounded_box);
+
+        opaque(
+            mouse_area(
+                container(opaque(menu))
+                    .center(Length::Fill)
+                    .style(|_| container::Style {
+                        background: Some(Color::from_rgba(0.0, 0.0, 0.0, 0.4).into()),
+                        ..container::Style::default()
+                    }),
+            )
+            .on_press(Message::CloseCellMenu),
+        )
+    }
+
+    // Adds the toast overlay and the color tag border around a full-size view
+    fn framed<'a>(&'a self, content: Element<'a, Message>) -> Element<'a, Message> {
+        let color_tag = self.color_tag.color();
+
+        // Cell copies are confirmed in the cell itself; everything else gets a toast at the bottom
+        let toast = self
+            .toast
+            .as_ref()
+            .filter(|toast| toast.cell.is_none())
+            .map(|toast| {
+                container(text(&toast.message).style(if toast.is_error {
+                    text::danger
+                } else {
+                    text::default
+                }))
+                .padding(SPACING / 2)
+                .style(container::rounded_box)
+            });
+
+        let content = stack![content].push_maybe(toast.map(|toast| {
+            container(toast)
+                .center_x(Length::Fill)
+                .align_bottom(Length::Fill)
+        }));
+
+        container(content)
+            .padding(SPACING)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(move |_| container::Style {
+                border: Border {
+                    color: color_tag.unwrap_or_default(),
+                    width: color_tag.map_or(0.0, |_| COLOR_TAG_BORDER_WIDTH),
+                    ..Border::default()
+                },
+                ..container::Style::default()
+            })
+            .into()
+    }
+
+    fn preferences_view(&self) -> Element<'_, Message> {
+        let setting = |label: &'static str, control: Element<'static, Message>| {
+            row![text(label).width(PREFERENCE_LABEL_WIDTH), control]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center)
+        };
+
+        let keybindings = KEYBINDINGS.map(|(keys, action)| {
+            row![
+                text(tr(keys)).width(PREFERENCE_LABEL_WIDTH),
+                text(tr(action))
+            ]
+            .spacing(SPACING)
+            .into()
+        });
+
+        // Hidden without curl, since there'd be nothing to check with
+        let update_check_setting = self.can_check_for_updates.then(|| {
+            with_tooltip(
+                checkbox(
+                    tr("check-for-updates"),
+                    self.settings.check_for_updates == Some(true),
+                )
+                .on_toggle(Message::CheckForUpdates),
+                tr("check-for-updates-tooltip"),
+            )
+        });
+
+        let stage_value_rows = NOTE_VALUES.map(|note_value| {
+            Row::new()
+                .push(text(format!("{}", note_value)).width(PREFERENCE_LABEL_WIDTH))
+                .extend(RHYTHMIC_MODIFIER.iter().map(|rhythmic_modifier| {
+                    let rhythmic_modifier = *rhythmic_modifier;
+                    checkbox(
+                        rhythmic_modifier.label(),
+                        self.settings
+                            .stage_values
+                            .contains(&(note_value, rhythmic_modifier)),
+                    )
+                    .on_toggle(move |is_shown| {
+                        Message::ToggleStageValue(note_value, rhythmic_modifier, is_shown)
+                    })
+                    .into()
+                }))
+                .spacing(SPACING)
+                .into()
+        });
+
+        let millisecond_notes = Row::with_children(NOTE_VALUES.map(|note_value| {
+            checkbox(
+                note_value.to_string(),
+                self.settings.millisecond_notes.contains(&note_value),
+            )
+            .on_toggle(move |is_pinned| Message::PinToMilliseconds(note_value, is_pinned))
+            .into()
+        }))
+        .spacing(SPACING)
+        .wrap();
+
+        let startup_tempo_is_invalid = parse_tempo(&self.startup_tempo_text).is_none();
+        let tap_latency_is_invalid = parse_tap_latency(&self.tap_latency_text).is_none();
+        let nudge_steps_are_invalid = parse_nudge_steps(&self.nudge_steps_text).is_none();
+        let custom_unit_factor_is_invalid =
+            self.custom_unit_factor_text.trim().parse() != Ok(self.settings.custom_unit.factor);
+        let preferred_range_is_invalid = parse_tempo_range(&self.preferred_range_text).is_none();
+        // What's saved only changes to valid ranges, so the fields show when they've drifted from it
+        let automation_range = self.settings.automation_range;
+        let automation_range_is_invalid = self.automation_min_text.trim().parse()
+            != Ok(automation_range.min_ms)
+            || self.automation_max_text.trim().parse() != Ok(automation_range.max_ms);
+        let (min_tempo, max_tempo) = self.settings.tempo_range();
+        let tempo_limits_are_invalid = parse_tempo(&self.min_tempo_text) != Some(min_tempo)
+            || parse_tempo(&self.max_tempo_text) != Some(max_tempo);
+        let tempo_limits_style = move |theme: &Theme, status| {
+            let mut style = text_input::default(theme, status);
+            if tempo_limits_are_invalid {
+                style.border.color = theme.extended_palette().danger.base.color;
+            }
+            style
+        };
+        let automation_style = move |theme: &Theme, status| {
+            let mut style = text_input::default(theme, status);
+            if automation_range_is_invalid {
+                style.border.color = theme.extended_palette().danger.base.color;
+            }
+            style
+        };
+
+        scrollable(
+            column![
+                row![
+                    text(tr("preferences")).size(20).width(Length::Fill),
+                    with_tooltip(
+                        button(tr("export-settings")).on_press(Message::ExportSettings),
+                        tr("export-settings-tooltip"),
+                    ),
+                    button(tr("import-settings")).on_press(Message::ImportSettings),
+                    button(tr("done")).on_press(Message::ClosePreferences),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                setting(
+                    tr("theme"),
+                    pick_list(
+                        ThemeChoice::all(),
+                        Some(self.settings.theme.clone()),
+                        Message::SetTheme,
+                    )
+                    .into(),
+                ),
+                setting(
+                    tr("language"),
+                    pick_list(
+                        LANGUAGES,
+                        Some(self.settings.language),
+                        Message::SetLanguage
+                    )
+                    .into(),
+                ),
+                row![
+                    text(tr("ui-scale")).width(PREFERENCE_LABEL_WIDTH),
+                    slider(
+                        settings::UI_SCALE_RANGE,
+                        self.ui_scale_draft,
+                        Message::DragUiScale
+                    )
+                    .step(0.05)
+                    .on_release(Message::SetUiScale)
+                    .width(200),
+                    text(format!("{}%", (self.ui_scale_draft * 100.0).round())),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    with_tooltip(
+                        text(tr("window-opacity")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("window-opacity-tooltip"),
+                    ),
+                    slider(
+                        settings::WINDOW_OPACITY_RANGE,
+                        self.settings.window_opacity(),
+                        Message::DragWindowOpacity
+                    )
+                    .step(0.05)
+                    .on_release(Message::SaveWindowOpacity)
+                    .width(200),
+                    text(format!(
+                        "{}%",
+                        (self.settings.window_opacity() * 100.0).round()
+                    )),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                setting(
+                    tr("default-unit"),
+                    pick_list(
+                        [
+                            Unit::Milliseconds,
+                            Unit::Hertz,
+                            Unit::Ticks(self.settings.ppqn),
+                            Unit::Samples(self.settings.sample_rate),
+                            self.settings.custom_as_unit(),
+                        ],
+                        Some(self.settings.startup_unit()),
+                        Message::SetDefaultUnit,
+                    )
+                    .into(),
+                ),
+                row![
+                    with_tooltip(
+                        text(tr("custom-unit")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("custom-unit-definition-tooltip"),
+                    ),
+                    text_input(tr("custom-unit-name"), &self.settings.custom_unit.name)
+                        .on_input(Message::StoreCustomUnitName)
+                        .width(120),
+                    text("="),
+                    pick_list(
+                        CUSTOM_BASES,
+                        Some(self.settings.custom_unit.base),
+                        Message::SetCustomUnitBase,
+                    ),
+                    text_input("1000", &self.custom_unit_factor_text)
+                        .on_input(Message::StoreCustomUnitFactor)
+                        .style(move |theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if custom_unit_factor_is_invalid {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(80),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                setting(
+                    tr("decimal-places"),
+                    pick_list(
+                        Vec::from_iter(0..=settings::MAX_PRECISION),
+                        Some(self.settings.precision()),
+                        Message::SetPrecision,
+                    )
+                    .into(),
+                ),
+                setting(
+                    tr("note-labels"),
+                    pick_list(
+                        NOTE_NAMES,
+                        Some(self.settings.note_names),
+                        Message::SetNoteNames,
+                    )
+                    .into(),
+                ),
+                setting(
+                    tr("reset-taps"),
+                    pick_list(
+                        TAP_TIMEOUTS,
+                        Some(self.settings.tap_timeout),
+                        Message::SetTapTimeout,
+                    )
+                    .into(),
+                ),
+                setting(
+                    tr("ignore-taps"),
+                    pick_list(
+                        TAP_TOLERANCES,
+                        Some(self.settings.tap_tolerance),
+                        Message::SetTapTolerance,
+                    )
+                    .into(),
+                ),
+                setting(
+                    tr("average-taps"),
+                    pick_list(
+                        TAP_AVERAGINGS,
+                        Some(self.settings.tap_averaging),
+                        Message::SetTapAveraging,
+                    )
+                    .into(),
+                ),
+                row![
+                    with_tooltip(
+                        text(tr("tap-latency")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("tap-latency-tooltip"),
+                    ),
+                    text_input("0", &self.tap_latency_text)
+                        .on_input(Message::StoreTapLatency)
+                        .style(move |theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if tap_latency_is_invalid {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(80),
+                    text("ms"),
+                    button(tr("calibrate")).on_press(Message::StartTapCalibration),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    text(tr("startup-tempo")).width(PREFERENCE_LABEL_WIDTH),
+                    text_input("", &self.startup_tempo_text)
+                        .on_input(Message::StoreStartupTempo)
+                        .style(move |theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if startup_tempo_is_invalid {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(80),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    with_tooltip(
+                        text(tr("tempo-limits")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("tempo-limits-tooltip"),
+                    ),
+                    text_input("", &self.min_tempo_text)
+                        .on_input(Message::StoreMinTempo)
+                        .style(tempo_limits_style)
+                        .width(80),
+                    text("–"),
+                    text_input("", &self.max_tempo_text)
+                        .on_input(Message::StoreMaxTempo)
+                        .style(tempo_limits_style)
+                        .width(80),
+                    text("BPM"),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    text(tr("nudge-buttons")).width(PREFERENCE_LABEL_WIDTH),
+                    text_input("1, 5", &self.nudge_steps_text)
+                        .on_input(Message::StoreNudgeSteps)
+                        .style(move |theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if nudge_steps_are_invalid {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(120),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    with_tooltip(
+                        text(tr("automation-range")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("automation-range-tooltip"),
+                    ),
+                    text_input("", &self.automation_min_text)
+                        .on_input(Message::StoreAutomationMin)
+                        .style(automation_style)
+                        .width(80),
+                    text("–"),
+                    text_input("", &self.automation_max_text)
+                        .on_input(Message::StoreAutomationMax)
+                        .style(automation_style)
+                        .width(80),
+                    text("ms"),
+                    pick_list(
+                        PARAMETER_SCALES,
+                        Some(self.settings.automation_range.scale),
+                        Message::SetAutomationScale,
+                    ),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                row![
+                    with_tooltip(
+                        text(tr("preferred-range")).width(PREFERENCE_LABEL_WIDTH),
+                        tr("preferred-range-tooltip"),
+                    ),
+                    text_input("70–180", &self.preferred_range_text)
+                        .on_input(Message::StorePreferredRange)
+                        .style(move |theme: &Theme, status| {
+                            let mut style = text_input::default(theme, status);
+                            if preferred_range_is_invalid {
+                                style.border.color = theme.extended_palette().danger.base.color;
+                            }
+                            style
+                        })
+                        .width(120),
+                ]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                Row::with_children(
+                    std::iter::once(
+                        text(tr("column-colors"))
+                            .width(PREFERENCE_LABEL_WIDTH)
+                            .into()
+                    )
+                    .chain(RHYTHMIC_MODIFIER.iter().map(|rhythmic_modifier| {
+                        let rhythmic_modifier = *rhythmic_modifier;
+                        row![
+                            text(rhythmic_modifier.label()),
+                            pick_list(
+                                COLOR_TAGS,
+                                Some(self.settings.modifier_color(rhythmic_modifier)),
+                                move |color_tag| {
+                                    Message::SetModifierColor(rhythmic_modifier, color_tag)
+                                },
+                            ),
+                        ]
+                        .spacing(SPACING / 2)
+                        .align_y(iced::Alignment::Center)
+                        .into()
+                    })),
+                )
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+                with_tooltip(
+                    checkbox(tr("exact-copies"), self.settings.exact_copies)
+                        .on_toggle(Message::ExactCopies),
+                    tr("exact-copies-tooltip"),
+                ),
+                Column::new()
+                    .push(
+                        row![
+                            with_tooltip(
+                                text(tr("decimal-separator")).width(PREFERENCE_LABEL_WIDTH),
+                                tr("decimal-separator-tooltip"),
+                            ),
+                            pick_list(
+                                DECIMAL_SEPARATORS,
+                                Some(self.settings.decimal_separator),
+                                Message::SetDecimalSeparator
+                            ),
+                        ]
+                        .spacing(SPACING)
+                        .align_y(iced::Alignment::Center)
+                    )
+                    .push_maybe(update_check_setting)
+                    .spacing(SPACING),
+                with_tooltip(
+                    checkbox(tr("color-by-range"), self.settings.color_by_range)
+                        .on_toggle(Message::ColorByRange),
+                    tr("color-by-range-tooltip"),
+                ),
+                with_tooltip(
+                    text(tr("millisecond-notes")),
+                    tr("millisecond-notes-tooltip")
+                ),
+                millisecond_notes,
+                text(tr("stage-values")),
+                Column::with_children(stage_value_rows).spacing(SPACING / 3),
+                text(tr("keyboard-shortcuts")),
+                Column::with_children(keybindings).spacing(SPACING / 3),
+            ]
+            .spacing(SPACING),
+        )
+        .into()
+    }
+
+    fn tempo_input(&self, step: f64) -> Element<'_, Message> {
+        mouse_area(
+            text_input("", self.tempo_input_text.as_str())
+                .id(focus::tempo_input_id())
+                .on_input(Message::StoreTempo)
+                .on_submit(Message::CommitTempoInput)
+                .style(|theme: &Theme, status| {
+                    let mut style = text_input::default(theme, status);
+                    if self.tempo_input_is_invalid {
+                        style.border.color = theme.extended_palette().danger.base.color;
+                    }
+                    style
+                }),
+        )
+        .on_scroll(move |delta| {
+            let y = match delta {
+                ScrollDelta::Lines { y, .. } | ScrollDelta::Pixels { y, .. } => y,
+            };
+            let direction = if y > 0.0 {
+                1.0
+            } else if y < 0.0 {
+                -1.0
+            } else {
+                0.0
+            };
+            Message::ModifyTempo(TempoAction::NudgeBy(direction * step))
+        })
+        .into()
+    }
+
+    // White on black whatever the theme, sized to be read from across a stage
+    fn stage_view(&self) -> Element<'_, Message> {
+        let precision = self.settings.precision();
+
+        let tempo = text(match self.tempo {
+            Some(tempo) => format!("{} BPM", round(tempo, ROUND_LIMIT)),
+            None => NOT_APPLICABLE.to_string(),
+        })
+        .size(STAGE_TEMPO_SIZE);
+
+        let values = self
+            .settings
+            .stage_values
+            .iter()
+            .map(|(note_value, rhythmic_modifier)| {
+                let value = self.tempo.map(|tempo| {
+                    let delay_times = delay_times(tempo, rhythmic_modifier, &self.unit);
+                    delay_time(&delay_times, note_value)
+                });
+
+                column![
+                    text(stage_label(*note_value, rhythmic_modifier)).size(STAGE_LABEL_SIZE),
+                    text(
+                        value
+                            .map(|value| format!("{} {}", round(value, precision), self.unit))
+                            .unwrap_or(NOT_APPLICABLE.to_string())
+                    )
+                    .size(STAGE_VALUE_SIZE),
+                ]
+                .align_x(iced::Alignment::Center)
+                .into()
+            });
+
+        container(
+            column![
+                tempo,
+                Row::with_children(values).spacing(SPACING * 4).wrap(),
+                text(tr("leave-stage")),
+            ]
+            .spacing(SPACING * 4)
+            .align_x(iced::Alignment::Center),
+        )
+        .center(Length::Fill)
+        .style(|_| container::Style {
+            text_color: Some(Color::WHITE),
+            background: Some(Color::BLACK.into()),
+            ..container::Style::default()
+        })
+        .into()
+    }
+
+    fn release_notes_view<'a>(
+        &'a self,
+        release: &'a update_check::Release,
+    ) -> Element<'a, Message> {
+        column![
+            row![
+                text(tr_with(
+                    "release-notes-heading",
+                    &[("version", &release.tag_name)]
+                ))
+                .size(20)
+                .width(Length::Fill),
+                button(tr("copy-release-link")).on_press(Message::CopyReleaseLink),
+                button(tr("done")).on_press(Message::ToggleReleaseNotes),
+            ]
+            .spacing(SPACING)
+            .align_y(iced::Alignment::Center),
+            scrollable(text(
+                release
+                    .body
+                    .clone()
+                    .unwrap_or_else(|| tr("no-release-notes").to_string())
+            ))
+            .height(Length::Fill),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+
+    fn tap_calibration_view<'a>(&'a self, calibration: &TapCalibration) -> Element<'a, Message> {
+        column![
+            row![
+                text(tr("tap-calibration-heading"))
+                    .size(20)
+                    .width(Length::Fill),
+                button(tr("cancel")).on_press(Message::CancelTapCalibration),
+            ]
+            .spacing(SPACING)
+            .align_y(iced::Alignment::Center),
+            text(tr_with(
+                "tap-calibration-instructions",
+                &[("remaining", &calibration.remaining_taps())]
+            )),
+            row![self.pulse_indicator(), tap_button()]
+                .spacing(SPACING)
+                .align_y(iced::Alignment::Center),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+
+    // The whole window as a tap target, for mice and touchscreens mid-show. Like the tap button it
+    // taps on press.
+    fn tap_pad_view(&self) -> Element<'_, Message> {
+        let tempo = text(match self.tempo {
+            Some(tempo) => format!("{} BPM", round(tempo, ROUND_LIMIT)),
+            None => NOT_APPLICABLE.to_string(),
+        })
+        .size(STAGE_VALUE_SIZE);
+
+        let pad = mouse_area(
+            container(
+                column![text(tr("tap")).size(STAGE_LABEL_SIZE), tempo]
+                    .spacing(SPACING)
+                    .align_x(iced::Alignment::Center),
+            )
+            .center(Length::Fill)
+            .style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+                container::Style {
+                    text_color: Some(palette.primary.strong.text),
+                    background: Some(palette.primary.strong.color.into()),
+                    ..container::rounded_box(theme)
+                }
+            }),
+        )
+        .on_press(Message::Tap);
+
+        column![
+            pad,
+            row![
+                text(tr("leave-tap-pad")).width(Length::Fill),
+                button(tr("reset")).on_press(Message::Reset),
+                button(tr("done")).on_press(Message::ToggleTapPad),
+            ]
+            .spacing(SPACING)
+            .align_y(iced::Alignment::Center),
+        ]
+        .spacing(SPACING)
+        .padding(SPACING)
+        .into()
+    }
+
+    // A single strip with the tempo, the tap button, and one straight note value
+    fn mini_view(&self) -> Element<'_, Message> {
+        let value = self
+            .delay_table
+            .as_ref()
+            .and_then(|delay_table| delay_table.columns.first())
+            .map(|delay_times| delay_time(delay_times, &self.mini_note_value));
+
+        let value_button = button(text(
+            value
+                .map(|value| format!("{} {}", round(value, self.settings.precision()), self.unit))
+                .unwrap_or(NOT_APPLICABLE.to_string()),
+        ))
+        .on_press_maybe(value.map(|_| Message::CopyToClipboard(0, self.mini_note_value)));
+
+        let toast = self
+            .toast
+            .as_ref()
+            .map(|toast| text(&toast.message))
+            .unwrap_or(text(""));
+
+        container(
+            row![
+                tap_button(),
+                self.tempo_input(tempo_step(self.modifiers)),
+                pick_list(
+                    NOTE_VALUES,
+                    Some(self.mini_note_value),
+                    Message::SelectMiniNoteValue
+                ),
+                value_button,
+                toast,
+                button(tr("expand")).on_press(Message::ToggleMiniMode),
+            ]
+            .spacing(SPACING / 2)
+            .align_y(iced::Alignment::Center),
+        )
+        .padding(SPACING / 2)
+        .center_y(Length::Fill)
+        .into()
+    }
+
+    fn tempo_history_sidebar(&self) -> Element<'_, Message> {
+        let entries = self.tempo_history.iter().map(|tempo| {
+            button(text(format!("{} BPM", tempo)))
+                .on_press(Message::RestoreTempo(*tempo))
+                .width(Length::Fill)
+                .into()
+        });
+
+        column![
+            text(tr("history")),
+            scrollable(Column::with_children(entries).spacing(SPACING / 3))
+        ]
+        .spacing(SPACING)
+        .width(TEMPO_HISTORY_WIDTH)
+        .into()
+    }
+
+    // MIDI listeners keep running in the background even when another tool is shown
+    fn subscription(&self) -> Subscription<Message> {
+        Subscription::batch([
+            keyboard::on_key_press(|key, modifiers| Some(Message::KeyPressed(key, modifiers))),
+            iced::event::listen_with(|event, _, id| match event {
+                iced::Event::Mouse(iced::mouse::Event::ButtonPressed(_)) => {
+                    Some(Message::CheckFocus)
+                }
+                // Text fields swallow the Escape that unfocuses them
+                iced::Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: Key::Named(Named::Escape),
+                    ..
+                }) => Some(Message::CheckFocus),
+                iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    Some(Message::ModifiersChanged(modifiers))
+                }
+                iced::Event::Window(window::Event::FileDropped(path)) => {
+                    Some(Message::FileDropped(path))
+                }
+                iced::Event::Window(window::Event::Resized(size)) => {
+                    Some(Message::WindowResized(size))
+                }
+                iced::Event::Window(window::Event::Moved(position)) => {
+                    Some(Message::WindowMoved(position))
+                }
+                iced::Event::Window(window::Event::CloseRequested) => {
+                    Some(Message::CloseRequested(id))
+                }
+                _ => None,
+            }),
+            match self.settings.theme {
+                ThemeChoice::System => iced::time::every(SYSTEM_THEME_POLL_INTERVAL)
+                    .map(|_| Message::DetectSystemTheme),
+                ThemeChoice::Theme(_) => Subscription::none(),
+            },
+            self.timecode_chase
+                .subscription()
+                .map(Message::TimecodeChase),
+            self.midi_tap.subscription().map(Message::MidiTap),
+            self.audio_tap.subscription().map(Message::AudioTap),
+            match &self.serve_address {
+                Some(address) => http_api::listen(address.clone()).map(Message::HttpApi),
+                None => Subscription::none(),
+            },
+            if self.can_check_for_updates && self.settings.check_for_updates == Some(true) {
+                update_check::check().map(Message::UpdateCheckFinished)
+            } else {
+                Subscription::none()
+            },
+            match self.tray {
+                Some(_) => tray::listen().map(Message::Tray),
+                None => Subscription::none(),
+            },
+            if self.pipe {
+                pipe::listen().map(Message::Pipe)
+            } else {
+                Subscription::none()
+            },
+            if self.settings.watch_clipboard {
+                iced::time::every(CLIPBOARD_POLL_INTERVAL).map(|_| Message::PollClipboard)
+            } else {
+                Subscription::none()
+            },
+            match self.toast {
+                Some(_) => iced::time::every(TOAST_POLL_INTERVAL).map(|_| Message::ExpireToast),
+                None => Subscription::none(),
+            },
+            if self.settings_save_pending {
+                iced::time::every(SETTINGS_SAVE_INTERVAL).map(|_| Message::FlushSettings)
+            } else {
+                Subscription::none()
+            },
+            if self.pending_nudge != 0.0 {
+                window::frames().map(|_| Message::ApplyPendingNudge)
+            } else {
+                Subscription::none()
+            },
+            match self.unit_switched_at {
+                Some(_) => window::frames().map(Message::FadeInValues),
+                None => Subscription::none(),
+            },
+            match self
+                .tap_calibration
+                .as_ref()
+                .map(|_| CALIBRATION_TEMPO)
+                .or(self.tempo.filter(|_| self.show_pulse))
+            {
+                Some(tempo) => match pulse_interval(tempo, self.settings.tempo_range()) {
+                    Some(interval) => iced::time::every(interval).map(|_| Message::Pulse),
+                    None => Subscription::none(),
+                },
+                None => Subscription::none(),
+            },
+        ])
+    }
+}
+
+// Half a beat, so the pulse is lit for the first half of each one. Tempos from files, the pipe, and
+// the API can fall outside the limits, and an interval too long for a `Duration` would panic.
+fn pulse_interval(tempo: f64, (min_tempo, max_tempo): (f64, f64)) -> Option<Duration> {
+    Duration::try_from_secs_f64(30.0 / tempo.clamp(min_tempo, max_tempo)).ok()
+}
+
+fn handle_key_press(key: Key, modifiers: Modifiers) -> Option<Message> {
+    let step = tempo_step(modifiers);
+
+    match key.as_ref() {
+        Key::Named(Named::ArrowUp) => Some(Message::QueueNudge(step)),
+        Key::Named(Named::ArrowDown) => Some(Message::QueueNudge(-step)),
+        Key::Character(",") if modifiers.command() => Some(Message::TogglePreferences),
+        Key::Named(Named::Escape) => Some(Message::Escape),
+        // Only text fields take focus, so this walks the tempo, tuplet, and tool inputs in layout
+        // order
+        Key::Named(Named::Tab) if modifiers.shift() => Some(Message::FocusPrevious),
+        Key::Named(Named::Tab) => Some(Message::FocusNext),
+        Key::Character("t") => Some(Message::Tap),
+        Key::Character("r") => Some(Message::Reset),
+        Key::Character("1") => Some(Message::ModifyTempo(TempoAction::Halve)),
+        Key::Character("2") => Some(Message::ModifyTempo(TempoAction::Double)),
+        Key::Character("m") => Some(Message::SetUnit(Unit::Milliseconds)),
+        Key::Character("h") => Some(Message::SetUnit(Unit::Hertz)),
+        Key::Character("p") => Some(Message::ToggleAlwaysOnTop),
+        Key::Character("s") => Some(Message::ToggleStageMode),
+        _ => None,
+    }
+}
+
+// Letters and digits belong to the field being typed in, but everything else still works
+fn handle_key_press_while_typing(key: Key, modifiers: Modifiers) -> Option<Message> {
+    match key.as_ref() {
+        Key::Character(_) if !modifiers.command() => None,
+        _ => handle_key_press(key, modifiers),
+    }
+}
+
+fn tempo_step(modifiers: Modifiers) -> f64 {
+    if modifiers.shift() {
+        5.0
+    } else if modifiers.alt() {
+        0.1
+    } else {
+        1.0
+    }
+}
+
+// Buttons fire on release, which lags the beat by however long the click is held, so taps are
+// taken from the press instead. The button itself stays inert and is only styled as if active.
+fn tap_button<'a>() -> Element<'a, Message> {
+    mouse_area(
+        button(tr("tap")).style(|theme: &Theme, _| button::primary(theme, button::Status::Active)),
+    )
+    .on_press(Message::Tap)
+    .into()
+}
+
+// `radio` needs a `Copy` value, which a unit carrying its own definition isn't, so each button only
+// knows whether it's the selected one
+fn unit_radio<'a>(label: String, unit: Unit, selected: &Unit) -> Element<'a, Message> {
+    let is_selected = unit == *selected;
+    radio(label, true, Some(is_selected), move |_| {
+        Message::SetUnit(unit)
+    })
+    .into()
+}
+
+fn with_tooltip<'a>(
+    content: impl Into<Element<'a, Message>>,
+    tip: impl text::IntoFragment<'a>,
+) -> Element<'a, Message> {
+    tooltip(content, text(tip), tooltip::Position::Bottom)
+        .style(container::rounded_box)
+        .into()
+}
+
+// Spells out the arithmetic behind a cell, e.g. "60000 / 120 × 0.75"
+fn formula(
+    tempo: f64,
+    rhythmic_modifier: &RhythmicModifier,
+    note_value: &NoteValue,
+    unit: &Unit,
+) -> String {
+    let multiplier = round(note_value.beats() * rhythmic_modifier.factor(), ROUND_LIMIT);
+    let tempo = round(tempo, ROUND_LIMIT);
+
+    match unit {
+        Unit::Milliseconds | Unit::Both => format!("60000 / {} × {}", tempo, multiplier),
+        Unit::Hertz => format!("{} / 60 / {}", tempo, multiplier),
+        Unit::Ticks(ppqn) => format!("{} × {}", ppqn, multiplier),
+        Unit::Samples(sample_rate) => format!(
+            "60000 / {} × {} × {}",
+            tempo,
+            multiplier,
+            f64::from(*sample_rate) / 1000.0
+        ),
+        Unit::Custom(custom_unit) => match custom_unit.base {
+            CustomBase::Milliseconds => format!(
+                "60000 / {} × {} × {}",
+                tempo, multiplier, custom_unit.factor
+            ),
+            CustomBase::Hertz => {
+                format!("{} / 60 / {} × {}", tempo, multiplier, custom_unit.factor)
+            }
+        },
+    }
+}
+
+// Anything beyond this is a mistyped offset rather than latency
+fn parse_tap_latency(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|offset_ms| offset_ms.abs() <= MAX_TAP_LATENCY_MS)
+}
+
+// A comma-separated list of positive steps, e.g. "1, 5". Returned smallest first without repeats.
+fn parse_nudge_steps(text: &str) -> Option<Vec<f64>> {
+    let mut steps = text
+        .split(',')
+        .map(|step| step.trim().parse::<f64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    if steps.iter().any(|step| !step.is_finite() || *step <= 0.0) {
+        return None;
+    }
+
+    steps.sort_by(f64::total_cmp);
+    steps.dedup();
+    Some(steps)
+}
+
+fn nudge_steps_text(nudge_steps: &[f64]) -> String {
+    nudge_steps
+        .iter()
+        .map(f64::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn tempo_range_text((min_tempo, max_tempo): (f64, f64)) -> String {
+    format!("{}–{}", min_tempo, max_tempo)
+}
+
+// A range of positive tempos, e.g. "70-180" or "70–180"
+fn parse_tempo_range(text: &str) -> Option<(f64, f64)> {
+    let (min_tempo, max_tempo) = text.split_once(['-', '–'])?;
+    let min_tempo = parse_tempo(min_tempo)?;
+    let max_tempo = parse_tempo(max_tempo)?;
+    Some((min_tempo, max_tempo)).filter(|_| min_tempo < max_tempo)
+}
+
+// The half- or double-time equivalent of a tempo outside the preferred range, if one lands inside
+// it. Narrow ranges can have nothing to suggest.
+fn octave_suggestion(tempo: f64, (min_tempo, max_tempo): (f64, f64)) -> Option<f64> {
+    let suggestion = if tempo > max_tempo {
+        tempo / 2.0
+    } else if tempo < min_tempo {
+        tempo * 2.0
+    } else {
+        return None;
+    };
+
+    Some(suggestion).filter(|suggestion| (min_tempo..=max_tempo).contains(suggestion))
+}
+
+// Accepts a bare number like "93.5", or a number labelled as a tempo like "Tempo: 128" or "128bpm"
+fn clipboard_tempo(text: &str) -> Option<f64> {
+    let text = text.trim();
+
+    if text.len() > 100 {
+        return None;
+    }
+
+    if let Ok(tempo) = text.parse::<f64>() {
+        return Some(tempo).filter(|tempo| PLAIN_NUMBER_TEMPO_RANGE.contains(tempo));
+    }
+
+    let lowercase = text.to_lowercase();
+
+    if !lowercase.contains("bpm") && !lowercase.contains("tempo") {
+        return None;
+    }
+
+    lowercase
+        .split(|character: char| !character.is_ascii_digit() && character != '.')
+        .find_map(|number| number.parse::<f64>().ok())
+        .filter(|tempo| tempo.is_finite() && *tempo > 0.0)
+}
+
+// Slashes from note values like 1/8 would read as directories, and the colon in tuplets like 5:4
+// isn't allowed on Windows, so anything unusual becomes a dash
+fn preset_file_name(
+    (rhythmic_modifier, note_value): (RhythmicModifier, NoteValue),
+    tempo: f64,
+    extension: &str,
+) -> String {
+    let name: String = format!(
+        "{} {} {} BPM",
+        rhythmic_modifier,
+        note_value,
+        round(tempo, ROUND_LIMIT)
+    )
+    .chars()
+    .map(|character| {
+        if character.is_ascii_alphanumeric() || matches!(character, ' ' | '_' | '-') {
+            character
+        } else {
+            '-'
+        }
+    })
+    .collect();
+    format!("{}.{}", name, extension)
+}
+
+#[derive(Clone, Copy)]
+struct TableOptions<'a> {
+    unit: &'a Unit,
+    // Shown in milliseconds even in Hertz mode, e.g. short values used as pre-delay
+    millisecond_notes: &'a [NoteValue],
+    // Decimal places shown; copies keep full precision
+    precision: i32,
+    show_note_glyphs: bool,
+    note_names: NoteNames,
+    show_quarter_ratios: bool,
+    // Modifiers run across and note values down, unless this swaps them
+    transpose: bool,
+    // Highlighted while auto-copy is on
+    selected_cell: Option<(RhythmicModifier, NoteValue)>,
+    modifier_colors: &'a [(RhythmicModifier, ColorTag)],
+    // Takes precedence over the column colors
+    color_by_range: bool,
+    // In samples, the common rates are shown alongside the selected one
+    show_both_sample_rates: bool,
+    // The other tempo slot's table, which cells show their difference from
+    delta_table: Option<&'a DelayTable>,
+    // Half- and double-time tables aren't the app's table, so they copy their values directly
+    is_comparison: bool,
+    // Below 1 while the values fade in after a unit switch
+    value_opacity: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CellAction {
+    CopyValue,
+    CopyWithUnit,
+    CopySeconds,
+    CopyFullPrecision,
+    // As a 0–1 value in the automation range from preferences
+    CopyNormalized,
+    CopyRow,
+    CopyColumn,
+    // Makes the cell the one auto-copy follows and presets are made from, without copying it
+    SetActive,
+}
+
+const CELL_ACTIONS: [CellAction; 8] = [
+    CellAction::CopyValue,
+    CellAction::CopyWithUnit,
+    CellAction::CopySeconds,
+    CellAction::CopyFullPrecision,
+    CellAction::CopyNormalized,
+    CellAction::CopyRow,
+    CellAction::CopyColumn,
+    CellAction::SetActive,
+];
+
+impl CellAction {
+    fn label(self) -> &'static str {
+        match self {
+            CellAction::CopyValue => tr("menu-copy-value"),
+            CellAction::CopyWithUnit => tr("menu-copy-with-unit"),
+            CellAction::CopySeconds => tr("menu-copy-seconds"),
+            CellAction::CopyFullPrecision => tr("menu-copy-full-precision"),
+            CellAction::CopyNormalized => tr("menu-copy-normalized"),
+            CellAction::CopyRow => tr("menu-copy-row"),
+            CellAction::CopyColumn => tr("menu-copy-column"),
+            CellAction::SetActive => tr("menu-set-active"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TempoSlot {
+    A,
+    B,
+}
+
+impl TempoSlot {
+    fn other(self) -> Self {
+        match self {
+            TempoSlot::A => TempoSlot::B,
+            TempoSlot::B => TempoSlot::A,
+        }
+    }
+}
+
+impl Display for TempoSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TempoSlot::A => write!(f, "A"),
+            TempoSlot::B => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ComparisonFactor {
+    Half,
+    Normal,
+    Double,
+}
+
+impl ComparisonFactor {
+    fn label(self) -> &'static str {
+        match self {
+            ComparisonFactor::Half => "½×",
+            ComparisonFactor::Normal => "1×",
+            ComparisonFactor::Double => "2×",
+        }
+    }
+}
+
+// Rebuilds a cached table only when what it was built from has changed
+fn refresh_table(
+    table: &mut Option<DelayTable>,
+    tempo: Option<f64>,
+    unit: &Unit,
+    rhythmic_modifiers: &[RhythmicModifier],
+) {
+    let is_stale = match (&*table, tempo) {
+        (Some(table), Some(tempo)) => {
+            table.tempo != tempo
+                || table.unit != *unit
+                || table.rhythmic_modifiers != rhythmic_modifiers
+        }
+        (None, None) => false,
+        _ => true,
+    };
+
+    if is_stale {
+        *table =
+            tempo.map(|tempo| DelayTable::new(tempo, unit.clone(), rhythmic_modifiers.to_vec()));
+    }
+}
+
+// Comparing grooves shouldn't cost the tempo, the way halving or doubling it does
+fn compare_tempos(tempo: f64) -> [(ComparisonFactor, f64); 3] {
+    [
+        (ComparisonFactor::Half, tempo / 2.0),
+        (ComparisonFactor::Normal, tempo),
+        (ComparisonFactor::Double, tempo * 2.0),
+    ]
+}
+
+fn table<'a>(
+    delay_table: Option<&DelayTable>,
+    rhythmic_modifiers: &[RhythmicModifier],
+    options: TableOptions<'_>,
+    toast: Option<&Toast>,
+) -> Row<'a, Message, Theme, Renderer> {
+    let blank_cell = || text("").height(Length::Fill).into(); // Is there a better way to add a blank cell?
+    let cell = |index: usize, note_value: NoteValue| {
+        let cached =
+            delay_table.map(|delay_table| (delay_table.tempo, &delay_table.columns[index]));
+        value_cell(
+            cached,
+            index,
+            &rhythmic_modifiers[index],
+            note_value,
+            options,
+            toast,
+        )
+    };
+
+    let columns: Vec<Vec<Element<_>>> =
+        if options.transpose {
+            let labels =
+                std::iter::once(blank_cell())
+                    .chain(rhythmic_modifiers.iter().enumerate().map(
+                        |(index, rhythmic_modifier)| {
+                            modifier_label(index, rhythmic_modifier, options.is_comparison)
+                        },
+                    ))
+                    .collect();
+
+            std::iter::once(labels)
+                .chain(NOTE_VALUES.map(|note_value| {
+                    std::iter::once(note_label(note_value, options))
+                        .chain((0..rhythmic_modifiers.len()).map(|index| cell(index, note_value)))
+                        .collect()
+                }))
+                .collect()
+        } else {
+            let labels = std::iter::once(blank_cell())
+                .chain(NOTE_VALUES.map(|note_value| note_label(note_value, options)))
+                .collect();
+
+            std::iter::once(labels)
+                .chain(
+                    rhythmic_modifiers
+                        .iter()
+                        .enumerate()
+                        .map(|(index, rhythmic_modifier)| {
+                            std::iter::once(modifier_label(
+                                index,
+                                rhythmic_modifier,
+                                options.is_comparison,
+                            ))
+                            .chain(NOTE_VALUES.map(|note_value| cell(index, note_value)))
+                            .collect()
+                        }),
+                )
+                .collect()
+        };
+
+    Row::with_children(columns.into_iter().map(|column| {
+        Column::with_children(column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .spacing(SPACING)
+            .into()
+    }))
+    .spacing(SPACING)
+}
+
+fn stage_label(note_value: NoteValue, rhythmic_modifier: &RhythmicModifier) -> String {
+    match rhythmic_modifier {
+        RhythmicModifier::Normal => note_value.to_string(),
+        _ => format!("{} {}", rhythmic_modifier.label(), note_value),
+    }
+}
+
+// Keeps the chosen values in table order however they were picked
+fn toggle_stage_value(
+    stage_values: &[(NoteValue, RhythmicModifier)],
+    toggled: (NoteValue, RhythmicModifier),
+    is_shown: bool,
+) -> Vec<(NoteValue, RhythmicModifier)> {
+    NOTE_VALUES
+        .iter()
+        .flat_map(|note_value| {
+            RHYTHMIC_MODIFIER
+                .iter()
+                .map(move |rhythmic_modifier| (*note_value, *rhythmic_modifier))
+        })
+        .filter(|value| {
+            if *value == toggled {
+                is_shown
+            } else {
+                stage_values.contains(value)
+            }
+        })
+        .collect()
+}
+
+// Right-clicking a note value label copies its values for every modifier
+fn note_label<'a>(note_value: NoteValue, options: TableOptions<'_>) -> Element<'a, Message> {
+    let label: Element<'a, Message> = if options.show_note_glyphs {
+        canvas(NoteGlyph { note_value })
+            .width(note_glyph::width(note_value, NOTE_GLYPH_HEIGHT))
+            .height(NOTE_GLYPH_HEIGHT)
+            .into()
+    } else {
+        text(format!("{}:", note_value.label(options.note_names)))
+            .height(Length::Fill)
+            .into()
+    };
+
+    if options.is_comparison {
+        return label;
+    }
+
+    mouse_area(label)
+        .on_right_press(Message::CopyRow(note_value))
+        .into()
+}
+
+// Right-clicking a modifier label copies its values for every note value
+fn modifier_label<'a>(
+    index: usize,
+    rhythmic_modifier: &RhythmicModifier,
+    is_comparison: bool,
+) -> Element<'a, Message> {
+    let label = text(rhythmic_modifier.label()).height(Length::Fill);
+
+    if is_comparison {
+        return label.into();
+    }
+
+    mouse_area(label)
+        .on_right_press(Message::CopyColumn(index))
+        .into()
+}
+
+// The tag colors are all light, so dark text reads on every one of them
+fn colored_cell(fill: Color, theme: &Theme, status: button::Status) -> button::Style {
+    let fill = match status {
+        button::Status::Hovered | button::Status::Pressed => Color { a: 0.8, ..fill },
+        _ => fill,
+    };
+
+    button::Style {
+        background: Some(fill.into()),
+        text_color: Color::BLACK,
+        ..button::primary(theme, status)
+    }
+}
+
+fn value_cell<'a>(
+    cached: Option<(f64, &delay_times::DelayTimes)>,
+    index: usize,
+    rhythmic_modifier: &RhythmicModifier,
+    note_value: NoteValue,
+    options: TableOptions<'_>,
+    toast: Option<&Toast>,
+) -> Element<'a, Message> {
+    let tempo = cached.map(|(tempo, _)| tempo);
+    let (value, unit) = match cached {
+        Some((_, delay_times)) => {
+            let (value, unit) = cell_value(
+                delay_times,
+                note_value,
+                options.unit,
+                options.millisecond_notes,
+            );
+            (Some(value), unit)
+        }
+        None => (None, options.unit),
+    };
+
+    let toast = toast.filter(|toast| {
+        toast.cell.is_some_and(|(toast_index, toast_note_value)| {
+            toast_index == index && toast_note_value == note_value
+        })
+    });
+
+    let display_text = match (toast, value) {
+        (Some(toast), _) => toast.message.clone(),
+        (None, Some(value)) => {
+            let mut display_text = format!("{} {}", round(value, options.precision), unit);
+            if let (true, Unit::Samples(sample_rate)) = (options.show_both_sample_rates, unit) {
+                display_text = format!("{} @ {}", display_text, sample_rate_label(*sample_rate));
+            }
+            // Delays pushed into audible range are heard as pitches, so name the one they land on
+            if let (Unit::Hertz, Some(label)) = (unit, comb_tuning::pitch_label(value)) {
+                display_text = format!("{} {}", display_text, label);
+            }
+            if options.show_quarter_ratios {
+                display_text = format!(
+                    "{} ×{}",
+                    display_text,
+                    round(quarter_ratio(rhythmic_modifier, &note_value), ROUND_LIMIT)
+                );
+            }
+            display_text
+        }
+        (None, None) => NOT_APPLICABLE.to_string(),
+    };
+
+    let secondary_text = match (toast, value) {
+        (None, Some(value)) if *unit == Unit::Both => {
+            Some(format!("/ {} Hz", round(1000.0 / value, options.precision)))
+        }
+        (None, Some(value)) if options.show_both_sample_rates => match unit {
+            Unit::Samples(sample_rate) => Some(
+                other_sample_rates(value, *sample_rate)
+                    .map(|(other_rate, other_value)| {
+                        format!(
+                            "/ {} @ {}",
+                            round(other_value, options.precision),
+                            sample_rate_label(other_rate)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+            .filter(|secondary_text| !secondary_text.is_empty()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let delta_text = options
+        .delta_table
+        .and_then(|delta_table| delta_table.columns.get(index))
+        .zip(value.filter(|_| toast.is_none()))
+        .map(|(delta_times, value)| {
+            let (other_value, _) = cell_value(
+                delta_times,
+                note_value,
+                options.unit,
+                options.millisecond_notes,
+            );
+            format!("Δ {:+}", round(value - other_value, options.precision))
+        });
+
+    let mut button = button(
+        row![Text::new(display_text)]
+            .push_maybe(
+                secondary_text.map(|secondary_text| text(secondary_text).size(SECONDARY_UNIT_SIZE)),
+            )
+            .push_maybe(delta_text.map(|delta_text| text(delta_text).size(SECONDARY_UNIT_SIZE)))
+            .spacing(SPACING / 2)
+            .align_y(iced::Alignment::Center),
+    );
+
+    if let Some(value) = value {
+        button = button.on_press(if options.is_comparison {
+            Message::CopyValue(value, unit.clone())
+        } else {
+            Message::CopyToClipboard(index, note_value)
+        });
+    };
+
+    let milliseconds = tempo
+        .zip(value)
+        .map(|(tempo, value)| unit.to_milliseconds(value, tempo));
+    let is_in_haas_zone = milliseconds.is_some_and(|ms| HAAS_ZONE_MS.contains(&ms));
+
+    let range_color = milliseconds
+        .filter(|_| options.color_by_range)
+        .and_then(|ms| DelayRange::of(ms).color_tag().color());
+    let column_color = options
+        .modifier_colors
+        .iter()
+        .find(|(colored_modifier, _)| colored_modifier == rhythmic_modifier)
+        .and_then(|(_, color_tag)| color_tag.color());
+    let fill = range_color.or(column_color);
+
+    let is_auto_copied = options.selected_cell == Some((*rhythmic_modifier, note_value));
+    let value_opacity = options.value_opacity;
+
+    let button =
+        button
+            .height(Length::Fill)
+            .width(Length::Fill)
+            .style(move |theme: &Theme, status| {
+                let style = if is_auto_copied {
+                    button::success(theme, status)
+                } else if let Some(fill) = fill {
+                    colored_cell(fill, theme, status)
+                } else if is_in_haas_zone {
+                    button::secondary(theme, status)
+                } else {
+                    button::primary(theme, status)
+                };
+                button::Style {
+                    text_color: style.text_color.scale_alpha(value_opacity),
+                    ..style
+                }
+            });
+
+    let button: Element<_> = if value.is_some() && !options.is_comparison {
+        mouse_area(button)
+            .on_right_press(Message::OpenCellMenu(index, note_value))
+            .into()
+    } else {
+        button.into()
+    };
+
+    match (tempo, value) {
+        (Some(tempo), Some(value)) => with_tooltip(
+            button,
+            format!(
+                "{} {}\n{}{}",
+                value,
+                unit,
+                formula(tempo, rhythmic_modifier, &note_value, unit),
+                if is_in_haas_zone {
+                    format!("\n{}", tr("haas-zone-tooltip"))
+                } else {
+                    String::new()
+                }
+            ),
+        ),
+        _ => button,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Writes run as tasks, which tests don't execute, so nothing ever reaches this
+    fn no_clipboard() -> clipboard::Handle {
+        clipboard::Handle::new(|| Err("No clipboard in tests".to_string()))
+    }
+
+    fn app() -> Tap {
+        Tap::new(Environment {
+            clipboard: no_clipboard(),
+            settings: settings::Settings::default(),
+            settings_path: None,
+            preset_templates: Vec::new(),
+            system_theme: Theme::Dark,
+            serve_address: None,
+            pipe: false,
+            can_check_for_updates: true,
+        })
+    }
+
+    fn cell(app: &Tap, index: usize, note_value: NoteValue) -> f64 {
+        let delay_table = app.delay_table.as_ref().unwrap();
+        delay_time(&delay_table.columns[index], &note_value)
+    }
+
+    #[test]
+    fn parses_nudge_steps() {
+        assert_eq!(parse_nudge_steps("1, 5"), Some(vec![1.0, 5.0]));
+        assert_eq!(parse_nudge_steps("10,0.5,10"), Some(vec![0.5, 10.0]));
+        assert_eq!(parse_nudge_steps(""), None);
+        assert_eq!(parse_nudge_steps("1, -5"), None);
+        assert_eq!(parse_nudge_steps("1,"), None);
+    }
+
+    #[test]
+    fn octave_suggestions() {
+        assert_eq!(parse_tempo_range("70-180"), Some((70.0, 180.0)));
+        assert_eq!(parse_tempo_range("70 – 180"), Some((70.0, 180.0)));
+        assert_eq!(parse_tempo_range("180-70"), None);
+        assert_eq!(parse_tempo_range("70"), None);
+
+        assert_eq!(octave_suggestion(174.0, (70.0, 180.0)), None);
+        assert_eq!(octave_suggestion(190.0, (70.0, 180.0)), Some(95.0));
+        assert_eq!(octave_suggestion(60.0, (70.0, 180.0)), Some(120.0));
+        assert_eq!(octave_suggestion(60.0, (70.0, 100.0)), None);
+    }
+
+    #[test]
+    fn tempo_step_follows_modifiers() {
+        assert_eq!(tempo_step(Modifiers::empty()), 1.0);
+        assert_eq!(tempo_step(Modifiers::SHIFT), 5.0);
+        assert_eq!(tempo_step(Modifiers::ALT), 0.1);
+    }
+
+    #[test]
+    fn modify_tempo_updates_state_and_table() {
+        let mut app = app();
+
+        let _ = app.update(Message::ModifyTempo(TempoAction::Double));
+        assert_eq!(app.tempo, Some(240.0));
+        assert_eq!(app.tempo_input_text, "240");
+        assert_eq!(app.tempo_history.front(), Some(&240.0));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 250.0);
+
+        // Nudges aren't recorded in the history
+        let _ = app.update(Message::ModifyTempo(TempoAction::NudgeBy(1.0)));
+        assert_eq!(app.tempo, Some(241.0));
+        assert_eq!(app.tempo_history.len(), 1);
+    }
+
+    #[test]
+    fn held_nudges_apply_once_a_frame() {
+        let mut app = app();
+
+        for _ in 0..3 {
+            let _ = app.update(Message::QueueNudge(0.1));
+        }
+        let _ = app.update(Message::QueueNudge(5.0));
+        assert_eq!(app.tempo, Some(120.0));
+
+        let _ = app.update(Message::ApplyPendingNudge);
+        assert_eq!(app.tempo, Some(125.3));
+        assert_eq!(app.pending_nudge, 0.0);
+    }
+
+    #[test]
+    fn modify_tempo_clamps_to_range() {
+        let mut app = app();
+
+        let _ = app.update(Message::StoreTempo("2".to_string()));
+        for _ in 0..5 {
+            let _ = app.update(Message::ModifyTempo(TempoAction::NudgeBy(-1.0)));
+        }
+        assert_eq!(app.tempo, Some(1.0));
+        assert!(app.tempo_was_clamped);
+
+        let _ = app.update(Message::StoreTempo("5000".to_string()));
+        let _ = app.update(Message::SubmitTempo);
+        assert_eq!(app.tempo, Some(999.0));
+        assert_eq!(app.tempo_input_text, "999");
+    }
+
+    #[test]
+    fn tempo_limits_are_set_in_preferences() {
+        let mut app = app();
+
+        let _ = app.update(Message::StoreMinTempo("60".to_string()));
+        let _ = app.update(Message::StoreMaxTempo("200".to_string()));
+        assert_eq!(app.settings.tempo_range(), (60.0, 200.0));
+
+        // A minimum above the maximum waits for the maximum to follow
+        let _ = app.update(Message::StoreMinTempo("300".to_string()));
+        assert_eq!(app.settings.tempo_range(), (60.0, 200.0));
+
+        let _ = app.update(Message::StoreTempo("250".to_string()));
+        let _ = app.update(Message::SubmitTempo);
+        assert_eq!(app.tempo, Some(200.0));
+
+        // Slid and restored tempos are held to the same limits
+        let _ = app.update(Message::SlideTempo(40.0));
+        assert_eq!(app.tempo, Some(60.0));
+        let _ = app.update(Message::RestoreTempo(300.0));
+        assert_eq!(app.tempo, Some(200.0));
+        assert!(app.tempo_was_clamped);
+    }
+
+    #[test]
+    fn invalid_tempo_input() {
+        let mut app = app();
+
+        let _ = app.update(Message::StoreTempo("12a".to_string()));
+        assert_eq!(app.tempo, None);
+        assert!(app.tempo_input_is_invalid);
+        assert!(app.delay_table.is_none());
+
+        let _ = app.update(Message::StoreTempo("60*2".to_string()));
+        assert_eq!(app.tempo, Some(120.0));
+        assert!(!app.tempo_input_is_invalid);
+    }
+
+    #[test]
+    fn unit_switching() {
+        let mut app = app();
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 500.0);
+
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        assert_eq!(app.unit, Unit::Hertz);
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 2.0);
+
+        let _ = app.update(Message::SetUnit(Unit::Ticks(96)));
+        let _ = app.update(Message::SetPpqn(480));
+        assert_eq!(app.unit, Unit::Ticks(480));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 480.0);
+
+        let _ = app.update(Message::SetUnit(Unit::Samples(48_000)));
+        let _ = app.update(Message::SetSampleRate(44_100));
+        assert_eq!(app.unit, Unit::Samples(44_100));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 22_050.0);
+        assert_eq!(
+            Unit::Samples(44_100).to_milliseconds(22_050.0, 120.0),
+            500.0
+        );
+        assert_eq!(
+            other_sample_rates(22_050.0, 44_100).collect::<Vec<_>>(),
+            [(48_000, 24_000.0)]
+        );
+        assert_eq!(sample_rate_label(44_100), "44.1k");
+
+        let _ = app.update(Message::SetUnit(Unit::Milliseconds));
+        assert_eq!(app.unit, Unit::Milliseconds);
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 500.0);
+
+        // Both keeps milliseconds as the value, with Hertz only shown beside it
+        let _ = app.update(Message::SetUnit(Unit::Both));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 500.0);
+    }
+
+    #[test]
+    fn custom_unit() {
+        let mut app = app();
+
+        let _ = app.update(Message::SetUnit(app.settings.custom_as_unit()));
+        let _ = app.update(Message::StoreCustomUnitFactor("2".to_string()));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 1000.0);
+        assert_eq!(app.unit.to_milliseconds(1000.0, 120.0), 500.0);
+
+        let _ = app.update(Message::SetCustomUnitBase(CustomBase::Hertz));
+        assert_eq!(cell(&app, 0, NoteValue::Quarter), 4.0);
+        assert_eq!(app.unit.to_milliseconds(4.0, 120.0), 500.0);
+
+        // Unusable factors are kept in the field but not applied
+        let _ = app.update(Message::StoreCustomUnitFactor("0".to_string()));
+        assert_eq!(app.settings.custom_unit.factor, 2.0);
+    }
+
+    #[test]
+    fn update_notices() {
+        let mut app = app();
+        let release = |tag_name: &str| update_check::Release {
+            tag_name: tag_name.to_string(),
+            html_url: "https://example.com".to_string(),
+            body: None,
+        };
+
+        let _ = app.update(Message::UpdateCheckFinished(Ok(release("v0.0.1"))));
+        assert_eq!(app.available_update, None);
+
+        let _ = app.update(Message::UpdateCheckFinished(Ok(release("v99.0.0"))));
+        assert_eq!(app.available_update, Some(release("v99.0.0")));
+
+        let _ = app.update(Message::DismissUpdate);
+        assert_eq!(app.available_update, None);
+
+        // Nothing is checked until the first launch's question is answered
+        assert_eq!(app.settings.check_for_updates, None);
+        let _ = app.update(Message::CheckForUpdates(false));
+        assert_eq!(app.settings.check_for_updates, Some(false));
+    }
+
+    #[test]
+    fn tempo_slots() {
+        let mut app = app();
+
+        let _ = app.update(Message::StoreOtherTempo("90".to_string()));
+        let _ = app.update(Message::SwitchTempoSlot);
+        assert_eq!(app.tempo_slot, TempoSlot::B);
+        assert_eq!(app.tempo, Some(90.0));
+        assert_eq!(app.other_tempo, Some(120.0));
+        assert_eq!(app.other_tempo_text, "120");
+
+        let _ = app.update(Message::SwitchTempoSlot);
+        assert_eq!(app.tempo_slot, TempoSlot::A);
+        assert_eq!(app.tempo, Some(120.0));
+        assert_eq!(app.tempo_input_text, "120");
+    }
+
+    #[test]
+    fn tempo_input_focus() {
+        let mut app = app();
+        let typing = focus::TextFocus {
+            any_input: true,
+            tempo_input: true,
+        };
+
+        let _ = app.update(Message::FocusChanged(typing));
+        let _ = app.update(Message::StoreTempo("128.00049".to_string()));
+        let _ = app.update(Message::FocusChanged(focus::TextFocus::default()));
+        assert_eq!(app.tempo_input_text, "128");
+        assert_eq!(app.tempo_history.front(), Some(&128.0));
+
+        let _ = app.update(Message::FocusChanged(typing));
+        let _ = app.update(Message::StoreTempo("500ms".to_string()));
+        let _ = app.update(Message::CommitTempoInput);
+        assert_eq!(app.tempo_input_text, "120");
+        assert_eq!(app.text_focus, focus::TextFocus::default());
+
+        let t = Key::Character("t".into());
+        assert!(handle_key_press_while_typing(t.clone(), Modifiers::empty()).is_none());
+        assert!(matches!(
+            handle_key_press(t, Modifiers::empty()),
+            Some(Message::Tap)
+        ));
+        assert!(matches!(
+            handle_key_press_while_typing(Key::Named(Named::Escape), Modifiers::empty()),
+            Some(Message::Escape)
+        ));
+    }
+
+    #[test]
+    fn shortcuts_follow_focus() {
+        let mut app = app();
+        let typing = focus::TextFocus {
+            any_input: true,
+            tempo_input: false,
+        };
+        let h = Key::Character("h".into());
+
+        let _ = app.update(Message::Shortcut(typing, h.clone(), Modifiers::empty()));
+        assert_eq!(app.unit, Unit::Milliseconds);
+        assert_eq!(app.text_focus, typing);
+
+        let _ = app.update(Message::Shortcut(
+            focus::TextFocus::default(),
+            h,
+            Modifiers::empty(),
+        ));
+        assert_eq!(app.unit, Unit::Hertz);
+    }
+
+    #[test]
+    fn modifier_colors() {
+        let mut app = app();
+
+        let _ = app.update(Message::SetModifierColor(
+            RhythmicModifier::Dotted,
+            ColorTag::Green,
+        ));
+        let _ = app.update(Message::SetModifierColor(
+            RhythmicModifier::Dotted,
+            ColorTag::Blue,
+        ));
+        assert_eq!(
+            app.settings.modifier_colors,
+            [(RhythmicModifier::Dotted, ColorTag::Blue)]
+        );
+
+        // Clearing a color drops the entry rather than storing an empty one
+        let _ = app.update(Message::SetModifierColor(
+            RhythmicModifier::Dotted,
+            ColorTag::None,
+        ));
+        assert!(app.settings.modifier_colors.is_empty());
+        assert_eq!(
+            app.settings.modifier_color(RhythmicModifier::Dotted),
+            ColorTag::None
+        );
+    }
+
+    #[test]
+    fn tuplet_columns() {
+        assert_eq!(
+            RhythmicModifier::parse_tuplet("5:4"),
+            Some(RhythmicModifier::Tuplet(5, 4))
+        );
+        assert_eq!(RhythmicModifier::parse_tuplet("5"), None);
+        assert_eq!(RhythmicModifier::parse_tuplet("0:4"), None);
+
+        // Five quarter notes in the time of four at 120 BPM
+        let tuplet = delay_times(120.0, &RhythmicModifier::Tuplet(5, 4), &Unit::Milliseconds);
+        assert_eq!(delay_time(&tuplet, &NoteValue::Quarter), 400.0);
+        // A 3:2 tuplet is a triplet
+        let three_in_two = delay_times(120.0, &RhythmicModifier::Tuplet(3, 2), &Unit::Milliseconds);
+        let triplet = delay_times(120.0, &RhythmicModifier::Triplet, &Unit::Milliseconds);
+        assert_eq!(
+            round(delay_time(&three_in_two, &NoteValue::Eighth), ROUND_LIMIT),
+            round(delay_time(&triplet, &NoteValue::Eighth), ROUND_LIMIT)
+        );
+
+        let mut app = app();
+        let _ = app.update(Message::StoreTuplet("7:8".to_string()));
+        let delay_table = app.delay_table.as_ref().unwrap();
+        assert_eq!(delay_table.columns.len(), RHYTHMIC_MODIFIER.len() + 1);
+    }
+
+    #[test]
+    fn centres_without_a_saved_position() {
+        assert_eq!(
+            saved_window_position(Size::new(600.0, 400.0), Size::new(1000.0, 800.0)),
+            Point::new(200.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn finds_tempos_on_the_clipboard() {
+        assert_eq!(clipboard_tempo("93.5"), Some(93.5));
+        assert_eq!(clipboard_tempo(" Tempo: 128 "), Some(128.0));
+        assert_eq!(clipboard_tempo("174bpm"), Some(174.0));
+        assert_eq!(clipboard_tempo("3"), None);
+        assert_eq!(clipboard_tempo("Page 128"), None);
+    }
+
+    #[test]
+    fn preset_file_names_are_safe_everywhere() {
+        assert_eq!(
+            preset_file_name(
+                (RhythmicModifier::Dotted, NoteValue::Eighth),
+                120.0,
+                "vstpreset"
+            ),
+            "Dotted 1-8 120 BPM.vstpreset"
+        );
+        assert_eq!(
+            preset_file_name(
+                (RhythmicModifier::Tuplet(5, 4), NoteValue::Quarter),
+                92.5,
+                "fxp"
+            ),
+            "Tuplet 5-4 1-4 92-5 BPM.fxp"
+        );
+    }
+
+    #[test]
+    fn stage_values_stay_in_table_order() {
+        let stage_values = vec![(NoteValue::Eighth, RhythmicModifier::Normal)];
+
+        let added = toggle_stage_value(
+            &stage_values,
+            (NoteValue::Quarter, RhythmicModifier::Dotted),
+            true,
+        );
+        assert_eq!(
+            added,
+            [
+                (NoteValue::Quarter, RhythmicModifier::Dotted),
+                (NoteValue::Eighth, RhythmicModifier::Normal),
+            ]
+        );
+
+        let removed =
+            toggle_stage_value(&added, (NoteValue::Eighth, RhythmicModifier::Normal), false);
+        assert_eq!(removed, [(NoteValue::Quarter, RhythmicModifier::Dotted)]);
+    }
+
+    #[test]
+    fn starts_with_the_preferred_tempo_and_unit() {
+        let app = Tap::new(Environment {
+            clipboard: no_clipboard(),
+            settings: settings::Settings {
+                startup_tempo: 90.0,
+                default_unit: Unit::Hertz,
+                ..settings::Settings::default()
+            },
+            settings_path: None,
+            preset_templates: Vec::new(),
+            system_theme: Theme::Dark,
+            serve_address: None,
+            pipe: false,
+            can_check_for_updates: true,
+        });
+
+        assert_eq!(app.tempo, Some(90.0));
+        assert_eq!(app.delay_table.as_ref().unwrap().unit, Unit::Hertz);
+    }
+
+    #[test]
+    fn saves_settings_where_it_was_told() {
+        let directory =
+            std::env::temp_dir().join(format!("delay_time_settings_{}", std::process::id()));
+        let path = directory.join("settings.json");
+        let mut app = app();
+        app.settings_path = Some(path.clone());
+
+        let _ = app.update(Message::SetNoteNames(NoteNames::British));
+        let saved: settings::Settings =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.note_names, NoteNames::British);
+
+        let _ = std::fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn typed_preferences_save_later() {
+        let directory =
+            std::env::temp_dir().join(format!("delay_time_typing_{}", std::process::id()));
+        let path = directory.join("settings.json");
+        let mut app = app();
+        app.settings_path = Some(path.clone());
+
+        let _ = app.update(Message::StoreStartupTempo("9".to_string()));
+        let _ = app.update(Message::StoreStartupTempo("96".to_string()));
+        assert!(!path.exists());
+
+        let _ = app.update(Message::FlushSettings);
+        let saved: settings::Settings =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(saved.startup_tempo, 96.0);
+        assert!(!app.settings_save_pending);
+
+        let _ = std::fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn auto_copy_follows_the_tempo() {
+        let mut app = app();
+
+        let _ = app.update(Message::ModifyTempo(TempoAction::Double));
+        assert_eq!(app.last_clipboard_text, None);
+
+        let _ = app.update(Message::AutoCopy(true));
+        let _ = app.update(Message::ModifyTempo(TempoAction::NudgeBy(-120.0)));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("375"));
+
+        // Clicking a cell makes it the one that follows
+        let _ = app.update(Message::CopyToClipboard(0, NoteValue::Quarter));
+        let _ = app.update(Message::ModifyTempo(TempoAction::Halve));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("1000"));
+
+        // Only the finished tempo is copied, not each keystroke or step of the slider
+        app.last_clipboard_text = None;
+        for text in ["1", "12", "120"] {
+            let _ = app.update(Message::StoreTempo(text.to_string()));
+        }
+        let _ = app.update(Message::SlideTempo(90.0));
+        assert_eq!(app.last_clipboard_text, None);
+        let _ = app.update(Message::StoreTempo("120".to_string()));
+        let _ = app.update(Message::SubmitTempo);
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("500"));
+    }
+
+    #[test]
+    fn comparison_keeps_the_tempo() {
+        let mut app = app();
+
+        let _ = app.update(Message::ShowComparison(true));
+        assert_eq!(app.tempo, Some(120.0));
+        assert_eq!(
+            compare_tempos(120.0).map(|(_, tempo)| tempo),
+            [60.0, 120.0, 240.0]
+        );
+
+        let _ = app.update(Message::CopyValue(1000.0, Unit::Milliseconds));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("1000"));
+        assert_eq!(app.tempo, Some(120.0));
+    }
+
+    #[test]
+    fn pulse_interval_stays_in_range() {
+        let range = (20.0, 999.0);
+        assert_eq!(
+            pulse_interval(120.0, range),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(
+            pulse_interval(1e-300, range),
+            Some(Duration::from_secs_f64(1.5))
+        );
+        assert_eq!(pulse_interval(f64::NAN, range), None);
+    }
+
+    #[test]
+    fn side_tables_are_built_in_update() {
+        let mut app = app();
+        assert!(app.comparison_tables.iter().all(Option::is_none));
+
+        let _ = app.update(Message::ShowComparison(true));
+        let tempos = app
+            .comparison_tables
+            .each_ref()
+            .map(|table| table.as_ref().map(|table| table.tempo));
+        assert_eq!(tempos, [Some(60.0), None, Some(240.0)]);
+
+        let _ = app.update(Message::StoreOtherTempo("90".to_string()));
+        assert!(app.delta_table.is_none());
+        let _ = app.update(Message::ShowTempoDeltas(true));
+        assert_eq!(
+            app.delta_table.as_ref().map(|table| table.tempo),
+            Some(90.0)
+        );
+
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        assert_eq!(
+            app.delta_table.as_ref().map(|table| table.unit.clone()),
+            Some(Unit::Hertz)
+        );
+        assert_eq!(
+            app.comparison_tables[0]
+                .as_ref()
+                .map(|table| table.unit.clone()),
+            Some(Unit::Hertz)
+        );
+    }
+
+    #[test]
+    fn pinned_notes_stay_in_milliseconds() {
+        let mut app = app();
+
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        let _ = app.update(Message::PinToMilliseconds(NoteValue::SixtyFourth, true));
+
+        let _ = app.update(Message::CopyToClipboard(0, NoteValue::SixtyFourth));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("31.25"));
+        let _ = app.update(Message::CopyToClipboard(0, NoteValue::Quarter));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn unit_switches_fade_the_values_in() {
+        let mut app = app();
+
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        let unit_switched_at = app.unit_switched_at.unwrap();
+        assert_eq!(app.value_opacity, 0.0);
+
+        let _ = app.update(Message::FadeInValues(
+            unit_switched_at + UNIT_FADE_DURATION / 2,
+        ));
+        assert_eq!(app.value_opacity, 0.5);
+
+        let _ = app.update(Message::FadeInValues(unit_switched_at + UNIT_FADE_DURATION));
+        assert_eq!((app.unit_switched_at, app.value_opacity), (None, 1.0));
+
+        // Other changes don't fade anything
+        let _ = app.update(Message::ModifyTempo(TempoAction::Double));
+        assert_eq!(app.unit_switched_at, None);
+    }
+
+    #[test]
+    fn rounded_copies_warn_about_drift() {
+        let mut app = app();
+        app.submit_tempo(128.0);
+
+        // A whole note at 128 BPM is 1875 ms, or 0.533 Hz once rounded, which is only a ms out
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        assert!(app.toast.is_none());
+
+        // A quadruple whole note at 40 BPM is 24000 ms, or 0.042 Hz once rounded
+        let _ = app.update(Message::SetUnit(Unit::Milliseconds));
+        app.submit_tempo(40.0);
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        assert!(app.toast.as_ref().is_some_and(|toast| !toast.is_error));
+
+        let _ = app.update(Message::OpenCellMenu(0, NoteValue::QuadrupleWhole));
+        let _ = app.update(Message::CellMenu(CellAction::CopyValue));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("0.042"));
+
+        let _ = app.update(Message::ExactCopies(true));
+        let _ = app.update(Message::OpenCellMenu(0, NoteValue::QuadrupleWhole));
+        let _ = app.update(Message::CellMenu(CellAction::CopyValue));
+        assert_eq!(
+            app.last_clipboard_text.as_deref(),
+            Some((1000.0 / 24000.0).to_string().as_str())
+        );
+
+        app.toast = None;
+        let _ = app.update(Message::SetUnit(Unit::Milliseconds));
+        let _ = app.update(Message::SetUnit(Unit::Hertz));
+        assert!(app.toast.is_none());
+    }
+
+    #[test]
+    fn copies_use_the_decimal_separator() {
+        let mut app = app();
+
+        let _ = app.update(Message::OpenCellMenu(1, NoteValue::Eighth));
+        let _ = app.update(Message::CellMenu(CellAction::CopySeconds));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("0.375"));
+
+        let _ = app.update(Message::SetDecimalSeparator(DecimalSeparator::Comma));
+        let _ = app.update(Message::OpenCellMenu(2, NoteValue::Quarter));
+        let _ = app.update(Message::CellMenu(CellAction::CopyWithUnit));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("333,333 ms"));
+        let _ = app.update(Message::OpenCellMenu(2, NoteValue::Quarter));
+        let _ = app.update(Message::CellMenu(CellAction::CopySeconds));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("0,333333"));
+
+        // Only the number changes, not a dot in the unit's name
+        let _ = app.update(Message::StoreCustomUnitName("ds.".to_string()));
+        let _ = app.update(Message::StoreCustomUnitFactor("0.01".to_string()));
+        let _ = app.update(Message::SetUnit(app.settings.custom_as_unit()));
+        let _ = app.update(Message::OpenCellMenu(1, NoteValue::Eighth));
+        let _ = app.update(Message::CellMenu(CellAction::CopyWithUnit));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("3,75 ds."));
+
+        let _ = app.update(Message::PingPong(ping_pong::Message::Copy(187.5, 202.5)));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("187,5\n202,5"));
+    }
+
+    #[test]
+    fn cell_menu() {
+        let mut app = app();
+
+        let _ = app.update(Message::OpenCellMenu(1, NoteValue::Eighth));
+        let _ = app.update(Message::CellMenu(CellAction::CopyWithUnit));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("375 ms"));
+        assert_eq!(app.cell_menu, None);
+
+        let _ = app.update(Message::OpenCellMenu(2, NoteValue::Quarter));
+        let _ = app.update(Message::CellMenu(CellAction::CopyValue));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("333.333"));
+
+        let _ = app.update(Message::StoreAutomationMin("0".to_string()));
+        let _ = app.update(Message::StoreAutomationMax("1000".to_string()));
+        let _ = app.update(Message::OpenCellMenu(1, NoteValue::Eighth));
+        let _ = app.update(Message::CellMenu(CellAction::CopyNormalized));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("0.375"));
+
+        let _ = app.update(Message::OpenCellMenu(0, NoteValue::Half));
+        let _ = app.update(Message::CellMenu(CellAction::SetActive));
+        assert_eq!(
+            app.settings.selected_cell,
+            (RhythmicModifier::Normal, NoteValue::Half)
+        );
+    }
+
+    #[test]
+    fn importing_settings() {
+        let mut app = app();
+
+        let settings = settings::Settings {
+            nudge_steps: vec![0.5, 2.0],
+            preferred_tempo_range: (80.0, 160.0),
+            ui_scale: 1.5,
+            ..settings::Settings::default()
+        };
+        app.import_settings(settings::Bundle {
+            settings,
+            preset_templates: Vec::new(),
+        });
+
+        assert_eq!(app.settings.nudge_steps, vec![0.5, 2.0]);
+        assert_eq!(app.nudge_steps_text, "0.5, 2");
+        assert_eq!(app.preferred_range_text, "80–160");
+        assert_eq!(app.settings.ui_scale, 1.0);
+    }
+
+    #[test]
+    fn tap_latency() {
+        let mut app = app();
+
+        let _ = app.update(Message::StoreTapLatency("12.5".to_string()));
+        assert_eq!(app.settings.tap_latency_offset_ms, 12.5);
+        let _ = app.update(Message::StoreTapLatency("500".to_string()));
+        assert_eq!(app.settings.tap_latency_offset_ms, 12.5);
+
+        // Taps far quicker than the pulse run out of range, so the offset stops at the limit
+        let _ = app.update(Message::StartTapCalibration);
+        for _ in 0..crate::tap_calibration::CALIBRATION_TAPS {
+            let _ = app.update(Message::Tap);
+        }
+        assert!(app.tap_calibration.is_none());
+        assert_eq!(app.settings.tap_latency_offset_ms, -MAX_TAP_LATENCY_MS);
+        assert_eq!(app.tap_latency_text, "-100");
+        assert_eq!(app.tempo, Some(120.0));
+    }
+
+    #[test]
+    fn ui_scale_waits_for_the_release() {
+        let mut app = app();
+
+        let _ = app.update(Message::DragUiScale(1.5));
+        assert_eq!(app.scale_factor(), 1.0);
+        let _ = app.update(Message::SetUiScale);
+        assert_eq!(app.scale_factor(), 1.5);
+
+        app.settings.ui_scale = 8.0;
+        assert_eq!(app.scale_factor(), 2.0);
+    }
+
+    #[test]
+    fn window_opacity() {
+        let mut app = app();
+        let theme = app.theme();
+        assert_eq!(app.style(&theme).background_color.a, 1.0);
+
+        let _ = app.update(Message::DragWindowOpacity(0.5));
+        assert_eq!(app.style(&theme).background_color.a, 0.5);
+
+        app.settings.window_opacity = 0.0;
+        assert_eq!(app.style(&theme).background_color.a, 0.3);
+    }
+
+    #[test]
+    fn sessions_restore_the_setup() {
+        let mut original = app();
+
+        let _ = original.update(Message::StoreTuplet("5:4".to_string()));
+        let _ = original.update(Message::SetUnit(Unit::Hertz));
+        let _ = original.update(Message::StoreSessionNotes("Chorus throws".to_string()));
+        let session = original.session();
+
+        let mut reopened = app();
+        reopened.apply_session(session);
+        assert_eq!(reopened.tempo, original.tempo);
+        assert_eq!(reopened.unit, Unit::Hertz);
+        assert_eq!(reopened.custom_tuplet, original.custom_tuplet);
+        assert_eq!(reopened.session_notes, "Chorus throws");
+    }
+
+    #[test]
+    fn tap_pad() {
+        let mut app = app();
+
+        let _ = app.update(Message::ToggleTapPad);
+        assert!(app.show_tap_pad);
+        let _ = app.update(Message::Escape);
+        assert!(!app.show_tap_pad);
+    }
+
+    #[test]
+    fn pulse_blinks() {
+        let mut app = app();
+
+        let _ = app.update(Message::ShowPulse(true));
+        let _ = app.update(Message::Pulse);
+        assert!(app.pulse_is_lit);
+        let _ = app.update(Message::Pulse);
+        assert!(!app.pulse_is_lit);
+
+        // Turning it back on doesn't start mid-blink
+        let _ = app.update(Message::Pulse);
+        let _ = app.update(Message::ShowPulse(false));
+        let _ = app.update(Message::ShowPulse(true));
+        assert!(!app.pulse_is_lit);
+    }
+
+    #[test]
+    fn copying() {
+        let mut app = app();
+
+        let _ = app.update(Message::CopyToClipboard(1, NoteValue::Quarter));
+        assert_eq!(app.last_clipboard_text.as_deref(), Some("750"));
+        let _ = app.update(Message::CopyRow(NoteValue::Quarter));
+        assert_eq!(
+            app.last_clipboard_text.as_deref(),
+            Some("500\n750\n333.3333333333333")
+        );
+
+        let cell = Some((1, NoteValue::Quarter));
+        let _ = app.update(Message::Copied(
+            "750 ms".to_string(),
+            cell,
+            Err("Busy".to_string()),
+        ));
+        assert!(app.toast.as_ref().is_some_and(|toast| toast.is_error));
+        assert_eq!(app.clipboard_error.as_deref(), Some("Busy"));
+
+        // A copy that goes through clears the standing error
+        let _ = app.update(Message::Copied("750 ms".to_string(), cell, Ok(())));
+        assert!(app.toast.is_some_and(|toast| !toast.is_error));
+        assert_eq!(app.clipboard_error, None);
+    }
+}
@@ -0,0 +1,180 @@
+// Audio input lives behind the `audio` feature, since cpal needs the platform audio libraries (ALSA
+// on Linux) at build time. Without it, no devices are listed and nothing listens, leaving the onset
+// detector unused outside tests.
+#![cfg_attr(not(feature = "audio"), allow(dead_code))]
+
+use iced::Subscription;
+
+pub const IS_SUPPORTED: bool = cfg!(feature = "audio");
+
+// About 5 ms at 48 kHz, short enough that a tap lands close to the hit
+const BLOCK_SIZE: usize = 256;
+// A block has to be this many times louder than the recent average to count as a hit
+const ONSET_THRESHOLD: f32 = 8.0;
+// Roughly -50 dBFS, so room noise swelling from silence isn't mistaken for a hit
+const MIN_ONSET_ENERGY: f32 = 1e-5;
+// Faster than any tapped tempo, but long enough to skip a snare's ringing
+const HOLD_OFF_SECONDS: f32 = 0.1;
+
+#[cfg(feature = "audio")]
+pub fn input_device_names() -> Vec<String> {
+    use cpal::traits::{DeviceTrait, HostTrait};
+
+    cpal::default_host()
+        .input_devices()
+        .map(|devices| devices.filter_map(|device| device.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "audio"))]
+pub fn input_device_names() -> Vec<String> {
+    Vec::new()
+}
+
+// Emits once per detected hit on the named input device for as long as the subscription lives
+#[cfg(feature = "audio")]
+pub fn listen(device_name: String) -> Subscription<()> {
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use cpal::{FromSample, SizedSample};
+    use iced::futures::channel::mpsc::Sender;
+
+    fn build<T>(
+        device: &cpal::Device,
+        config: &cpal::StreamConfig,
+        mut output: Sender<()>,
+    ) -> Option<cpal::Stream>
+    where
+        T: SizedSample,
+        f32: FromSample<T>,
+    {
+        let channels = usize::from(config.channels);
+        let mut detector = OnsetDetector::new(config.sample_rate.0);
+        let mut block = Vec::with_capacity(BLOCK_SIZE);
+
+        device
+            .build_input_stream(
+                config,
+                move |data: &[T], _| {
+                    // Only the first channel is listened to
+                    for sample in data.iter().step_by(channels) {
+                        block.push(sample.to_sample::<f32>());
+
+                        if block.len() == BLOCK_SIZE {
+                            if detector.process(&block) {
+                                let _ = output.try_send(());
+                            }
+                            block.clear();
+                        }
+                    }
+                },
+                |_| {},
+                None,
+            )
+            .ok()
+    }
+
+    fn connect(device_name: &str, output: Sender<()>) -> Option<cpal::Stream> {
+        let device = cpal::default_host()
+            .input_devices()
+            .ok()?
+            .find(|device| device.name().ok().as_deref() == Some(device_name))?;
+        let config = device.default_input_config().ok()?;
+
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::F32 => build::<f32>(&device, &config.into(), output),
+            cpal::SampleFormat::I16 => build::<i16>(&device, &config.into(), output),
+            cpal::SampleFormat::U16 => build::<u16>(&device, &config.into(), output),
+            _ => None,
+        }?;
+
+        stream.play().ok()?;
+        Some(stream)
+    }
+
+    Subscription::run_with_id(
+        device_name.clone(),
+        iced::stream::channel(100, move |output| async move {
+            // Streams can't move between threads on every platform, so one thread owns it and
+            // lets it go once the subscription is dropped
+            std::thread::spawn(move || {
+                let _stream = connect(&device_name, output.clone());
+
+                while !output.is_closed() {
+                    std::thread::sleep(std::time::Duration::from_millis(250));
+                }
+            });
+
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+#[cfg(not(feature = "audio"))]
+pub fn listen(_device_name: String) -> Subscription<()> {
+    Subscription::none()
+}
+
+// Flags a block as a hit when its energy jumps well above the recent average
+struct OnsetDetector {
+    average_energy: f32,
+    hold_off_blocks: usize,
+    blocks_until_ready: usize,
+}
+
+impl OnsetDetector {
+    fn new(sample_rate: u32) -> Self {
+        Self {
+            average_energy: 0.0,
+            hold_off_blocks: (HOLD_OFF_SECONDS * sample_rate as f32 / BLOCK_SIZE as f32) as usize,
+            blocks_until_ready: 0,
+        }
+    }
+
+    fn process(&mut self, block: &[f32]) -> bool {
+        let energy = block.iter().map(|sample| sample * sample).sum::<f32>() / block.len() as f32;
+
+        let is_onset = self.blocks_until_ready == 0
+            && energy > MIN_ONSET_ENERGY
+            && energy > self.average_energy * ONSET_THRESHOLD;
+
+        self.average_energy = self.average_energy * 0.9 + energy * 0.1;
+
+        if is_onset {
+            self.blocks_until_ready = self.hold_off_blocks;
+        } else {
+            self.blocks_until_ready = self.blocks_until_ready.saturating_sub(1);
+        }
+
+        is_onset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_hits_over_quiet_input_once() {
+        let mut detector = OnsetDetector::new(48_000);
+        let quiet = [0.001; BLOCK_SIZE];
+        let loud = [0.5; BLOCK_SIZE];
+
+        assert!(!detector.process(&quiet));
+        assert!(!detector.process(&quiet));
+        assert!(detector.process(&loud));
+        // Still ringing, and within the hold-off
+        assert!(!detector.process(&loud));
+
+        for _ in 0..100 {
+            detector.process(&quiet);
+        }
+        assert!(detector.process(&loud));
+    }
+
+    #[test]
+    fn ignores_silence() {
+        let mut detector = OnsetDetector::new(48_000);
+        assert!(!detector.process(&[0.0; BLOCK_SIZE]));
+        assert!(!detector.process(&[0.0001; BLOCK_SIZE]));
+    }
+}
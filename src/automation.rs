@@ -0,0 +1,106 @@
+// Delay times as normalized 0–1 automation values, for plugins whose parameters only take those.
+// The range is the plugin parameter's own, in milliseconds, from its minimum to its maximum.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+// How the plugin spreads its range over 0–1. Delay parameters are often logarithmic, giving short
+// times more of the knob.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParameterScale {
+    #[default]
+    Linear,
+    Logarithmic,
+}
+
+pub const PARAMETER_SCALES: [ParameterScale; 2] =
+    [ParameterScale::Linear, ParameterScale::Logarithmic];
+
+impl Display for ParameterScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterScale::Linear => write!(f, "{}", tr("scale-linear")),
+            ParameterScale::Logarithmic => write!(f, "{}", tr("scale-logarithmic")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ParameterRange {
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub scale: ParameterScale,
+}
+
+impl Default for ParameterRange {
+    fn default() -> Self {
+        Self {
+            min_ms: 1.0,
+            max_ms: 2000.0,
+            scale: ParameterScale::Linear,
+        }
+    }
+}
+
+impl ParameterRange {
+    // Logarithmic ranges can't start at zero
+    pub fn is_valid(&self) -> bool {
+        let min_is_valid = match self.scale {
+            ParameterScale::Linear => self.min_ms >= 0.0,
+            ParameterScale::Logarithmic => self.min_ms > 0.0,
+        };
+
+        min_is_valid
+            && self.min_ms.is_finite()
+            && self.max_ms.is_finite()
+            && self.min_ms < self.max_ms
+    }
+
+    // Times beyond the range pin to its ends, as the plugin would
+    pub fn normalize(&self, ms: f64) -> Option<f64> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let normalized = match self.scale {
+            ParameterScale::Linear => (ms - self.min_ms) / (self.max_ms - self.min_ms),
+            ParameterScale::Logarithmic => {
+                (ms / self.min_ms).ln() / (self.max_ms / self.min_ms).ln()
+            }
+        };
+
+        Some(normalized.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes() {
+        let linear = ParameterRange {
+            min_ms: 0.0,
+            max_ms: 1000.0,
+            scale: ParameterScale::Linear,
+        };
+        assert_eq!(linear.normalize(250.0), Some(0.25));
+        assert_eq!(linear.normalize(1500.0), Some(1.0));
+
+        let logarithmic = ParameterRange {
+            min_ms: 1.0,
+            max_ms: 10_000.0,
+            scale: ParameterScale::Logarithmic,
+        };
+        assert_eq!(logarithmic.normalize(100.0), Some(0.5));
+
+        let starts_at_zero = ParameterRange {
+            min_ms: 0.0,
+            ..logarithmic
+        };
+        assert_eq!(starts_at_zero.normalize(100.0), None);
+    }
+}
@@ -0,0 +1,247 @@
+// Platform clipboard access. Native builds go through arboard; the web build uses the browser's
+// async clipboard API, which can only be written to, and only from a user gesture. Native builds
+// without the `clipboard` feature have no clipboard, so every copy reports an error.
+
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+// Wayland compositors in particular refuse the occasional write, usually while focus is moving
+#[cfg(not(target_arch = "wasm32"))]
+const WRITE_ATTEMPTS: u32 = 3;
+#[cfg(not(target_arch = "wasm32"))]
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+
+pub trait Clipboard: Send {
+    fn set_text(&mut self, text: String) -> Result<(), String>;
+    fn get_text(&mut self) -> Result<String, String>;
+}
+
+type Connect = dyn Fn() -> Result<Box<dyn Clipboard>, String> + Send + Sync;
+
+// Cheap to clone into background writes. The connection is made on first use and dropped after any
+// failure, so a clipboard that was missing at startup, or has gone away since, is tried again.
+#[derive(Clone)]
+pub struct Handle {
+    connect: Arc<Connect>,
+    connection: Arc<Mutex<Option<Box<dyn Clipboard>>>>,
+}
+
+impl Handle {
+    pub fn new(
+        connect: impl Fn() -> Result<Box<dyn Clipboard>, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            connect: Arc::new(connect),
+            connection: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn system() -> Self {
+        Self::new(connect)
+    }
+
+    pub fn get_text(&self) -> Result<String, String> {
+        self.with_connection(|clipboard| clipboard.get_text())
+    }
+
+    // Runs off the UI thread, retrying a few times before giving up
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn set_text(self, text: String) -> Result<(), String> {
+        let (sender, receiver) = iced::futures::channel::oneshot::channel();
+
+        std::thread::spawn(move || {
+            let _ = sender.send(self.set_text_with_retries(&text, RETRY_DELAY));
+        });
+
+        receiver
+            .await
+            .unwrap_or_else(|_| Err("Clipboard write was interrupted".to_string()))
+    }
+
+    // The browser queues the write itself, so there's nothing to retry
+    #[cfg(target_arch = "wasm32")]
+    pub async fn set_text(self, text: String) -> Result<(), String> {
+        self.with_connection(|clipboard| clipboard.set_text(text.clone()))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn set_text_with_retries(&self, text: &str, retry_delay: Duration) -> Result<(), String> {
+        let mut result = Ok(());
+
+        for attempt in 1..=WRITE_ATTEMPTS {
+            result = self.with_connection(|clipboard| clipboard.set_text(text.to_string()));
+
+            if result.is_ok() {
+                break;
+            }
+
+            if attempt < WRITE_ATTEMPTS {
+                std::thread::sleep(retry_delay * attempt);
+            }
+        }
+
+        result
+    }
+
+    fn with_connection<T>(
+        &self,
+        operation: impl FnOnce(&mut dyn Clipboard) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut connection = self
+            .connection
+            .lock()
+            .map_err(|_| "Clipboard is unavailable".to_string())?;
+
+        let clipboard = match connection.as_mut() {
+            Some(clipboard) => clipboard,
+            None => connection.insert((self.connect)()?),
+        };
+
+        let result = operation(clipboard.as_mut());
+
+        if result.is_err() {
+            *connection = None;
+        }
+
+        result
+    }
+}
+
+#[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+fn connect() -> Result<Box<dyn Clipboard>, String> {
+    arboard::Clipboard::new()
+        .map(|clipboard| Box::new(NativeClipboard(clipboard)) as Box<dyn Clipboard>)
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(all(not(feature = "clipboard"), not(target_arch = "wasm32")))]
+fn connect() -> Result<Box<dyn Clipboard>, String> {
+    Err("Built without clipboard support".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn connect() -> Result<Box<dyn Clipboard>, String> {
+    web_sys::window()
+        .map(|_| Box::new(WebClipboard) as Box<dyn Clipboard>)
+        .ok_or("No browser window".to_string())
+}
+
+#[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+struct NativeClipboard(arboard::Clipboard);
+
+#[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+impl Clipboard for NativeClipboard {
+    fn set_text(&mut self, text: String) -> Result<(), String> {
+        self.0.set_text(text).map_err(|error| error.to_string())
+    }
+
+    fn get_text(&mut self) -> Result<String, String> {
+        self.0.get_text().map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+struct WebClipboard;
+
+#[cfg(target_arch = "wasm32")]
+impl Clipboard for WebClipboard {
+    // The write resolves asynchronously; a rejected promise is dropped rather than awaited
+    fn set_text(&mut self, text: String) -> Result<(), String> {
+        let window = web_sys::window().ok_or("No browser window")?;
+        let clipboard = window
+            .navigator()
+            .clipboard()
+            .ok_or("Clipboard unavailable outside a secure context")?;
+        let _ = clipboard.write_text(&text);
+        Ok(())
+    }
+
+    fn get_text(&mut self) -> Result<String, String> {
+        Err("Reading the clipboard isn't supported in the browser".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Refuses the first `failures` writes across every connection
+    struct FlakyClipboard {
+        failures: Arc<Mutex<u32>>,
+        written: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Clipboard for FlakyClipboard {
+        fn set_text(&mut self, text: String) -> Result<(), String> {
+            let mut failures = self.failures.lock().unwrap();
+
+            if *failures > 0 {
+                *failures -= 1;
+                return Err("Busy".to_string());
+            }
+
+            self.written.lock().unwrap().push(text);
+            Ok(())
+        }
+
+        fn get_text(&mut self) -> Result<String, String> {
+            Err("Unsupported".to_string())
+        }
+    }
+
+    fn flaky(failures: u32) -> (Handle, Arc<Mutex<Vec<String>>>) {
+        let failures = Arc::new(Mutex::new(failures));
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let handle = Handle::new({
+            let written = written.clone();
+            move || {
+                Ok(Box::new(FlakyClipboard {
+                    failures: failures.clone(),
+                    written: written.clone(),
+                }) as Box<dyn Clipboard>)
+            }
+        });
+        (handle, written)
+    }
+
+    #[test]
+    fn retries_transient_failures() {
+        let (handle, written) = flaky(WRITE_ATTEMPTS - 1);
+        assert_eq!(handle.set_text_with_retries("120", Duration::ZERO), Ok(()));
+        assert_eq!(*written.lock().unwrap(), ["120"]);
+    }
+
+    #[test]
+    fn gives_up_after_repeated_failures() {
+        let (handle, written) = flaky(WRITE_ATTEMPTS);
+        assert_eq!(
+            handle.set_text_with_retries("120", Duration::ZERO),
+            Err("Busy".to_string())
+        );
+        assert!(written.lock().unwrap().is_empty());
+
+        // A later copy reconnects and goes through
+        assert_eq!(
+            iced::futures::executor::block_on(handle.set_text("90".to_string())),
+            Ok(())
+        );
+        assert_eq!(*written.lock().unwrap(), ["90"]);
+    }
+
+    #[test]
+    fn reconnects_when_unavailable_at_first() {
+        let attempts = Arc::new(Mutex::new(0));
+        let handle = Handle::new({
+            let attempts = attempts.clone();
+            move || {
+                *attempts.lock().unwrap() += 1;
+                Err("No display".to_string())
+            }
+        });
+
+        assert!(handle.get_text().is_err());
+        assert!(handle.get_text().is_err());
+        assert_eq!(*attempts.lock().unwrap(), 2);
+    }
+}
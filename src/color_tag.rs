@@ -0,0 +1,72 @@
+use std::fmt::Display;
+
+#[cfg(feature = "gui")]
+use iced::{theme::Palette, Color, Theme};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+// Tags let several open windows (one per song) be told apart at a glance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorTag {
+    None,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+pub const COLOR_TAGS: [ColorTag; 7] = [
+    ColorTag::None,
+    ColorTag::Red,
+    ColorTag::Orange,
+    ColorTag::Yellow,
+    ColorTag::Green,
+    ColorTag::Blue,
+    ColorTag::Purple,
+];
+
+#[cfg(feature = "gui")]
+impl ColorTag {
+    pub fn color(&self) -> Option<Color> {
+        match self {
+            ColorTag::None => None,
+            ColorTag::Red => Some(Color::from_rgb8(0xFF, 0x55, 0x55)),
+            ColorTag::Orange => Some(Color::from_rgb8(0xFF, 0xB8, 0x6C)),
+            ColorTag::Yellow => Some(Color::from_rgb8(0xF1, 0xFA, 0x8C)),
+            ColorTag::Green => Some(Color::from_rgb8(0x50, 0xFA, 0x7B)),
+            ColorTag::Blue => Some(Color::from_rgb8(0x8B, 0xE9, 0xFD)),
+            ColorTag::Purple => Some(Color::from_rgb8(0xBD, 0x93, 0xF9)),
+        }
+    }
+
+    // Swaps the theme's primary color for the tag color, leaving everything else untouched
+    pub fn tint(&self, theme: Theme) -> Theme {
+        match self.color() {
+            Some(color) => Theme::custom(
+                format!("{} ({})", theme, self),
+                Palette {
+                    primary: color,
+                    ..theme.palette()
+                },
+            ),
+            None => theme,
+        }
+    }
+}
+
+impl Display for ColorTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorTag::None => write!(f, "{}", tr("color-none")),
+            ColorTag::Red => write!(f, "{}", tr("color-red")),
+            ColorTag::Orange => write!(f, "{}", tr("color-orange")),
+            ColorTag::Yellow => write!(f, "{}", tr("color-yellow")),
+            ColorTag::Green => write!(f, "{}", tr("color-green")),
+            ColorTag::Blue => write!(f, "{}", tr("color-blue")),
+            ColorTag::Purple => write!(f, "{}", tr("color-purple")),
+        }
+    }
+}
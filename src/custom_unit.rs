@@ -0,0 +1,58 @@
+// A unit of the user's own, defined as milliseconds or Hertz times a factor, e.g. clock ticks at
+// 1 MHz as milliseconds × 1000. It's set in preferences and carried by `Unit::Custom`, so tables
+// built in it are rebuilt when it's redefined.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CustomBase {
+    #[default]
+    Milliseconds,
+    Hertz,
+}
+
+pub const CUSTOM_BASES: [CustomBase; 2] = [CustomBase::Milliseconds, CustomBase::Hertz];
+
+impl Display for CustomBase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomBase::Milliseconds => write!(f, "ms ×"),
+            CustomBase::Hertz => write!(f, "Hz ×"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomUnit {
+    pub name: String,
+    pub base: CustomBase,
+    pub factor: f64,
+}
+
+impl Default for CustomUnit {
+    fn default() -> Self {
+        Self {
+            name: "µs".to_string(),
+            base: CustomBase::Milliseconds,
+            factor: 1000.0,
+        }
+    }
+}
+
+impl CustomUnit {
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty() && self.factor.is_finite() && self.factor > 0.0
+    }
+
+    // Preferences keep what's typed, which can be half-finished, so units fall back to the default
+    pub fn usable(&self) -> CustomUnit {
+        if self.is_valid() {
+            self.clone()
+        } else {
+            CustomUnit::default()
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// The bands delay times are heard in, for coloring the table by range. Under the Haas zone's end a
+// repeat fuses with the dry sound, up to `SLAPBACK_MAX_MS` it's a slapback, and beyond that an echo.
+
+use crate::color_tag::ColorTag;
+use crate::tools::haas::HAAS_ZONE_MS;
+
+pub const SLAPBACK_MAX_MS: f64 = 150.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelayRange {
+    Haas,
+    Slapback,
+    Echo,
+}
+
+impl DelayRange {
+    pub fn of(ms: f64) -> Self {
+        if ms <= *HAAS_ZONE_MS.end() {
+            DelayRange::Haas
+        } else if ms <= SLAPBACK_MAX_MS {
+            DelayRange::Slapback
+        } else {
+            DelayRange::Echo
+        }
+    }
+
+    pub fn color_tag(&self) -> ColorTag {
+        match self {
+            DelayRange::Haas => ColorTag::Purple,
+            DelayRange::Slapback => ColorTag::Orange,
+            DelayRange::Echo => ColorTag::Blue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bands() {
+        assert_eq!(DelayRange::of(20.0), DelayRange::Haas);
+        assert_eq!(DelayRange::of(35.0), DelayRange::Haas);
+        assert_eq!(DelayRange::of(90.0), DelayRange::Slapback);
+        assert_eq!(DelayRange::of(375.0), DelayRange::Echo);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use round::round;
+
+use crate::{delay_time, delay_times, Unit, NOTE_VALUES, RHYTHMIC_MODIFIER, ROUND_LIMIT};
+
+// Half-time, original, and double-time, in that order
+const TEMPO_SCALES: [f64; 3] = [0.5, 1.0, 2.0];
+
+pub fn half_and_double_time_csv(tempo: f64, unit: &Unit) -> String {
+    let mut csv = String::new();
+
+    for (index, scale) in TEMPO_SCALES.iter().enumerate() {
+        if index > 0 {
+            csv.push('\n');
+        }
+
+        csv.push_str(&table_csv(tempo * scale, unit));
+    }
+
+    csv
+}
+
+pub fn write_half_and_double_time_csv(path: &Path, tempo: f64, unit: &Unit) -> std::io::Result<()> {
+    std::fs::write(path, half_and_double_time_csv(tempo, unit))
+}
+
+fn table_csv(tempo: f64, unit: &Unit) -> String {
+    let mut csv = format!("Tempo,{} BPM\nNote", round(tempo, ROUND_LIMIT));
+
+    for rhythmic_modifier in &RHYTHMIC_MODIFIER {
+        let _ = write!(csv, ",{} ({})", rhythmic_modifier, unit);
+    }
+
+    csv.push('\n');
+
+    let delay_times =
+        RHYTHMIC_MODIFIER.map(|rhythmic_modifier| delay_times(tempo, &rhythmic_modifier, unit));
+
+    for note_value in &NOTE_VALUES {
+        csv.push_str(&note_value.to_string());
+
+        for delay_times in &delay_times {
+            let _ = write!(
+                csv,
+                ",{}",
+                round(delay_time(delay_times, note_value), ROUND_LIMIT)
+            );
+        }
+
+        csv.push('\n');
+    }
+
+    csv
+}
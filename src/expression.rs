@@ -0,0 +1,148 @@
+// A tiny recursive descent evaluator for tempo math like `174/2`, `120*1.5`, or `(95+7)*2`
+
+// Far more nesting than any tempo needs, and shallow enough that the recursion can't overflow the
+// stack however long the input is
+const MAX_DEPTH: usize = 64;
+
+pub fn evaluate(text: &str) -> Option<f64> {
+    let mut parser = Parser {
+        chars: text.chars().filter(|char| !char.is_whitespace()).collect(),
+        position: 0,
+        depth: 0,
+    };
+
+    let value = parser.expression()?;
+
+    if parser.position != parser.chars.len() {
+        return None;
+    }
+
+    Some(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next_if(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    // expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+
+        loop {
+            if self.next_if('+') {
+                value += self.term()?;
+            } else if self.next_if('-') {
+                value -= self.term()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    // term := factor (('*' | 'x' | '/') factor)*
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+
+        loop {
+            if self.next_if('*') || self.next_if('x') || self.next_if('×') {
+                value *= self.factor()?;
+            } else if self.next_if('/') || self.next_if('÷') {
+                value /= self.factor()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    // factor := '-' factor | '(' expression ')' | number
+    fn factor(&mut self) -> Option<f64> {
+        if self.depth == MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+
+        let value = if self.next_if('-') {
+            self.factor().map(|value| -value)
+        } else if self.next_if('(') {
+            self.expression().filter(|_| self.next_if(')'))
+        } else {
+            self.number()
+        };
+
+        self.depth -= 1;
+        value
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let start = self.position;
+
+        while self
+            .peek()
+            .is_some_and(|char| char.is_ascii_digit() || char == '.')
+        {
+            self.position += 1;
+        }
+
+        self.chars[start..self.position]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluate;
+
+    #[test]
+    fn plain_numbers() {
+        assert_eq!(evaluate("120"), Some(120.0));
+        assert_eq!(evaluate(" 93.5 "), Some(93.5));
+    }
+
+    #[test]
+    fn operators_and_precedence() {
+        assert_eq!(evaluate("174/2"), Some(87.0));
+        assert_eq!(evaluate("120*1.5"), Some(180.0));
+        assert_eq!(evaluate("120 x 2"), Some(240.0));
+        assert_eq!(evaluate("90 × 2 ÷ 3"), Some(60.0));
+        assert_eq!(evaluate("100+10*2"), Some(120.0));
+        assert_eq!(evaluate("(95+7)*2"), Some(204.0));
+        assert_eq!(evaluate("-(-120)"), Some(120.0));
+    }
+
+    #[test]
+    fn malformed_input() {
+        assert_eq!(evaluate(""), None);
+        assert_eq!(evaluate("abc"), None);
+        assert_eq!(evaluate("120bpm"), None);
+        assert_eq!(evaluate("(120"), None);
+        assert_eq!(evaluate("120*"), None);
+    }
+
+    #[test]
+    fn deep_nesting() {
+        let nested = |depth: usize| format!("{}120{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(evaluate(&nested(20)), Some(120.0));
+        assert_eq!(evaluate(&nested(100)), None);
+        assert_eq!(evaluate(&"(".repeat(60_000)), None);
+        assert_eq!(evaluate(&format!("{}120", "-".repeat(60_000))), None);
+    }
+}
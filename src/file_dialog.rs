@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+// Filters are (name, extension) pairs. The web build has no filesystem to save into or open from,
+// so every dialog resolves as cancelled there.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn save_file(filters: Vec<(String, String)>, file_name: String) -> Option<PathBuf> {
+    filters
+        .into_iter()
+        .fold(rfd::AsyncFileDialog::new(), |dialog, (name, extension)| {
+            dialog.add_filter(name, &[extension])
+        })
+        .set_file_name(file_name)
+        .save_file()
+        .await
+        .map(|file| file.path().to_path_buf())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn save_file(_filters: Vec<(String, String)>, _file_name: String) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn open_file(filters: Vec<(String, String)>) -> Option<PathBuf> {
+    filters
+        .into_iter()
+        .fold(rfd::AsyncFileDialog::new(), |dialog, (name, extension)| {
+            dialog.add_filter(name, &[extension])
+        })
+        .pick_file()
+        .await
+        .map(|file| file.path().to_path_buf())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub async fn open_file(_filters: Vec<(String, String)>) -> Option<PathBuf> {
+    None
+}
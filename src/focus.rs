@@ -0,0 +1,57 @@
+// Iced doesn't say when a text field gains or loses focus, so the app asks the widget tree after
+// anything that could have moved it: clicks, Tab, and Enter
+
+use iced::advanced::widget::operation::{Focusable, Operation, Outcome};
+use iced::advanced::widget::{self, Id};
+use iced::widget::text_input;
+use iced::{Rectangle, Task};
+
+pub fn tempo_input_id() -> text_input::Id {
+    text_input::Id::new("tempo")
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextFocus {
+    // Any field, tempo or otherwise, so typed letters aren't taken as shortcuts
+    pub any_input: bool,
+    pub tempo_input: bool,
+}
+
+struct FindTextFocus {
+    tempo_input_id: Id,
+    text_focus: TextFocus,
+}
+
+impl Operation<TextFocus> for FindTextFocus {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<TextFocus>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        if state.is_focused() {
+            self.text_focus.any_input = true;
+            self.text_focus.tempo_input |= id == Some(&self.tempo_input_id);
+        }
+    }
+
+    fn finish(&self) -> Outcome<TextFocus> {
+        Outcome::Some(self.text_focus)
+    }
+}
+
+pub fn check() -> Task<TextFocus> {
+    widget::operate(FindTextFocus {
+        tempo_input_id: tempo_input_id().into(),
+        text_focus: TextFocus::default(),
+    })
+}
+
+// Focusing an id no field has leaves every field unfocused
+pub fn blur<T>() -> Task<T> {
+    text_input::focus(text_input::Id::unique())
+}
@@ -0,0 +1,300 @@
+// A small HTTP API for companion tools and stream decks, enabled with `--serve <address>`:
+//
+// - `GET /table?tempo=128&unit=ms` returns the table as JSON, in the same shape export plugins get.
+//   `unit` can also be `hz`, or `ticks` with an optional `ppqn`.
+// - `POST /tempo` with a body of `128` or `{"tempo": 128}` sets the app's tempo
+//
+// Requests are handled one at a time on a background thread; only the tempo change reaches the UI.
+// The web build can't listen on a socket, so everything but the stub goes unused there.
+#![cfg_attr(target_arch = "wasm32", allow(dead_code))]
+
+#[cfg(feature = "gui")]
+use iced::Subscription;
+use serde::Deserialize;
+
+use crate::{parse_tempo, plugins, Unit, DEFAULT_PPQN};
+
+const MAX_REQUEST_BYTES: usize = 64 * 1024;
+// A client that stops sending would otherwise hold up every request behind it
+#[cfg(all(feature = "gui", not(target_arch = "wasm32")))]
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+// Far more than any tempo needs, and short enough that evaluating it stays cheap
+const MAX_TEMPO_LENGTH: usize = 256;
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    SetTempo(f64),
+    Failed(String),
+}
+
+pub fn serve_address(mut args: impl Iterator<Item = String>) -> Option<String> {
+    args.find(|arg| arg == "--serve")?;
+    args.next()
+}
+
+#[cfg(all(feature = "gui", not(target_arch = "wasm32")))]
+pub fn listen(address: String) -> Subscription<Event> {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    use iced::futures::channel::mpsc::Sender;
+
+    fn handle(stream: TcpStream, output: &mut Sender<Event>) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+        stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?).take(MAX_REQUEST_BYTES as u64);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length.min(MAX_REQUEST_BYTES)];
+        reader.read_exact(&mut body)?;
+
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+        let response = respond(method, target, &String::from_utf8_lossy(&body));
+
+        if let Some(tempo) = response.tempo {
+            let _ = output.try_send(Event::SetTempo(tempo));
+        }
+
+        write!(
+            &stream,
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            response.body.len(),
+            response.body
+        )
+    }
+
+    Subscription::run_with_id(
+        address.clone(),
+        iced::stream::channel(100, move |mut output| async move {
+            match TcpListener::bind(&address) {
+                Ok(listener) => {
+                    let mut output = output.clone();
+                    std::thread::spawn(move || {
+                        for stream in listener.incoming().flatten() {
+                            let _ = handle(stream, &mut output);
+                        }
+                    });
+                }
+                Err(error) => {
+                    let _ = output.try_send(Event::Failed(format!(
+                        "Couldn't serve on {}: {}",
+                        address, error
+                    )));
+                }
+            }
+
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+#[cfg(all(feature = "gui", target_arch = "wasm32"))]
+pub fn listen(_address: String) -> Subscription<Event> {
+    Subscription::none()
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+    tempo: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct TempoBody {
+    tempo: f64,
+}
+
+fn respond(method: &str, target: &str, body: &str) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    match (method, path) {
+        ("GET", "/table") => {
+            let Some(parameters) = query
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(key, value)| Some((percent_decode(key)?, percent_decode(value)?)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return error("400 Bad Request", "Malformed query");
+            };
+            let parameter = |name: &str| {
+                parameters
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.as_str())
+            };
+
+            let Some(tempo) = parameter("tempo").and_then(parse_tempo_field) else {
+                return error("400 Bad Request", "Expected a positive `tempo` parameter");
+            };
+
+            let Ok(ppqn) = parameter("ppqn").map(str::parse).transpose() else {
+                return error("400 Bad Request", PPQN_ERROR);
+            };
+
+            let unit = match parse_unit(parameter("unit"), ppqn) {
+                Ok(unit) => unit,
+                Err(message) => return error("400 Bad Request", message),
+            };
+
+            Response {
+                status: "200 OK",
+                body: serde_json::to_string(&plugins::ExportTable::new(tempo, &unit))
+                    .unwrap_or_default(),
+                tempo: None,
+            }
+        }
+        ("POST", "/tempo") => {
+            let tempo = serde_json::from_str::<TempoBody>(body)
+                .map(|body| body.tempo)
+                .ok()
+                .or_else(|| parse_tempo_field(body.trim()))
+                .filter(|tempo| tempo.is_finite() && *tempo > 0.0);
+
+            match tempo {
+                Some(tempo) => Response {
+                    status: "200 OK",
+                    body: serde_json::json!({ "tempo": tempo }).to_string(),
+                    tempo: Some(tempo),
+                },
+                None => error("400 Bad Request", "Expected a positive tempo"),
+            }
+        }
+        (_, "/table" | "/tempo") => error("405 Method Not Allowed", "Method not allowed"),
+        _ => error("404 Not Found", "Not found"),
+    }
+}
+
+fn parse_tempo_field(text: &str) -> Option<f64> {
+    (text.len() <= MAX_TEMPO_LENGTH).then(|| parse_tempo(text))?
+}
+
+// Query values arrive percent-encoded, e.g. `174%2F2`, with `+` for a space
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.bytes();
+
+    while let Some(byte) = rest.next() {
+        bytes.push(match byte {
+            b'%' => {
+                let hex = [rest.next()?, rest.next()?];
+                u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?
+            }
+            b'+' => b' ',
+            byte => byte,
+        });
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+const PPQN_ERROR: &str = "`ppqn` must be a positive integer";
+
+// Shared with the stdin pipe, which takes the same parameters
+pub fn parse_unit(unit: Option<&str>, ppqn: Option<u16>) -> Result<Unit, &'static str> {
+    match unit.map(str::to_ascii_lowercase).as_deref() {
+        None | Some("ms") => Ok(Unit::Milliseconds),
+        Some("hz") => Ok(Unit::Hertz),
+        Some("ticks") => match ppqn.unwrap_or(DEFAULT_PPQN) {
+            0 => Err(PPQN_ERROR),
+            ppqn => Ok(Unit::Ticks(ppqn)),
+        },
+        Some(_) => Err("`unit` must be `ms`, `hz`, or `ticks`"),
+    }
+}
+
+fn error(status: &'static str, message: &str) -> Response {
+    Response {
+        status,
+        body: serde_json::json!({ "error": message }).to_string(),
+        tempo: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_the_serve_flag() {
+        let args = ["app", "--serve", "127.0.0.1:8080"].map(String::from);
+        assert_eq!(
+            serve_address(args.into_iter()),
+            Some("127.0.0.1:8080".to_string())
+        );
+        assert_eq!(serve_address(["app"].map(String::from).into_iter()), None);
+    }
+
+    #[test]
+    fn table_requests() {
+        let response = respond("GET", "/table?tempo=120&unit=hz", "");
+        assert_eq!(response.status, "200 OK");
+        assert!(response.body.contains(r#""unit":"Hz""#));
+
+        let response = respond("GET", "/table?tempo=120&unit=ticks&ppqn=480", "");
+        assert!(response.body.contains(r#""unit":"ticks""#));
+
+        assert_eq!(respond("GET", "/table", "").status, "400 Bad Request");
+        assert_eq!(
+            respond("GET", "/table?tempo=120&unit=bpm", "").status,
+            "400 Bad Request"
+        );
+        assert_eq!(respond("GET", "/nope", "").status, "404 Not Found");
+    }
+
+    #[test]
+    fn query_values_are_percent_decoded() {
+        let response = respond("GET", "/table?tempo=174%2F2", "");
+        assert_eq!(response.status, "200 OK");
+        assert!(response.body.contains(r#""tempo":87.0"#));
+
+        assert_eq!(percent_decode("1+2").as_deref(), Some("1 2"));
+        assert_eq!(percent_decode("%C2%B5s").as_deref(), Some("µs"));
+    }
+
+    #[test]
+    fn malformed_and_oversized_requests() {
+        for target in ["/table?tempo=12%", "/table?tempo=%zz", "/table?tempo=%FF"] {
+            assert_eq!(respond("GET", target, "").status, "400 Bad Request");
+        }
+
+        let huge = "1+".repeat(MAX_TEMPO_LENGTH) + "1";
+        assert_eq!(
+            respond("GET", &format!("/table?tempo={}", huge), "").status,
+            "400 Bad Request"
+        );
+        assert_eq!(respond("POST", "/tempo", &huge).tempo, None);
+        assert_eq!(respond("POST", "/tempo", "(((((1)))))").tempo, Some(1.0));
+    }
+
+    #[test]
+    fn tempo_requests() {
+        assert_eq!(respond("POST", "/tempo", "128").tempo, Some(128.0));
+        assert_eq!(
+            respond("POST", "/tempo", r#"{"tempo": 90.5}"#).tempo,
+            Some(90.5)
+        );
+        assert_eq!(respond("POST", "/tempo", "fast").tempo, None);
+        assert_eq!(
+            respond("GET", "/tempo", "").status,
+            "405 Method Not Allowed"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+// UI strings, looked up by ID in the Fluent-style files under `locales/`. Only the single-line
+// subset of Fluent is understood: `id = text`, `#` comments, and `{ $name }` placeholders. A string
+// a translation leaves out falls back to English, and one missing from English shows its ID.
+//
+// Adding a language means adding its `.ftl` file, a `Language` variant, and an entry in
+// `Language::source`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+pub const LANGUAGES: [Language; 2] = [Language::English, Language::Spanish];
+
+impl Language {
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en.ftl"),
+            Language::Spanish => include_str!("../locales/es.ftl"),
+        }
+    }
+}
+
+// Each language in its own words, so it can be found without reading the current one
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Language::English => write!(f, "English"),
+            Language::Spanish => write!(f, "Español"),
+        }
+    }
+}
+
+// Global so the many `Display` impls and view helpers don't all need the language passed in
+static LANGUAGE: AtomicUsize = AtomicUsize::new(0);
+static BUNDLES: OnceLock<Vec<HashMap<&'static str, &'static str>>> = OnceLock::new();
+
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as usize, Ordering::Relaxed);
+}
+
+pub fn tr(id: &'static str) -> &'static str {
+    lookup(LANGUAGES[LANGUAGE.load(Ordering::Relaxed)], id)
+}
+
+pub fn tr_with(id: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    substitute(tr(id), args)
+}
+
+fn lookup(language: Language, id: &'static str) -> &'static str {
+    let bundles = BUNDLES.get_or_init(|| {
+        LANGUAGES
+            .iter()
+            .map(|language| parse(language.source()))
+            .collect()
+    });
+
+    bundles[language as usize]
+        .get(id)
+        .or_else(|| bundles[Language::English as usize].get(id))
+        .copied()
+        .unwrap_or(id)
+}
+
+fn substitute(text: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter().fold(text.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{ ${} }}", name), &value.to_string())
+    })
+}
+
+fn parse(source: &'static str) -> HashMap<&'static str, &'static str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(id, text)| (id.trim(), text.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translations_only_use_english_ids() {
+        let english = parse(Language::English.source());
+
+        for language in LANGUAGES {
+            for id in parse(language.source()).keys() {
+                assert!(
+                    english.contains_key(id),
+                    "{:?} defines unknown {}",
+                    language,
+                    id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn falls_back_to_the_id() {
+        assert_eq!(lookup(Language::Spanish, "halve"), "Mitad");
+        assert_eq!(lookup(Language::English, "halve"), "Halve");
+        assert_eq!(lookup(Language::Spanish, "no-such-id"), "no-such-id");
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        assert_eq!(
+            substitute(
+                lookup(Language::English, "averaging-taps"),
+                &[("used", &4), ("total", &6)]
+            ),
+            "Averaging 4 of 6 taps"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// Renders the table as a plain black-on-white cheat sheet, independent of the app's theme
+
+use std::fmt::Write;
+use std::path::Path;
+
+use round::round;
+
+use crate::i18n::tr_with;
+use crate::{cell_value, note_glyph, DelayTable, NoteValue, NOTE_VALUES, ROUND_LIMIT};
+
+const LABEL_WIDTH: usize = 80;
+const COLUMN_WIDTH: usize = 140;
+const ROW_HEIGHT: usize = 32;
+const MARGIN: usize = 24;
+const FONT_SIZE: usize = 16;
+const GLYPH_HEIGHT: f32 = 26.0;
+#[cfg(not(target_arch = "wasm32"))]
+const PNG_SCALE: f32 = 2.0;
+
+// Cells for `millisecond_notes` stay in milliseconds the way they do in the window, and
+// `note_glyphs` draws the row labels the way the window does when glyphs are on
+pub fn table_svg(
+    delay_table: &DelayTable,
+    millisecond_notes: &[NoteValue],
+    note_glyphs: bool,
+) -> String {
+    let width = MARGIN * 2 + LABEL_WIDTH + COLUMN_WIDTH * delay_table.columns.len();
+    // Title, header, then one row per note value
+    let height = MARGIN * 2 + ROW_HEIGHT * (NOTE_VALUES.len() + 2);
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" font-family="sans-serif" font-size="{FONT_SIZE}">
+<rect width="100%" height="100%" fill="white"/>
+"#
+    );
+
+    let baseline = |row: usize| MARGIN + ROW_HEIGHT * row + ROW_HEIGHT * 2 / 3;
+    let column_x = |column: usize| MARGIN + LABEL_WIDTH + COLUMN_WIDTH * column;
+
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-weight="bold">{}</text>"#,
+        MARGIN,
+        baseline(0),
+        escape(&tr_with(
+            "image-title",
+            &[
+                ("tempo", &round(delay_table.tempo, ROUND_LIMIT)),
+                ("unit", &delay_table.unit)
+            ]
+        ))
+    );
+
+    for (column, rhythmic_modifier) in delay_table.rhythmic_modifiers.iter().enumerate() {
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-weight="bold">{}</text>"#,
+            column_x(column),
+            baseline(1),
+            escape(&rhythmic_modifier.to_string())
+        );
+    }
+
+    for (row, note_value) in NOTE_VALUES.iter().enumerate() {
+        let row = row + 2;
+        let line_y = MARGIN + ROW_HEIGHT * row;
+        let _ = writeln!(
+            svg,
+            "<line x1=\"{}\" y1=\"{line_y}\" x2=\"{}\" y2=\"{line_y}\" stroke=\"#ccc\"/>",
+            MARGIN,
+            width - MARGIN
+        );
+        if note_glyphs {
+            let top = line_y as f32 + (ROW_HEIGHT as f32 - GLYPH_HEIGHT) / 2.0;
+            let glyph = note_glyph::svg(*note_value, MARGIN as f32, top, GLYPH_HEIGHT, "black");
+            let _ = writeln!(svg, "{}", glyph);
+        } else {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                MARGIN,
+                baseline(row),
+                escape(&note_value.to_string())
+            );
+        }
+
+        for (column, delay_times) in delay_table.columns.iter().enumerate() {
+            let (value, unit) = cell_value(
+                delay_times,
+                *note_value,
+                &delay_table.unit,
+                millisecond_notes,
+            );
+            let value = round(value, ROUND_LIMIT);
+            // Only pinned cells differ from the unit in the title, so only they say which they're in
+            let cell = if *unit == delay_table.unit {
+                value.to_string()
+            } else {
+                format!("{} {}", value, unit)
+            };
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                column_x(column),
+                baseline(row),
+                escape(&cell)
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Unit names and tuplets are the user's own text
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+// The format follows the file extension, defaulting to SVG
+pub fn write_table_image(
+    path: &Path,
+    delay_table: &DelayTable,
+    millisecond_notes: &[NoteValue],
+    note_glyphs: bool,
+) -> Result<(), String> {
+    let svg = table_svg(delay_table, millisecond_notes, note_glyphs);
+
+    let is_png = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("png"));
+
+    if is_png {
+        write_png(path, &svg)
+    } else {
+        std::fs::write(path, svg).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_png(path: &Path, svg: &str) -> Result<(), String> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|error| error.to_string())?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(PNG_SCALE)
+        .ok_or("Image too large")?;
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).ok_or("Image too large")?;
+
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(PNG_SCALE, PNG_SCALE),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap.save_png(path).map_err(|error| error.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_png(_path: &Path, _svg: &str) -> Result<(), String> {
+    Err("PNG export isn't available on the web".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{custom_unit, RhythmicModifier, Unit};
+
+    #[test]
+    fn user_text_is_escaped() {
+        let unit = Unit::Custom(custom_unit::CustomUnit {
+            name: r#"<ticks> & "beats""#.to_string(),
+            ..custom_unit::CustomUnit::default()
+        });
+        let delay_table = DelayTable::new(120.0, unit, vec![RhythmicModifier::Normal]);
+        let svg = table_svg(&delay_table, &[], true);
+
+        assert!(svg.contains("(&lt;ticks&gt; &amp; &quot;beats&quot;)"));
+        assert!(resvg::usvg::Tree::from_str(&svg, &resvg::usvg::Options::default()).is_ok());
+    }
+
+    #[test]
+    fn pinned_notes_stay_in_milliseconds() {
+        let delay_table = DelayTable::new(120.0, Unit::Hertz, vec![RhythmicModifier::Normal]);
+        let svg = table_svg(&delay_table, &[NoteValue::Quarter], false);
+
+        assert!(svg.contains(">500 ms</text>"));
+        assert!(svg.contains(">4</text>"));
+    }
+}
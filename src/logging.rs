@@ -0,0 +1,169 @@
+// Diagnostics for when something misbehaves on a user's machine, written to
+// `<config dir>/delay_time_calculator/logs/delay_time_calculator.log`. Past `MAX_LOG_SIZE` the file
+// is moved to `delay_time_calculator.log.1`, so at most two are kept. By default only the app's own
+// events are logged; `--verbose` adds debug detail and what iced and the other libraries log. The
+// web build has nowhere to write, so nothing is logged there.
+#![cfg_attr(target_arch = "wasm32", allow(dead_code))]
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use log::{Level, Metadata, Record};
+
+use crate::settings;
+
+const MAX_LOG_SIZE: u64 = 1024 * 1024;
+
+pub fn is_verbose(mut args: impl Iterator<Item = String>) -> bool {
+    args.any(|arg| arg == "--verbose")
+}
+
+pub fn log_path() -> Option<PathBuf> {
+    settings::config_directory().map(|directory| {
+        directory
+            .join("logs")
+            .join(concat!(env!("CARGO_CRATE_NAME"), ".log"))
+    })
+}
+
+struct FileLogger {
+    path: PathBuf,
+    file: Mutex<Option<File>>,
+    verbose: bool,
+}
+
+impl log::Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        if self.verbose {
+            metadata.level() <= Level::Debug
+        } else {
+            metadata.level() <= Level::Info
+                && metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format_line(
+            &chrono::Local::now()
+                .format("%Y-%m-%d %H:%M:%S%.3f")
+                .to_string(),
+            record,
+        );
+
+        let Ok(mut file) = self.file.lock() else {
+            return;
+        };
+
+        // Logging failures have nowhere to be reported, so the line is just lost
+        if let Some(open_file) = file.as_mut() {
+            let _ = writeln!(open_file, "{}", line);
+            if open_file
+                .metadata()
+                .is_ok_and(|metadata| metadata.len() > MAX_LOG_SIZE)
+            {
+                *file = None;
+                *file = rotate(&self.path).ok();
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            if let Some(file) = file.as_mut() {
+                let _ = file.flush();
+            }
+        }
+    }
+}
+
+fn format_line(timestamp: &str, record: &Record) -> String {
+    format!(
+        "{} {:<5} {}: {}",
+        timestamp,
+        record.level(),
+        record.target(),
+        record.args()
+    )
+}
+
+// Moves the current log aside and starts a new one
+fn rotate(path: &Path) -> std::io::Result<File> {
+    let _ = std::fs::rename(path, path.with_extension("log.1"));
+    File::create(path)
+}
+
+fn open(path: &Path) -> std::io::Result<File> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > MAX_LOG_SIZE) {
+        return rotate(path);
+    }
+
+    File::options().create(true).append(true).open(path)
+}
+
+// Starting without a log is better than not starting
+#[cfg(not(target_arch = "wasm32"))]
+pub fn init(verbose: bool) {
+    let Some(path) = log_path() else {
+        return;
+    };
+    let Ok(file) = open(&path) else {
+        return;
+    };
+
+    let logger = FileLogger {
+        path,
+        file: Mutex::new(Some(file)),
+        verbose,
+    };
+
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(if verbose {
+            log::LevelFilter::Debug
+        } else {
+            log::LevelFilter::Info
+        });
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn init(_verbose: bool) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_lines() {
+        let line = format_line(
+            "2024-01-01 12:00:00.000",
+            &Record::builder()
+                .level(Level::Warn)
+                .target("delay_time_calculator")
+                .args(format_args!("Copying failed: {}", "no clipboard"))
+                .build(),
+        );
+
+        assert_eq!(
+            line,
+            "2024-01-01 12:00:00.000 WARN  delay_time_calculator: Copying failed: no clipboard"
+        );
+    }
+
+    #[test]
+    fn reads_the_flag() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert!(is_verbose(args(&["app", "--verbose"]).into_iter()));
+        assert!(!is_verbose(args(&["app", "--pipe"]).into_iter()));
+    }
+}
@@ -1,41 +1,121 @@
+// Without the window, the parts of the core only it uses go unused
+#![cfg_attr(not(feature = "gui"), allow(dead_code))]
+
+#[cfg(feature = "gui")]
+mod app;
+#[cfg(feature = "gui")]
+mod audio;
+mod automation;
+#[cfg(feature = "gui")]
+mod clipboard;
+mod color_tag;
+mod custom_unit;
+#[cfg(feature = "gui")]
+mod delay_range;
+mod export;
+mod expression;
+#[cfg(feature = "gui")]
+mod file_dialog;
+#[cfg(feature = "gui")]
+mod focus;
+mod http_api;
+mod i18n;
+#[cfg(feature = "gui")]
+mod image_export;
+mod logging;
+#[cfg(feature = "gui")]
+mod midi;
+mod midi_file;
+#[cfg(feature = "gui")]
+mod note_glyph;
+mod pipe;
+mod plugins;
+mod presets;
+mod project_file;
+mod session;
+mod settings;
+#[cfg(feature = "gui")]
+mod sparkline;
+mod tap;
+#[cfg(feature = "gui")]
+mod tap_calibration;
+mod tempo_action;
+mod time_signature;
+#[cfg(feature = "gui")]
+mod tools;
+#[cfg(feature = "gui")]
+mod tray;
+// crossterm has no terminal to drive in a browser
+#[cfg(not(target_arch = "wasm32"))]
+mod tui;
+#[cfg(feature = "gui")]
+mod update_check;
+
 use std::fmt::Display;
 
-use arboard::Clipboard;
-use delay_times;
-use iced::widget::{button, column, container, radio, text, text_input, Column, Row, Text};
-use iced::window::Settings;
-use iced::{Element, Length, Renderer, Size, Task, Theme};
+use custom_unit::{CustomBase, CustomUnit};
+use i18n::{tr, tr_with};
 use round::round;
-use tap_tempo::TapTempo;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 enum Unit {
     Milliseconds,
     Hertz,
+    // Sequencer ticks at the given PPQN (pulses per quarter note)
+    Ticks(u16),
+    // Audio samples at the given sample rate
+    Samples(u32),
+    // Milliseconds, with Hertz shown alongside each cell
+    Both,
+    // Milliseconds or Hertz times a factor, as set in preferences
+    Custom(CustomUnit),
 }
 
+// Hardware sequencers, most DAWs, and high-resolution DAWs respectively
+const PPQNS: [u16; 3] = [96, 480, 960];
+const DEFAULT_PPQN: u16 = 96;
+
+const SAMPLE_RATES: [u32; 4] = [44_100, 48_000, 88_200, 96_000];
+const DEFAULT_SAMPLE_RATE: u32 = 48_000;
+// Music and video rates, shown together for sessions that mix them
+const COMMON_SAMPLE_RATES: [u32; 2] = [44_100, 48_000];
+
 impl Unit {
-    fn toggle(&self) -> Self {
+    fn to_milliseconds(&self, value: f64, tempo: f64) -> f64 {
         match self {
-            Unit::Milliseconds => Unit::Hertz,
-            Unit::Hertz => Unit::Milliseconds,
+            Unit::Milliseconds | Unit::Both => value,
+            Unit::Hertz => 1000.0 / value,
+            Unit::Ticks(ppqn) => value * 60_000.0 / (tempo * f64::from(*ppqn)),
+            Unit::Samples(sample_rate) => value * 1000.0 / f64::from(*sample_rate),
+            Unit::Custom(custom_unit) => match custom_unit.base {
+                CustomBase::Milliseconds => value / custom_unit.factor,
+                CustomBase::Hertz => 1000.0 * custom_unit.factor / value,
+            },
         }
     }
 }
 
+// The unit values are in, so `Both` is "ms" in exports and copies
 impl Display for Unit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Unit::Milliseconds => write!(f, "ms"),
+            Unit::Milliseconds | Unit::Both => write!(f, "ms"),
             Unit::Hertz => write!(f, "Hz"),
+            Unit::Ticks(_) => write!(f, "ticks"),
+            Unit::Samples(_) => write!(f, "samples"),
+            Unit::Custom(custom_unit) => write!(f, "{}", custom_unit.name),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 enum RhythmicModifier {
     Normal,
     Dotted,
     Triplet,
+    // n:m, i.e. n notes in the time of m
+    Tuplet(u8, u8),
 }
 
 const RHYTHMIC_MODIFIER: [RhythmicModifier; 3] = [
@@ -44,17 +124,65 @@ const RHYTHMIC_MODIFIER: [RhythmicModifier; 3] = [
     RhythmicModifier::Triplet,
 ];
 
+impl RhythmicModifier {
+    // `Display` stays in English for exported files; this is for the screen
+    fn label(&self) -> String {
+        match self {
+            RhythmicModifier::Normal => tr("modifier-normal").to_string(),
+            RhythmicModifier::Dotted => tr("modifier-dotted").to_string(),
+            RhythmicModifier::Triplet => tr("modifier-triplet").to_string(),
+            RhythmicModifier::Tuplet(notes, in_time_of) => tr_with(
+                "modifier-tuplet",
+                &[("notes", notes), ("in-time-of", in_time_of)],
+            ),
+        }
+    }
+
+    fn factor(&self) -> f64 {
+        match self {
+            RhythmicModifier::Normal => 1.0,
+            RhythmicModifier::Dotted => 1.5,
+            RhythmicModifier::Triplet => 2.0 / 3.0,
+            RhythmicModifier::Tuplet(notes, in_time_of) => {
+                f64::from(*in_time_of) / f64::from(*notes)
+            }
+        }
+    }
+
+    // Accepts "n:m" with both sides between 1 and 99
+    fn parse_tuplet(text: &str) -> Option<Self> {
+        let (notes, in_time_of) = text.trim().split_once(':')?;
+        let notes = notes
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|n| (1..100).contains(n))?;
+        let in_time_of = in_time_of
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|m| (1..100).contains(m))?;
+        Some(RhythmicModifier::Tuplet(notes, in_time_of))
+    }
+}
+
 impl Display for RhythmicModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RhythmicModifier::Normal => write!(f, "Normal"),
             RhythmicModifier::Dotted => write!(f, "Dotted"),
             RhythmicModifier::Triplet => write!(f, "Triplet"),
+            RhythmicModifier::Tuplet(notes, in_time_of) => {
+                write!(f, "Tuplet {}:{}", notes, in_time_of)
+            }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 enum NoteValue {
+    QuadrupleWhole,
+    DoubleWhole,
     Whole,
     Half,
     Quarter,
@@ -65,9 +193,56 @@ enum NoteValue {
     HundredTwentyEighth,
 }
 
+impl NoteValue {
+    fn beats(&self) -> f64 {
+        match self {
+            NoteValue::QuadrupleWhole => 16.0,
+            NoteValue::DoubleWhole => 8.0,
+            NoteValue::Whole => 4.0,
+            NoteValue::Half => 2.0,
+            NoteValue::Quarter => 1.0,
+            NoteValue::Eighth => 0.5,
+            NoteValue::Sixteenth => 0.25,
+            NoteValue::ThirtySecond => 1.0 / 8.0,
+            NoteValue::SixtyFourth => 1.0 / 16.0,
+            NoteValue::HundredTwentyEighth => 1.0 / 32.0,
+        }
+    }
+
+    // `Display` stays a fraction for exported files; this is for the screen
+    fn label(&self, names: NoteNames) -> String {
+        let (american, british) = match self {
+            NoteValue::QuadrupleWhole => ("note-quadruple-whole", "note-longa"),
+            NoteValue::DoubleWhole => ("note-double-whole", "note-breve"),
+            NoteValue::Whole => ("note-whole", "note-semibreve"),
+            NoteValue::Half => ("note-half", "note-minim"),
+            NoteValue::Quarter => ("note-quarter", "note-crotchet"),
+            NoteValue::Eighth => ("note-eighth", "note-quaver"),
+            NoteValue::Sixteenth => ("note-sixteenth", "note-semiquaver"),
+            NoteValue::ThirtySecond => ("note-thirty-second", "note-demisemiquaver"),
+            NoteValue::SixtyFourth => ("note-sixty-fourth", "note-hemidemisemiquaver"),
+            NoteValue::HundredTwentyEighth => {
+                ("note-hundred-twenty-eighth", "note-semihemidemisemiquaver")
+            }
+        };
+        let (american, british) = (tr(american), tr(british));
+
+        match names {
+            NoteNames::Fractions => self.to_string(),
+            NoteNames::American => american.to_string(),
+            NoteNames::British => british.to_string(),
+            // Languages without two naming systems only need the name once
+            NoteNames::Both if american == british => american.to_string(),
+            NoteNames::Both => format!("{} ({})", american, british.to_lowercase()),
+        }
+    }
+}
+
 impl Display for NoteValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let note = match self {
+            NoteValue::QuadrupleWhole => "4",
+            NoteValue::DoubleWhole => "2",
             NoteValue::Whole => "1",
             NoteValue::Half => "1/2",
             NoteValue::Quarter => "1/4",
@@ -82,7 +257,33 @@ impl Display for NoteValue {
     }
 }
 
-const NOTE_VALUES: [NoteValue; 8] = [
+// How rows are labeled when glyphs are off
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+enum NoteNames {
+    #[default]
+    Fractions,
+    American,
+    British,
+    Both,
+}
+
+const NOTE_NAMES: [NoteNames; 4] = [
+    NoteNames::Fractions,
+    NoteNames::American,
+    NoteNames::British,
+    NoteNames::Both,
+];
+
+// Each choice is shown by how it labels a quarter note
+impl Display for NoteNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", NoteValue::Quarter.label(*self))
+    }
+}
+
+const NOTE_VALUES: [NoteValue; 10] = [
+    NoteValue::QuadrupleWhole,
+    NoteValue::DoubleWhole,
     NoteValue::Whole,
     NoteValue::Half,
     NoteValue::Quarter,
@@ -95,232 +296,323 @@ const NOTE_VALUES: [NoteValue; 8] = [
 
 const SPACING: u16 = 15;
 const NOT_APPLICABLE: &str = "N/A";
-const INITIAL_WINDOW_SIZE: Size = Size {
-    width: 650.0,
-    height: 600.0,
-};
 const ROUND_LIMIT: i32 = 3;
+const DEFAULT_TEMPO: f64 = 120.0;
+
+// Returns whether a terminal mode ran, leaving nothing for the window to do
+fn run_terminal_modes() -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    if tui::is_enabled(std::env::args()) {
+        if let Err(error) = tui::run(settings::load()) {
+            eprintln!("Couldn't start the terminal UI: {}", error);
+        }
+        return true;
+    }
 
-pub fn main() -> iced::Result {
-    iced::application("Delay Time Calculator", Tap::update, Tap::view)
-        .theme(|_| Theme::Dracula)
-        .window(Settings {
-            size: Size {
-                ..INITIAL_WINDOW_SIZE
-            },
-            min_size: Some(Size {
-                ..INITIAL_WINDOW_SIZE
-            }),
-            max_size: None,
-            ..Settings::default()
-        })
-        .antialiasing(true)
-        .run()
+    if pipe::is_headless(std::env::args()) {
+        pipe::run_headless(settings::load());
+        return true;
+    }
+
+    false
 }
 
-struct Tap {
-    tap_tempo: TapTempo,
-    tempo: Option<f64>,
-    tempo_input_text: String,
-    unit: Unit,
-    clipboard: Option<Clipboard>,
+#[cfg(feature = "gui")]
+pub fn main() -> iced::Result {
+    logging::init(logging::is_verbose(std::env::args()));
+
+    if run_terminal_modes() {
+        return Ok(());
+    }
+
+    app::run()
 }
 
-#[derive(Debug, Clone)]
-enum Message {
-    Tap,
-    Reset,
-    ScaleTempo(f64),
-    StoreTempo(String),
-    ToggleUnit,
-    CopyToClipboard(f64),
+// With no window to open, scripting over stdin is what's left
+#[cfg(not(feature = "gui"))]
+pub fn main() {
+    logging::init(logging::is_verbose(std::env::args()));
+
+    if !run_terminal_modes() {
+        pipe::run_headless(settings::load());
+    }
 }
 
-impl Default for Tap {
-    fn default() -> Self {
-        let tempo = 120.0;
+// The computed table only depends on the tempo, unit, and columns, so it's cached on those rather
+// than recomputed on every view pass
+struct DelayTable {
+    tempo: f64,
+    unit: Unit,
+    rhythmic_modifiers: Vec<RhythmicModifier>,
+    // One per entry in `rhythmic_modifiers`
+    columns: Vec<delay_times::DelayTimes>,
+}
 
+impl DelayTable {
+    fn new(tempo: f64, unit: Unit, rhythmic_modifiers: Vec<RhythmicModifier>) -> Self {
         Self {
-            tap_tempo: TapTempo::new(),
-            tempo: Some(tempo),
-            tempo_input_text: tempo.to_string(),
-            unit: Unit::Milliseconds,
-            clipboard: Clipboard::new().ok(),
+            tempo,
+            columns: rhythmic_modifiers
+                .iter()
+                .map(|rhythmic_modifier| delay_times(tempo, rhythmic_modifier, &unit))
+                .collect(),
+            unit,
+            rhythmic_modifiers,
         }
     }
 }
 
-impl Tap {
-    fn update(&mut self, message: Message) -> Task<Message> {
-        match message {
-            Message::Tap => {
-                self.tempo = self.tap_tempo.tap();
-                match self.tempo {
-                    Some(tempo) => self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string(),
-                    None => self.tempo_input_text = NOT_APPLICABLE.to_string(),
-                }
-            }
-            Message::Reset => {
-                self.tap_tempo.reset();
-            }
-            Message::ScaleTempo(scale) => {
-                if let Some(tempo) = self.tempo {
-                    let tempo = tempo * scale;
-                    self.tempo = Some(tempo);
-                    self.tempo_input_text = round(tempo, ROUND_LIMIT).to_string();
-                }
-            }
-            Message::StoreTempo(text) => {
-                self.tempo_input_text = text;
-                self.tempo = self.tempo_input_text.parse().ok();
-            }
-            Message::ToggleUnit => self.unit = self.unit.toggle(),
-            Message::CopyToClipboard(value) => {
-                self.clipboard
-                    .as_mut()
-                    .map(|clipboard| clipboard.set_text(value.to_string()));
-            }
+// Only finite, positive tempos produce meaningful delay times. A value ending in ms or Hz is taken as
+// the length of a quarter note, e.g. "500ms" and "2Hz" are both 120 BPM.
+fn parse_tempo(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let lowercase = text.to_lowercase();
+
+    let tempo = if let Some(period) = lowercase.strip_suffix("ms") {
+        expression::evaluate(period).map(|ms| 60_000.0 / ms)
+    } else if let Some(frequency) = lowercase.strip_suffix("hz") {
+        expression::evaluate(frequency).map(|hz| hz * 60.0)
+    } else {
+        expression::evaluate(text)
+    };
+
+    tempo.filter(|tempo| tempo.is_finite() && *tempo > 0.0)
+}
+
+fn delay_times(
+    tempo: f64,
+    rhythmic_modifier: &RhythmicModifier,
+    unit: &Unit,
+) -> delay_times::DelayTimes {
+    // A tick lasts a millisecond at 60000 / PPQN BPM, so ticks come out of the millisecond math.
+    // Samples are milliseconds scaled up by the rate in kHz, which is the tempo scaled down.
+    // Likewise, scaling milliseconds up is slowing the tempo down, and scaling Hertz up speeds it up
+    let tempo = match unit {
+        Unit::Ticks(ppqn) => 60_000.0 / f64::from(*ppqn),
+        Unit::Samples(sample_rate) => tempo * 1000.0 / f64::from(*sample_rate),
+        Unit::Custom(custom_unit) => match custom_unit.base {
+            CustomBase::Milliseconds => tempo / custom_unit.factor,
+            CustomBase::Hertz => tempo * custom_unit.factor,
+        },
+        _ => tempo,
+    };
+
+    // A tuplet's values are the straight values at the tempo divided by its ratio
+    let tempo = match rhythmic_modifier {
+        RhythmicModifier::Tuplet(..) => tempo / rhythmic_modifier.factor(),
+        _ => tempo,
+    };
+
+    let delay_times = delay_times::DelayTimes::new(tempo);
+    let delay_times = match unit {
+        Unit::Custom(custom_unit) if custom_unit.base == CustomBase::Hertz => delay_times.in_hz(),
+        Unit::Milliseconds | Unit::Ticks(_) | Unit::Samples(_) | Unit::Both | Unit::Custom(_) => {
+            delay_times.in_ms()
         }
+        Unit::Hertz => delay_times.in_hz(),
+    };
+    match rhythmic_modifier {
+        RhythmicModifier::Normal | RhythmicModifier::Tuplet(..) => delay_times.normal(),
+        RhythmicModifier::Dotted => delay_times.dotted(),
+        RhythmicModifier::Triplet => delay_times.triplet(),
+    }
+}
 
-        Task::none()
+// How many quarter notes long a value is, which holds at any tempo and in either unit
+fn quarter_ratio(rhythmic_modifier: &RhythmicModifier, note_value: &NoteValue) -> f64 {
+    note_value.beats() * rhythmic_modifier.factor()
+}
+
+fn delay_time(delay_times: &delay_times::DelayTimes, note_value: &NoteValue) -> f64 {
+    // `delay_times` stops at a whole note. Each doubling of length scales by the whole-to-half
+    // ratio, which is 2 in milliseconds and 0.5 in Hertz.
+    let doubling = delay_times.v_whole / delay_times.v_half;
+
+    match note_value {
+        NoteValue::QuadrupleWhole => delay_times.v_whole * doubling * doubling,
+        NoteValue::DoubleWhole => delay_times.v_whole * doubling,
+        NoteValue::Whole => delay_times.v_whole,
+        NoteValue::Half => delay_times.v_half,
+        NoteValue::Quarter => delay_times.v_quarter,
+        NoteValue::Eighth => delay_times.v_8th,
+        NoteValue::Sixteenth => delay_times.v_16th,
+        NoteValue::ThirtySecond => delay_times.v_32nd,
+        NoteValue::SixtyFourth => delay_times.v_64th,
+        NoteValue::HundredTwentyEighth => delay_times.v_128th,
     }
+}
 
-    fn view(&self) -> Element<Message> {
-        let (ms_selected, hz_selected) = match self.unit {
-            Unit::Milliseconds => (Some(()), None),
-            Unit::Hertz => (None, Some(())),
-        };
+// The value at whichever common rates aren't already the selected one
+fn other_sample_rates(value: f64, sample_rate: u32) -> impl Iterator<Item = (u32, f64)> {
+    COMMON_SAMPLE_RATES
+        .into_iter()
+        .filter(move |other_rate| *other_rate != sample_rate)
+        .map(move |other_rate| {
+            (
+                other_rate,
+                value * f64::from(other_rate) / f64::from(sample_rate),
+            )
+        })
+}
+
+// E.g. "44.1k"
+fn sample_rate_label(sample_rate: u32) -> String {
+    format!("{}k", f64::from(sample_rate) / 1000.0)
+}
 
-        let controls_row = Row::with_children(vec![
-            button("Tap").on_press(Message::Tap).into(),
-            button("Reset")
-                .style(|theme: &Theme, status| {
-                    if self.tap_tempo.tap_count() > 0 {
-                        let palette = theme.extended_palette();
-                        button::Style::default().with_background(palette.success.strong.color)
-                    } else {
-                        button::primary(theme, status)
-                    }
-                })
-                .on_press(Message::Reset)
-                .into(),
-            text_input("", self.tempo_input_text.as_str())
-                .on_input(|text| Message::StoreTempo(text))
-                .into(),
-            button("Halve").on_press(Message::ScaleTempo(0.5)).into(),
-            button("Double").on_press(Message::ScaleTempo(2.0)).into(),
-            radio(Unit::Milliseconds.to_string(), (), ms_selected, |_| {
-                Message::ToggleUnit
-            })
-            .into(),
-            radio(Unit::Hertz.to_string(), (), hz_selected, |_| {
-                Message::ToggleUnit
-            })
-            .into(),
-        ])
-        .spacing(SPACING);
-
-        let table = table(self.tempo, &self.unit).height(Length::Fill);
-        let column = column![controls_row, table].spacing(SPACING);
-
-        container(column).padding(SPACING).into()
+// A cell's value and the unit it's shown in
+fn cell_value<'a>(
+    delay_times: &delay_times::DelayTimes,
+    note_value: NoteValue,
+    unit: &'a Unit,
+    millisecond_notes: &[NoteValue],
+) -> (f64, &'a Unit) {
+    let value = delay_time(delay_times, &note_value);
+
+    match unit {
+        Unit::Hertz if millisecond_notes.contains(&note_value) => {
+            (1000.0 / value, &Unit::Milliseconds)
+        }
+        _ => (value, unit),
     }
+}
 
-    // fn subscription(&self) -> Subscription<Message> {
-    //     if self.last_tap.is_some() {
-    //         return Subscription::new();
-    //     }
+// Rounded values further than this fraction of the exact delay get a notice. It's relative since a
+// fixed amount is nothing on a long delay, and every long note rounds by a few ms once in Hertz.
+const DRIFT_NOTICE_RATIO: f64 = 0.005;
 
-    //     Subscription::none()
-    // }
+// How far, in ms, a value rounded to `precision` lands from the exact delay, when it's far enough
+// to hear. Slow Hertz values are where this bites: at 40 BPM a 24000 ms delay is 0.042 Hz, which
+// plays back as 23809.524 ms.
+fn rounding_drift_ms(value: f64, unit: &Unit, precision: i32, tempo: f64) -> Option<f64> {
+    let exact = unit.to_milliseconds(value, tempo);
+    let rounded = unit.to_milliseconds(round(value, precision), tempo);
+    let drift = (rounded - exact).abs();
+
+    Some(drift).filter(|drift| drift.is_finite() && *drift > exact.abs() * DRIFT_NOTICE_RATIO)
 }
 
-fn table<'a>(tempo: Option<f64>, unit: &Unit) -> Row<'a, Message, Theme, Renderer> {
-    let mut note_labels: Vec<Element<_>> = vec![
-        text("").height(Length::Fill).into(), // Is there a better way to add a blank cell?
-    ];
-
-    note_labels.extend(NOTE_VALUES.map(|note_value| {
-        text(format!("{}:", note_value.to_string()))
-            .height(Length::Fill)
-            .into()
-    }));
-
-    let note_label_column = Column::with_children(note_labels)
-        .height(Length::Fill)
-        .spacing(SPACING);
-
-    let mut table: Vec<Element<_>> = vec![note_label_column.width(Length::Fill).into()];
-
-    for rhythmic_modifier in &RHYTHMIC_MODIFIER {
-        table.push(
-            values_column(tempo, rhythmic_modifier, unit)
-                .width(Length::Fill)
-                .spacing(SPACING)
-                .into(),
+#[cfg(test)]
+mod tests {
+    use round::round;
+
+    use super::*;
+
+    #[test]
+    fn parses_tempos() {
+        assert_eq!(parse_tempo("120"), Some(120.0));
+        assert_eq!(parse_tempo("174/2"), Some(87.0));
+        assert_eq!(parse_tempo("0"), None);
+        assert_eq!(parse_tempo("-120"), None);
+        assert_eq!(parse_tempo("1/0"), None);
+        assert_eq!(parse_tempo("fast"), None);
+
+        // Quarter-note periods
+        assert_eq!(parse_tempo("500ms"), Some(120.0));
+        assert_eq!(parse_tempo("750 ms"), Some(80.0));
+        assert_eq!(parse_tempo("2Hz"), Some(120.0));
+        assert_eq!(parse_tempo("0ms"), None);
+        assert_eq!(parse_tempo("ms"), None);
+    }
+
+    #[test]
+    fn table_values() {
+        let normal = delay_times(120.0, &RhythmicModifier::Normal, &Unit::Milliseconds);
+        let dotted = delay_times(120.0, &RhythmicModifier::Dotted, &Unit::Milliseconds);
+        let triplet = delay_times(120.0, &RhythmicModifier::Triplet, &Unit::Milliseconds);
+
+        assert_eq!(delay_time(&normal, &NoteValue::Whole), 2000.0);
+        assert_eq!(delay_time(&normal, &NoteValue::QuadrupleWhole), 8000.0);
+        assert_eq!(delay_time(&normal, &NoteValue::Eighth), 250.0);
+        assert_eq!(delay_time(&dotted, &NoteValue::Quarter), 750.0);
+        assert_eq!(
+            round(delay_time(&triplet, &NoteValue::Quarter), ROUND_LIMIT),
+            333.333
         );
+
+        let hertz = delay_times(120.0, &RhythmicModifier::Normal, &Unit::Hertz);
+        assert_eq!(delay_time(&hertz, &NoteValue::Half), 1.0);
     }
 
-    Row::with_children(table).spacing(SPACING)
-}
+    #[test]
+    fn rounding_drift() {
+        assert_eq!(
+            rounding_drift_ms(1875.0, &Unit::Milliseconds, 0, 128.0),
+            None
+        );
+        assert_eq!(
+            rounding_drift_ms(1000.0 / 1875.0, &Unit::Hertz, 6, 128.0),
+            None
+        );
+        // 1875 ms rounds to 0.533 Hz, a ms out, which is too little of the delay to hear
+        assert_eq!(
+            rounding_drift_ms(1000.0 / 1875.0, &Unit::Hertz, 3, 128.0),
+            None
+        );
+        assert_eq!(
+            rounding_drift_ms(1000.0 / 24000.0, &Unit::Hertz, 3, 40.0).map(|drift| round(drift, 3)),
+            Some(190.476)
+        );
+    }
 
-fn values_column<'a>(
-    tempo: Option<f64>,
-    rhythmic_modifier: &RhythmicModifier,
-    unit: &Unit,
-) -> Column<'a, Message, Theme, Renderer> {
-    let delay_times = tempo.map(|tempo| {
-        let delay_times = delay_times::DelayTimes::new(tempo);
-        let delay_times = match unit {
-            Unit::Milliseconds => delay_times.in_ms(),
-            Unit::Hertz => delay_times.in_hz(),
-        };
-        match rhythmic_modifier {
-            RhythmicModifier::Normal => delay_times.normal(),
-            RhythmicModifier::Dotted => delay_times.dotted(),
-            RhythmicModifier::Triplet => delay_times.triplet(),
-        }
-    });
-
-    let mut column: Vec<Element<_>> = vec![text(rhythmic_modifier.to_string())
-        .height(Length::Fill)
-        .into()];
-
-    column.extend(NOTE_VALUES.map(|note_value| {
-        let value = delay_times.as_ref().map(|delay_times| match note_value {
-            NoteValue::Whole => delay_times.v_whole,
-            NoteValue::Half => delay_times.v_half,
-            NoteValue::Quarter => delay_times.v_quarter,
-            NoteValue::Eighth => delay_times.v_8th,
-            NoteValue::Sixteenth => delay_times.v_16th,
-            NoteValue::ThirtySecond => delay_times.v_32nd,
-            NoteValue::SixtyFourth => delay_times.v_64th,
-            NoteValue::HundredTwentyEighth => delay_times.v_128th,
-        });
-
-        let display_text = value
-            .map(|value| format!("{} {}", round(value, ROUND_LIMIT), unit.to_string()))
-            .unwrap_or(NOT_APPLICABLE.to_string());
-
-        let mut button = button(Text::new(display_text));
-
-        if let Some(value) = value {
-            button = button.on_press(Message::CopyToClipboard(value));
-        };
+    #[test]
+    fn ticks_ignore_the_tempo() {
+        let quarter = delay_times(93.0, &RhythmicModifier::Normal, &Unit::Ticks(96));
+        assert_eq!(
+            round(delay_time(&quarter, &NoteValue::Quarter), ROUND_LIMIT),
+            96.0
+        );
 
-        button.height(Length::Fill).width(Length::Fill).into()
-    }));
+        let dotted = delay_times(140.0, &RhythmicModifier::Dotted, &Unit::Ticks(960));
+        assert_eq!(
+            round(delay_time(&dotted, &NoteValue::Eighth), ROUND_LIMIT),
+            720.0
+        );
+
+        let tuplet = delay_times(120.0, &RhythmicModifier::Tuplet(5, 4), &Unit::Ticks(480));
+        assert_eq!(
+            round(delay_time(&tuplet, &NoteValue::Quarter), ROUND_LIMIT),
+            384.0
+        );
+
+        assert_eq!(Unit::Ticks(480).to_milliseconds(240.0, 120.0), 250.0);
+    }
+
+    #[test]
+    fn note_names() {
+        assert_eq!(NoteValue::Quarter.label(NoteNames::Fractions), "1/4");
+        assert_eq!(NoteValue::Quarter.label(NoteNames::American), "Quarter");
+        assert_eq!(NoteValue::Eighth.label(NoteNames::British), "Quaver");
+        assert_eq!(
+            NoteValue::Quarter.label(NoteNames::Both),
+            "Quarter (crotchet)"
+        );
+    }
 
-    Column::with_children(column)
+    #[test]
+    fn quarter_ratios() {
+        assert_eq!(
+            quarter_ratio(&RhythmicModifier::Dotted, &NoteValue::Eighth),
+            0.75
+        );
+        assert_eq!(
+            quarter_ratio(&RhythmicModifier::Dotted, &NoteValue::Quarter),
+            1.5
+        );
+        assert_eq!(
+            quarter_ratio(&RhythmicModifier::Tuplet(5, 4), &NoteValue::Quarter),
+            0.8
+        );
+    }
 }
 
 // TODO: Style buttons to look like label
+// TODO: Screen reader names for buttons and cells (e.g. "Dotted eighth, 375 milliseconds, press to
+// copy") once iced exposes an accessibility tree; 0.13 has no AccessKit integration to attach them to
 // TODO: simplify tests
 // TODO: auto reset tap tempo
 // TODO: reverse input
 // TODO: keyboard driven
 // TODO: styling
 // TODO: precision input
-// TODO: Click and drag to adjust tempo
 // TODO: [Other features](https://github.com/JosephTLyons/GUI-Delay-Time-Calculator?tab=readme-ov-file#features)
-// TODO: Round input when using enter or exiting focus
@@ -0,0 +1,270 @@
+// MIDI device access lives behind the `midi` feature, since midir needs the platform MIDI
+// libraries (ALSA on Linux) at build time. Without it, no ports are listed and nothing connects.
+
+use iced::Subscription;
+
+pub const IS_SUPPORTED: bool = cfg!(feature = "midi");
+
+#[cfg(feature = "midi")]
+const CLIENT_NAME: &str = "Delay Time Calculator";
+
+#[cfg(feature = "midi")]
+pub fn input_port_names() -> Vec<String> {
+    let Ok(midi_input) = midir::MidiInput::new(CLIENT_NAME) else {
+        return Vec::new();
+    };
+
+    midi_input
+        .ports()
+        .iter()
+        .filter_map(|port| midi_input.port_name(port).ok())
+        .collect()
+}
+
+#[cfg(not(feature = "midi"))]
+pub fn input_port_names() -> Vec<String> {
+    Vec::new()
+}
+
+// Emits every raw message received on the named input port for as long as the subscription lives
+#[cfg(feature = "midi")]
+pub fn listen(port_name: String) -> Subscription<Vec<u8>> {
+    use iced::futures::channel::mpsc::Sender;
+
+    fn connect(
+        port_name: &str,
+        mut output: Sender<Vec<u8>>,
+    ) -> Option<midir::MidiInputConnection<()>> {
+        let mut midi_input = midir::MidiInput::new(CLIENT_NAME).ok()?;
+        midi_input.ignore(midir::Ignore::None);
+
+        let Some(port) = midi_input
+            .ports()
+            .into_iter()
+            .find(|port| midi_input.port_name(port).ok().as_deref() == Some(port_name))
+        else {
+            log::warn!("MIDI input {} not found", port_name);
+            return None;
+        };
+
+        let connection = midi_input.connect(
+            &port,
+            CLIENT_NAME,
+            move |_, message, _| {
+                log::debug!("MIDI in: {:02X?}", message);
+                let _ = output.try_send(message.to_vec());
+            },
+            (),
+        );
+
+        match connection {
+            Ok(connection) => {
+                log::info!("Listening to MIDI input {}", port_name);
+                Some(connection)
+            }
+            Err(error) => {
+                log::warn!("Couldn't connect to MIDI input {}: {}", port_name, error);
+                None
+            }
+        }
+    }
+
+    Subscription::run_with_id(
+        port_name.clone(),
+        iced::stream::channel(100, move |output| async move {
+            let _connection = connect(&port_name, output);
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+#[cfg(not(feature = "midi"))]
+pub fn listen(_port_name: String) -> Subscription<Vec<u8>> {
+    Subscription::none()
+}
+
+#[cfg(feature = "midi")]
+pub fn output_port_names() -> Vec<String> {
+    let Ok(midi_output) = midir::MidiOutput::new(CLIENT_NAME) else {
+        return Vec::new();
+    };
+
+    midi_output
+        .ports()
+        .iter()
+        .filter_map(|port| midi_output.port_name(port).ok())
+        .collect()
+}
+
+#[cfg(not(feature = "midi"))]
+pub fn output_port_names() -> Vec<String> {
+    Vec::new()
+}
+
+// Only read by the clock thread, which doesn't exist without the `midi` feature
+#[cfg_attr(not(feature = "midi"), allow(dead_code))]
+pub enum ClockCommand {
+    SetTempo(f64),
+    Start,
+    Stop,
+}
+
+// Sends MIDI clock from its own thread, so ticks stay steady however busy the UI is. Clock runs
+// continuously so devices can follow the tempo while stopped; MTC, when enabled, only runs between
+// start and stop. Dropping this stops the thread and closes the port.
+pub struct ClockOutput {
+    commands: std::sync::mpsc::Sender<ClockCommand>,
+}
+
+impl ClockOutput {
+    pub fn send(&self, command: ClockCommand) {
+        let _ = self.commands.send(command);
+    }
+}
+
+#[cfg(feature = "midi")]
+pub fn clock_output(port_name: &str, tempo: f64, send_mtc: bool) -> Result<ClockOutput, String> {
+    let midi_output = midir::MidiOutput::new(CLIENT_NAME).map_err(|error| error.to_string())?;
+    let port = midi_output
+        .ports()
+        .into_iter()
+        .find(|port| midi_output.port_name(port).ok().as_deref() == Some(port_name))
+        .ok_or("MIDI output not found")?;
+    let connection = midi_output
+        .connect(&port, CLIENT_NAME)
+        .map_err(|error| error.to_string())?;
+
+    let (commands, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || run_clock(connection, receiver, tempo, send_mtc));
+    log::info!("Sending MIDI clock to {}", port_name);
+
+    Ok(ClockOutput { commands })
+}
+
+#[cfg(not(feature = "midi"))]
+pub fn clock_output(_port_name: &str, _tempo: f64, _send_mtc: bool) -> Result<ClockOutput, String> {
+    Err("MIDI output needs a build with the `midi` feature enabled".to_string())
+}
+
+#[cfg(feature = "midi")]
+fn run_clock(
+    mut connection: midir::MidiOutputConnection,
+    commands: std::sync::mpsc::Receiver<ClockCommand>,
+    mut tempo: f64,
+    send_mtc: bool,
+) {
+    use std::sync::mpsc::RecvTimeoutError;
+    use std::time::{Duration, Instant};
+
+    const CLOCKS_PER_BEAT: f64 = 24.0;
+    const START: u8 = 0xFA;
+    const STOP: u8 = 0xFC;
+    const CLOCK: u8 = 0xF8;
+
+    let clock_interval = |tempo: f64| Duration::from_secs_f64(60.0 / (tempo * CLOCKS_PER_BEAT));
+    let quarter_frame_interval = Duration::from_secs_f64(1.0 / (MTC_FRAMES_PER_SECOND * 4.0));
+
+    let mut next_clock = Instant::now();
+    // While running with MTC: when the transport started, when the next quarter frame is due, and
+    // which of the eight pieces it is
+    let mut mtc: Option<(Instant, Instant, u8)> = None;
+
+    loop {
+        let deadline = match mtc {
+            Some((_, next_quarter_frame, _)) => next_clock.min(next_quarter_frame),
+            None => next_clock,
+        };
+
+        match commands.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(ClockCommand::SetTempo(new_tempo)) => tempo = new_tempo,
+            Ok(ClockCommand::Start) => {
+                let now = Instant::now();
+                if send_mtc {
+                    let _ = connection.send(&full_frame(Duration::ZERO));
+                    mtc = Some((now, now, 0));
+                }
+                let _ = connection.send(&[START]);
+                next_clock = now;
+            }
+            Ok(ClockCommand::Stop) => {
+                let _ = connection.send(&[STOP]);
+                mtc = None;
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+
+                if now >= next_clock {
+                    let _ = connection.send(&[CLOCK]);
+                    next_clock += clock_interval(tempo);
+                }
+
+                if let Some((started, next_quarter_frame, piece)) = &mut mtc {
+                    if now >= *next_quarter_frame {
+                        // A full set of eight pieces spans two frames, so every piece describes
+                        // the frame the set started on
+                        let set_start = *next_quarter_frame
+                            - quarter_frame_interval * u32::from(*piece)
+                            - *started;
+                        let _ = connection.send(&quarter_frame(set_start, *piece));
+                        *piece = (*piece + 1) % 8;
+                        *next_quarter_frame += quarter_frame_interval;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "midi")]
+const MTC_FRAMES_PER_SECOND: f64 = 25.0;
+// The rate code for 25 fps in the hours byte
+#[cfg(feature = "midi")]
+const MTC_RATE_BITS: u8 = 0b01;
+
+#[cfg(feature = "midi")]
+fn timecode(elapsed: std::time::Duration) -> [u8; 4] {
+    let total_frames = (elapsed.as_secs_f64() * MTC_FRAMES_PER_SECOND) as u64;
+    let frames_per_second = MTC_FRAMES_PER_SECOND as u64;
+    let total_seconds = total_frames / frames_per_second;
+
+    [
+        (total_seconds / 3600 % 24) as u8,
+        (total_seconds / 60 % 60) as u8,
+        (total_seconds % 60) as u8,
+        (total_frames % frames_per_second) as u8,
+    ]
+}
+
+#[cfg(feature = "midi")]
+fn full_frame(elapsed: std::time::Duration) -> [u8; 10] {
+    let [hours, minutes, seconds, frames] = timecode(elapsed);
+    [
+        0xF0,
+        0x7F,
+        0x7F,
+        0x01,
+        0x01,
+        MTC_RATE_BITS << 5 | hours,
+        minutes,
+        seconds,
+        frames,
+        0xF7,
+    ]
+}
+
+#[cfg(feature = "midi")]
+fn quarter_frame(elapsed: std::time::Duration, piece: u8) -> [u8; 2] {
+    let [hours, minutes, seconds, frames] = timecode(elapsed);
+    let nibble = match piece {
+        0 => frames & 0x0F,
+        1 => frames >> 4,
+        2 => seconds & 0x0F,
+        3 => seconds >> 4,
+        4 => minutes & 0x0F,
+        5 => minutes >> 4,
+        6 => hours & 0x0F,
+        _ => MTC_RATE_BITS << 1 | hours >> 4,
+    };
+    [0xF1, piece << 4 | nibble]
+}
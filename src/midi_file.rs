@@ -0,0 +1,104 @@
+// Reads the tempo map out of a Standard MIDI File, which DAWs embed in every exported .mid
+
+use std::path::Path;
+
+use midly::{MetaMessage, Smf, Timing, TrackEventKind};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempoEvent {
+    // Position in quarter notes from the start of the file, when the file's timing allows it
+    pub beat: Option<f64>,
+    pub tempo: f64,
+}
+
+// Tempo changes in file order, with repeats of the same tempo collapsed
+pub fn tempo_events(path: &Path) -> Result<Vec<TempoEvent>, String> {
+    let bytes = std::fs::read(path).map_err(|error| error.to_string())?;
+    let smf = Smf::parse(&bytes).map_err(|error| error.to_string())?;
+
+    let ticks_per_beat = match smf.header.timing {
+        Timing::Metrical(ticks_per_beat) => Some(f64::from(ticks_per_beat.as_int())),
+        Timing::Timecode(..) => None,
+    };
+
+    let mut events: Vec<(u64, f64)> = smf
+        .tracks
+        .iter()
+        .flat_map(|track| {
+            let mut tick = 0;
+            track.iter().filter_map(move |event| {
+                tick += u64::from(event.delta.as_int());
+                match event.kind {
+                    TrackEventKind::Meta(MetaMessage::Tempo(microseconds_per_beat)) => Some((
+                        tick,
+                        60_000_000.0 / f64::from(microseconds_per_beat.as_int()),
+                    )),
+                    _ => None,
+                }
+            })
+        })
+        .collect();
+
+    // Type 1 files keep the tempo map in the first track, but nothing stops others from having them
+    events.sort_by_key(|(tick, _)| *tick);
+    events.dedup_by(|(_, b), (_, a)| a == b);
+
+    Ok(events
+        .into_iter()
+        .map(|(tick, tempo)| TempoEvent {
+            beat: ticks_per_beat.map(|ticks_per_beat| tick as f64 / ticks_per_beat),
+            tempo,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use midly::{Format, Header, TrackEvent};
+
+    use super::*;
+
+    fn tempo(microseconds_per_beat: u32, delta: u32) -> TrackEvent<'static> {
+        TrackEvent {
+            delta: delta.into(),
+            kind: TrackEventKind::Meta(MetaMessage::Tempo(microseconds_per_beat.into())),
+        }
+    }
+
+    #[test]
+    fn reads_tempo_changes() {
+        let mut smf = Smf::new(Header::new(
+            Format::SingleTrack,
+            Timing::Metrical(480.into()),
+        ));
+        smf.tracks.push(vec![
+            tempo(500_000, 0),
+            // Restating the same tempo isn't a change
+            tempo(500_000, 960),
+            tempo(400_000, 960),
+            TrackEvent {
+                delta: 0.into(),
+                kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+            },
+        ]);
+
+        let path = std::env::temp_dir().join("delay_time_calculator_tempo_events_test.mid");
+        smf.save(&path).unwrap();
+        let events = tempo_events(&path);
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(
+            events,
+            Ok(vec![
+                TempoEvent {
+                    beat: Some(0.0),
+                    tempo: 120.0,
+                },
+                TempoEvent {
+                    beat: Some(4.0),
+                    tempo: 150.0,
+                },
+            ])
+        );
+    }
+}
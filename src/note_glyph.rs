@@ -0,0 +1,268 @@
+// Note values drawn from simple shapes rather than a music font, so they look the same on every
+// system and in exported images. Coordinates are in staff spaces, with the noteheads on one line.
+
+use std::fmt::Write;
+
+use iced::mouse::Cursor;
+use iced::widget::canvas::{self, path::arc, Frame, Geometry, Path, Stroke};
+use iced::{Point, Radians, Rectangle, Renderer, Theme, Vector};
+
+use crate::NoteValue;
+
+// The space every glyph is fitted into, from the top of the longest stem to the bottom of a tie
+const TOP: f32 = -1.6;
+const BOTTOM: f32 = 5.2;
+const HEIGHT: f32 = BOTTOM - TOP;
+
+const HEAD_Y: f32 = 3.8;
+const STEM_X: f32 = 1.35;
+const LINE_WIDTH: f32 = 0.14;
+const FLAG_SPACING: f32 = 0.7;
+const BREVE_WIDTH: f32 = 2.6;
+const TIE_GAP: f32 = 0.6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+    // Stemmed heads tilt and are narrower than whole and breve heads
+    Head { x: f32, filled: bool, tilted: bool },
+    Line { from: (f32, f32), to: (f32, f32) },
+    Curve { points: [(f32, f32); 4] },
+}
+
+// A glyph's shapes and its width in staff spaces
+fn shapes(note_value: NoteValue) -> (Vec<Shape>, f32) {
+    let flags = match note_value {
+        NoteValue::Eighth => 1,
+        NoteValue::Sixteenth => 2,
+        NoteValue::ThirtySecond => 3,
+        NoteValue::SixtyFourth => 4,
+        NoteValue::HundredTwentyEighth => 5,
+        _ => 0,
+    };
+
+    match note_value {
+        // There's no single glyph this long, so it's written as two breves tied together
+        NoteValue::QuadrupleWhole => {
+            let mut shapes = breve(0.0);
+            shapes.extend(breve(BREVE_WIDTH + TIE_GAP));
+            let (start, end) = (BREVE_WIDTH / 2.0, BREVE_WIDTH * 1.5 + TIE_GAP);
+            shapes.push(Shape::Curve {
+                points: [
+                    (start, HEAD_Y + 0.7),
+                    (start + 0.6, HEAD_Y + 1.4),
+                    (end - 0.6, HEAD_Y + 1.4),
+                    (end, HEAD_Y + 0.7),
+                ],
+            });
+            (shapes, BREVE_WIDTH * 2.0 + TIE_GAP)
+        }
+        NoteValue::DoubleWhole => (breve(0.0), BREVE_WIDTH),
+        NoteValue::Whole => (
+            vec![Shape::Head {
+                x: 0.9,
+                filled: false,
+                tilted: false,
+            }],
+            1.8,
+        ),
+        _ => {
+            // Each flag needs room below the one before it
+            let stem_top = (HEAD_Y - 3.3).min(HEAD_Y - 1.6 - FLAG_SPACING * flags as f32);
+            let mut shapes = vec![
+                Shape::Head {
+                    x: 0.7,
+                    filled: note_value != NoteValue::Half,
+                    tilted: true,
+                },
+                Shape::Line {
+                    from: (STEM_X, HEAD_Y - 0.15),
+                    to: (STEM_X, stem_top),
+                },
+            ];
+            shapes.extend((0..flags).map(|flag| {
+                let y = stem_top + FLAG_SPACING * flag as f32;
+                Shape::Curve {
+                    points: [
+                        (STEM_X, y),
+                        (STEM_X + 0.4, y + 0.5),
+                        (STEM_X + 1.2, y + 0.9),
+                        (STEM_X + 0.9, y + 1.7),
+                    ],
+                }
+            }));
+            (shapes, 2.6)
+        }
+    }
+}
+
+// A whole-note head between two pairs of bars
+fn breve(x: f32) -> Vec<Shape> {
+    let bar = |x: f32| Shape::Line {
+        from: (x, HEAD_Y - 0.8),
+        to: (x, HEAD_Y + 0.8),
+    };
+
+    vec![
+        bar(x + 0.15),
+        bar(x + 0.4),
+        Shape::Head {
+            x: x + BREVE_WIDTH / 2.0,
+            filled: false,
+            tilted: false,
+        },
+        bar(x + BREVE_WIDTH - 0.4),
+        bar(x + BREVE_WIDTH - 0.15),
+    ]
+}
+
+fn head_radii(tilted: bool) -> (f32, f32) {
+    if tilted {
+        (0.62, 0.44)
+    } else {
+        (0.75, 0.48)
+    }
+}
+
+// The width a glyph needs when drawn `height` units tall
+pub fn width(note_value: NoteValue, height: f32) -> f32 {
+    shapes(note_value).1 * height / HEIGHT
+}
+
+// SVG elements for a glyph whose top-left corner is at `x`, `y`
+pub fn svg(note_value: NoteValue, x: f32, y: f32, height: f32, color: &str) -> String {
+    let scale = height / HEIGHT;
+    let mut svg = format!(
+        r#"<g transform="translate({x} {y}) scale({scale}) translate(0 {})" stroke="{color}" stroke-width="{LINE_WIDTH}" fill="none">"#,
+        -TOP
+    );
+
+    for shape in shapes(note_value).0 {
+        let _ = match shape {
+            Shape::Head { x, filled, tilted } => {
+                let (rx, ry) = head_radii(tilted);
+                let angle = if tilted { -20 } else { 0 };
+                let fill = if filled { color } else { "none" };
+                write!(
+                    svg,
+                    r#"<ellipse cx="{x}" cy="{HEAD_Y}" rx="{rx}" ry="{ry}" transform="rotate({angle} {x} {HEAD_Y})" fill="{fill}"/>"#
+                )
+            }
+            Shape::Line { from, to } => write!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                from.0, from.1, to.0, to.1
+            ),
+            Shape::Curve {
+                points: [a, b, c, d],
+            } => write!(
+                svg,
+                r#"<path d="M{} {} C{} {} {} {} {} {}"/>"#,
+                a.0, a.1, b.0, b.1, c.0, c.1, d.0, d.1
+            ),
+        };
+    }
+
+    svg.push_str("</g>");
+    svg
+}
+
+// Draws in the theme's text color, left-aligned and filling the widget's height
+pub struct NoteGlyph {
+    pub note_value: NoteValue,
+}
+
+impl<Message> canvas::Program<Message> for NoteGlyph {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let color = theme.extended_palette().background.base.text;
+        let scale = bounds.height / HEIGHT;
+        let point = |(x, y): (f32, f32)| Point::new(x * scale, (y - TOP) * scale);
+        let stroke = Stroke::default()
+            .with_color(color)
+            .with_width((LINE_WIDTH * scale).max(1.0));
+
+        for shape in shapes(self.note_value).0 {
+            match shape {
+                Shape::Head { x, filled, tilted } => {
+                    let (rx, ry) = head_radii(tilted);
+                    let head = Path::new(|builder| {
+                        builder.ellipse(arc::Elliptical {
+                            center: point((x, HEAD_Y)),
+                            radii: Vector::new(rx * scale, ry * scale),
+                            rotation: Radians(if tilted { -20f32.to_radians() } else { 0.0 }),
+                            start_angle: Radians(0.0),
+                            end_angle: Radians(std::f32::consts::TAU),
+                        });
+                    });
+                    if filled {
+                        frame.fill(&head, color);
+                    } else {
+                        frame.stroke(&head, stroke);
+                    }
+                }
+                Shape::Line { from, to } => {
+                    frame.stroke(&Path::line(point(from), point(to)), stroke);
+                }
+                Shape::Curve {
+                    points: [a, b, c, d],
+                } => {
+                    let curve = Path::new(|builder| {
+                        builder.move_to(point(a));
+                        builder.bezier_curve_to(point(b), point(c), point(d));
+                    });
+                    frame.stroke(&curve, stroke);
+                }
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NOTE_VALUES;
+
+    #[test]
+    fn glyphs_fit_their_box() {
+        for note_value in NOTE_VALUES {
+            let (shapes, width) = shapes(note_value);
+            let points = shapes.iter().flat_map(|shape| match shape {
+                Shape::Head { x, tilted, .. } => {
+                    let (rx, ry) = head_radii(*tilted);
+                    vec![(x - rx, HEAD_Y - ry), (x + rx, HEAD_Y + ry)]
+                }
+                Shape::Line { from, to } => vec![*from, *to],
+                Shape::Curve { points } => points.to_vec(),
+            });
+            for (x, y) in points {
+                assert!((0.0..=width).contains(&x), "{} at x {}", note_value, x);
+                assert!((TOP..=BOTTOM).contains(&y), "{} at y {}", note_value, y);
+            }
+        }
+    }
+
+    #[test]
+    fn quadruple_whole_is_two_tied_breves() {
+        let (shapes, _) = shapes(NoteValue::QuadrupleWhole);
+        let heads = shapes
+            .iter()
+            .filter(|shape| matches!(shape, Shape::Head { .. }))
+            .count();
+        let ties = shapes
+            .iter()
+            .filter(|shape| matches!(shape, Shape::Curve { .. }))
+            .count();
+        assert_eq!((heads, ties), (2, 1));
+    }
+}
@@ -0,0 +1,274 @@
+// Line-delimited JSON over stdin and stdout for scripts and Stream Deck plugins, enabled with
+// `--pipe`. Each line in is one command, answered by one line out:
+//
+// - `{"command": "tempo"}` returns the current tempo
+// - `{"command": "set_tempo", "tempo": 128}` sets it
+// - `{"command": "tap"}` taps, returning the tempo so far
+// - `{"command": "table"}` returns the table in the shape export plugins get. `tempo`, `unit`, and
+//   `ppqn` work as they do in the HTTP API, and default to the app's own.
+//
+// Anything that goes wrong comes back as `{"error": "..."}`. Add `--no-window` to run without the
+// GUI, for as long as stdin stays open. The web build has no stdin, so none of this is used there.
+#![cfg_attr(target_arch = "wasm32", allow(dead_code))]
+
+#[cfg(feature = "gui")]
+use iced::Subscription;
+use serde::Deserialize;
+
+use crate::settings::Settings;
+use crate::tap::TapTempo;
+use crate::{http_api, plugins, Unit};
+
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum Command {
+    Tempo,
+    SetTempo {
+        tempo: f64,
+    },
+    Tap,
+    Table {
+        tempo: Option<f64>,
+        unit: Option<String>,
+        ppqn: Option<u16>,
+    },
+}
+
+// What commands act on: the GUI, or the headless session
+pub trait Target {
+    fn tempo(&self) -> Option<f64>;
+    fn unit(&self) -> Unit;
+    fn set_tempo(&mut self, tempo: f64);
+    fn tap(&mut self);
+}
+
+pub fn is_enabled(mut args: impl Iterator<Item = String>) -> bool {
+    args.any(|arg| arg == "--pipe")
+}
+
+pub fn is_headless(args: impl Iterator<Item = String>) -> bool {
+    let args: Vec<_> = args.collect();
+    is_enabled(args.iter().cloned()) && args.iter().any(|arg| arg == "--no-window")
+}
+
+pub fn respond(line: &str, target: &mut impl Target) -> String {
+    let command = match serde_json::from_str::<Command>(line) {
+        Ok(command) => command,
+        Err(parse_error) => return error(&format!("Invalid command: {}", parse_error)),
+    };
+
+    match command {
+        Command::Tempo => serde_json::json!({ "tempo": target.tempo() }).to_string(),
+        Command::SetTempo { tempo } if tempo.is_finite() && tempo > 0.0 => {
+            target.set_tempo(tempo);
+            serde_json::json!({ "tempo": target.tempo() }).to_string()
+        }
+        Command::SetTempo { .. } => error("Expected a positive tempo"),
+        Command::Tap => {
+            target.tap();
+            serde_json::json!({ "tempo": target.tempo() }).to_string()
+        }
+        Command::Table { tempo, unit, ppqn } => {
+            let Some(tempo) = tempo.or(target.tempo()) else {
+                return error("No tempo to make a table for");
+            };
+            if !tempo.is_finite() || tempo <= 0.0 {
+                return error("Expected a positive tempo");
+            }
+
+            let unit = match (unit, ppqn) {
+                (None, None) => Ok(target.unit()),
+                (unit, ppqn) => http_api::parse_unit(unit.as_deref(), ppqn),
+            };
+
+            match unit {
+                Ok(unit) => serde_json::to_string(&plugins::ExportTable::new(tempo, &unit))
+                    .unwrap_or_default(),
+                Err(message) => error(message),
+            }
+        }
+    }
+}
+
+fn error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+// A closed stdout just means nobody's listening, which isn't worth stopping over
+pub fn write_line(line: &str) {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(stdout, "{}", line);
+    let _ = stdout.flush();
+}
+
+// Lines from stdin, for the GUI to answer in `update`
+#[cfg(all(feature = "gui", not(target_arch = "wasm32")))]
+pub fn listen() -> Subscription<String> {
+    use iced::futures::SinkExt;
+    use std::io::BufRead;
+
+    Subscription::run_with_id(
+        "pipe",
+        iced::stream::channel(100, |output| async move {
+            std::thread::spawn(move || {
+                let mut output = output;
+                for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+                    // Blocks rather than dropping commands when a script sends them faster than
+                    // the UI handles them
+                    if iced::futures::executor::block_on(output.send(line)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+#[cfg(all(feature = "gui", target_arch = "wasm32"))]
+pub fn listen() -> Subscription<String> {
+    Subscription::none()
+}
+
+// The model the headless modes share: what `--no-window` answers commands with, and what `--tui`
+// draws
+pub struct Session {
+    tempo: Option<f64>,
+    unit: Unit,
+    tap_tempo: TapTempo,
+    tempo_range: (f64, f64),
+}
+
+impl Target for Session {
+    fn tempo(&self) -> Option<f64> {
+        self.tempo
+    }
+
+    fn unit(&self) -> Unit {
+        self.unit.clone()
+    }
+
+    fn set_tempo(&mut self, tempo: f64) {
+        self.tempo = Some(tempo.clamp(self.tempo_range.0, self.tempo_range.1));
+    }
+
+    fn tap(&mut self) {
+        if self.tap_tempo.is_expired() {
+            self.tap_tempo.reset();
+        }
+        self.tempo = self.tap_tempo.tap().or(self.tempo);
+    }
+}
+
+impl Session {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            tempo: Some(settings.startup_tempo()),
+            unit: settings.startup_unit(),
+            tap_tempo: TapTempo::new(
+                settings.tap_window,
+                settings.tap_timeout,
+                settings.tap_tolerance,
+                settings.tap_averaging,
+                settings.tap_latency_offset_ms,
+            ),
+            tempo_range: settings.tempo_range(),
+        }
+    }
+
+    pub fn set_unit(&mut self, unit: Unit) {
+        self.unit = unit;
+    }
+
+    pub fn reset_taps(&mut self) {
+        self.tap_tempo.reset();
+    }
+}
+
+pub fn run_headless(settings: Settings) {
+    use std::io::BufRead;
+
+    let mut session = Session::new(&settings);
+
+    for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+        if !line.trim().is_empty() {
+            write_line(&respond(&line, &mut session));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        let settings = Settings::default();
+        Session {
+            tempo: Some(120.0),
+            unit: Unit::Milliseconds,
+            tap_tempo: TapTempo::new(
+                settings.tap_window,
+                settings.tap_timeout,
+                settings.tap_tolerance,
+                settings.tap_averaging,
+                settings.tap_latency_offset_ms,
+            ),
+            tempo_range: settings.tempo_range(),
+        }
+    }
+
+    #[test]
+    fn tempo_commands() {
+        let mut session = session();
+
+        assert_eq!(
+            respond(r#"{"command": "tempo"}"#, &mut session),
+            r#"{"tempo":120.0}"#
+        );
+        assert_eq!(
+            respond(r#"{"command": "set_tempo", "tempo": 90.5}"#, &mut session),
+            r#"{"tempo":90.5}"#
+        );
+        assert!(
+            respond(r#"{"command": "set_tempo", "tempo": -1}"#, &mut session).contains("error")
+        );
+
+        // A single tap can't make a tempo yet, so the last one stays
+        assert_eq!(
+            respond(r#"{"command": "tap"}"#, &mut session),
+            r#"{"tempo":90.5}"#
+        );
+    }
+
+    #[test]
+    fn table_commands() {
+        let mut session = session();
+
+        let table = respond(r#"{"command": "table"}"#, &mut session);
+        assert!(table.contains(r#""unit":"ms""#));
+
+        let table = respond(
+            r#"{"command": "table", "tempo": 60, "unit": "ticks", "ppqn": 480}"#,
+            &mut session,
+        );
+        assert!(table.contains(r#""unit":"ticks""#));
+
+        assert!(respond(r#"{"command": "table", "unit": "bpm"}"#, &mut session).contains("error"));
+        assert!(respond("not json", &mut session).contains("error"));
+    }
+
+    #[test]
+    fn reads_the_flags() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert!(is_enabled(args(&["app", "--pipe"]).into_iter()));
+        assert!(!is_headless(args(&["app", "--pipe"]).into_iter()));
+        assert!(is_headless(
+            args(&["app", "--no-window", "--pipe"]).into_iter()
+        ));
+        assert!(!is_headless(args(&["app", "--no-window"]).into_iter()));
+    }
+}
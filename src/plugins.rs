@@ -0,0 +1,322 @@
+// Export plugins are WebAssembly modules dropped into the plugins directory. They run in an
+// interpreter with nothing linked in, so a plugin can't reach files, the network, or anything else
+// beyond the table it's handed, and fuel and memory limits stop a runaway one. A plugin exports:
+//
+// - `memory`
+// - `alloc(len: i32) -> i32`, a buffer for the app to write the plugin's input into
+// - `describe() -> i64`, pointing at `{"name": "...", "extension": "..."}` as JSON
+// - `export(ptr: i32, len: i32) -> i64`, given an `ExportTable` as JSON, pointing at the file bytes
+//
+// Pointers come back packed with the address in the high 32 bits and the length in the low 32.
+// See `examples/tsv_export_plugin.rs` for a complete plugin.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{delay_time, delay_times, settings, Unit, NOTE_VALUES, RHYTHMIC_MODIFIER};
+
+// Enough for a few seconds of interpretation
+#[cfg(not(target_arch = "wasm32"))]
+const FUEL: u64 = 2_000_000_000;
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+#[cfg(not(target_arch = "wasm32"))]
+const MAX_OUTPUT_BYTES: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportPlugin {
+    pub name: String,
+    pub extension: String,
+    path: PathBuf,
+}
+
+impl Display for ExportPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (.{})", self.name, self.extension)
+    }
+}
+
+#[derive(Deserialize)]
+struct Description {
+    name: String,
+    extension: String,
+}
+
+#[derive(Serialize)]
+pub struct ExportTable {
+    tempo: f64,
+    unit: String,
+    modifiers: Vec<String>,
+    rows: Vec<ExportRow>,
+}
+
+#[derive(Serialize)]
+struct ExportRow {
+    note: String,
+    values: Vec<f64>,
+}
+
+impl ExportTable {
+    pub fn new(tempo: f64, unit: &Unit) -> Self {
+        let delay_times =
+            RHYTHMIC_MODIFIER.map(|rhythmic_modifier| delay_times(tempo, &rhythmic_modifier, unit));
+
+        Self {
+            tempo,
+            unit: unit.to_string(),
+            modifiers: RHYTHMIC_MODIFIER
+                .iter()
+                .map(|rhythmic_modifier| rhythmic_modifier.to_string())
+                .collect(),
+            rows: NOTE_VALUES
+                .iter()
+                .map(|note_value| ExportRow {
+                    note: note_value.to_string(),
+                    values: delay_times
+                        .iter()
+                        .map(|delay_times| delay_time(delay_times, note_value))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn plugins_directory() -> Option<PathBuf> {
+    settings::config_directory().map(|directory| directory.join("plugins"))
+}
+
+// Plugins that fail to describe themselves are skipped rather than reported
+pub fn discover() -> Vec<ExportPlugin> {
+    let Some(entries) = plugins_directory().and_then(|directory| directory.read_dir().ok()) else {
+        return Vec::new();
+    };
+
+    let mut plugins: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension == "wasm")
+        })
+        .filter_map(|path| describe(&path))
+        .collect();
+
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
+
+pub fn export(plugin: &ExportPlugin, table: &ExportTable) -> Result<Vec<u8>, String> {
+    let input = serde_json::to_vec(table).map_err(|error| error.to_string())?;
+    Sandbox::load(&plugin.path)?.export(&input)
+}
+
+fn describe(path: &Path) -> Option<ExportPlugin> {
+    let output = Sandbox::load(path)
+        .and_then(|mut sandbox| sandbox.describe())
+        .ok()?;
+    let description: Description = serde_json::from_slice(&output).ok()?;
+
+    Some(ExportPlugin {
+        name: description.name,
+        extension: description.extension,
+        path: path.to_path_buf(),
+    })
+}
+
+// A fresh instance per call, so nothing a plugin does carries over to the next
+#[cfg(not(target_arch = "wasm32"))]
+struct Sandbox {
+    store: wasmi::Store<wasmi::StoreLimits>,
+    instance: wasmi::Instance,
+    memory: wasmi::Memory,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Sandbox {
+    fn load(path: &Path) -> Result<Self, String> {
+        let wasm = std::fs::read(path).map_err(|error| error.to_string())?;
+        Self::new(&wasm, FUEL)
+    }
+
+    fn new(wasm: &[u8], fuel: u64) -> Result<Self, String> {
+        let mut config = wasmi::Config::default();
+        config.consume_fuel(true);
+        let engine = wasmi::Engine::new(&config);
+        let module = wasmi::Module::new(&engine, wasm).map_err(|error| error.to_string())?;
+
+        let limits = wasmi::StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY_BYTES)
+            .build();
+        let mut store = wasmi::Store::new(&engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(fuel).map_err(|error| error.to_string())?;
+
+        // The linker is left empty, so a module that imports anything fails to instantiate
+        let instance = wasmi::Linker::new(&engine)
+            .instantiate_and_start(&mut store, &module)
+            .map_err(plugin_error)?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or("Plugin exports no memory")?;
+
+        Ok(Self {
+            store,
+            instance,
+            memory,
+        })
+    }
+
+    fn describe(&mut self) -> Result<Vec<u8>, String> {
+        let describe = self
+            .instance
+            .get_typed_func::<(), i64>(&self.store, "describe")
+            .map_err(|error| error.to_string())?;
+        let output = describe.call(&mut self.store, ()).map_err(plugin_error)?;
+        self.read(output)
+    }
+
+    fn export(&mut self, input: &[u8]) -> Result<Vec<u8>, String> {
+        let alloc = self
+            .instance
+            .get_typed_func::<i32, i32>(&self.store, "alloc")
+            .map_err(|error| error.to_string())?;
+        let export = self
+            .instance
+            .get_typed_func::<(i32, i32), i64>(&self.store, "export")
+            .map_err(|error| error.to_string())?;
+
+        let length = i32::try_from(input.len()).map_err(|error| error.to_string())?;
+        let pointer = alloc.call(&mut self.store, length).map_err(plugin_error)?;
+        self.memory
+            .write(&mut self.store, pointer as u32 as usize, input)
+            .map_err(|error| error.to_string())?;
+
+        let output = export
+            .call(&mut self.store, (pointer, length))
+            .map_err(plugin_error)?;
+        self.read(output)
+    }
+
+    fn read(&self, packed: i64) -> Result<Vec<u8>, String> {
+        let packed = packed as u64;
+        let (pointer, length) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        if length > MAX_OUTPUT_BYTES {
+            return Err(format!(
+                "Plugin output is over {} MB",
+                MAX_OUTPUT_BYTES / 1024 / 1024
+            ));
+        }
+
+        let mut output = vec![0; length];
+        self.memory
+            .read(&self.store, pointer, &mut output)
+            .map_err(|error| error.to_string())?;
+        Ok(output)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn plugin_error(error: wasmi::Error) -> String {
+    match error.as_trap_code() {
+        Some(wasmi::TrapCode::OutOfFuel) => "Plugin took too long and was stopped".to_string(),
+        _ => format!("Plugin failed: {}", error),
+    }
+}
+
+// The browser has no plugins directory to load from, so there's never a sandbox to run
+#[cfg(target_arch = "wasm32")]
+enum Sandbox {}
+
+#[cfg(target_arch = "wasm32")]
+impl Sandbox {
+    fn load(_path: &Path) -> Result<Self, String> {
+        Err("Plugins aren't available in the browser".to_string())
+    }
+
+    fn describe(&mut self) -> Result<Vec<u8>, String> {
+        match *self {}
+    }
+
+    fn export(&mut self, _input: &[u8]) -> Result<Vec<u8>, String> {
+        match *self {}
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    // Describes itself and echoes its input back as the export
+    const ECHO_PLUGIN: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (data (i32.const 0) "{\"name\": \"Echo\", \"extension\": \"txt\"}")
+            (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+            (func (export "describe") (result i64) (i64.const 36))
+            (func (export "export") (param i32 i32) (result i64)
+                (i64.or
+                    (i64.shl (i64.extend_i32_u (local.get 0)) (i64.const 32))
+                    (i64.extend_i32_u (local.get 1))
+                )
+            )
+        )
+    "#;
+
+    #[test]
+    fn runs_plugins_in_a_sandbox() {
+        let description = Sandbox::new(ECHO_PLUGIN.as_bytes(), FUEL)
+            .unwrap()
+            .describe()
+            .unwrap();
+        let description: Description = serde_json::from_slice(&description).unwrap();
+        assert_eq!(description.name, "Echo");
+        assert_eq!(description.extension, "txt");
+
+        let output = Sandbox::new(ECHO_PLUGIN.as_bytes(), FUEL)
+            .unwrap()
+            .export(b"table")
+            .unwrap();
+        assert_eq!(output, b"table");
+    }
+
+    #[test]
+    fn refuses_what_a_plugin_shouldnt_do() {
+        // Nothing is provided to import
+        let importer = r#"
+            (module
+                (import "env" "open" (func))
+                (memory (export "memory") 1)
+            )
+        "#;
+        assert!(Sandbox::new(importer.as_bytes(), FUEL).is_err());
+
+        let endless = r#"
+            (module
+                (memory (export "memory") 1)
+                (func (export "describe") (result i64) (loop (br 0)) (i64.const 0))
+            )
+        "#;
+        assert_eq!(
+            Sandbox::new(endless.as_bytes(), 1_000_000)
+                .unwrap()
+                .describe(),
+            Err("Plugin took too long and was stopped".to_string())
+        );
+
+        let out_of_bounds = r#"
+            (module
+                (memory (export "memory") 1)
+                (func (export "describe") (result i64) (i64.const 0x0001000000000010))
+            )
+        "#;
+        assert!(Sandbox::new(out_of_bounds.as_bytes(), FUEL)
+            .unwrap()
+            .describe()
+            .is_err());
+    }
+}
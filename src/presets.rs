@@ -0,0 +1,264 @@
+// Preset templates turn the selected delay time into a file a plugin can load. A template is any
+// text file in the templates directory, named after what it's for and with the extension the
+// preset should have, e.g. `ValhallaDelay.vpreset`. The easiest way to make one is to save a
+// preset from the plugin and replace its delay time with a placeholder:
+//
+// - `{{delay_ms}}`, `{{delay_seconds}}`, and `{{delay_hz}}` for the time itself
+// - `{{tempo}}`, `{{note}}`, and `{{modifier}}` for where it came from, e.g. in the preset name
+//
+// ValhallaDelay, EchoBoy, and ReaDelay templates are built in alongside generic XML and JSON. They
+// only set the delay time, in milliseconds with tempo sync off, and leave everything else at the
+// plugin's defaults.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use round::round;
+use serde::{Deserialize, Serialize};
+
+use crate::{settings, NoteValue, RhythmicModifier, ROUND_LIMIT};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresetTemplate {
+    pub name: String,
+    pub extension: String,
+    body: String,
+}
+
+impl Display for PresetTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (.{})", self.name, self.extension)
+    }
+}
+
+pub struct PresetValues {
+    pub tempo: f64,
+    pub note_value: NoteValue,
+    pub rhythmic_modifier: RhythmicModifier,
+    pub delay_ms: f64,
+}
+
+impl PresetTemplate {
+    pub fn render(&self, values: &PresetValues) -> String {
+        let modifier = match values.rhythmic_modifier {
+            RhythmicModifier::Normal => String::new(),
+            rhythmic_modifier => rhythmic_modifier.to_string(),
+        };
+
+        [
+            ("delay_ms", round(values.delay_ms, ROUND_LIMIT).to_string()),
+            (
+                "delay_seconds",
+                round(values.delay_ms / 1000.0, ROUND_LIMIT + 3).to_string(),
+            ),
+            (
+                "delay_hz",
+                round(1000.0 / values.delay_ms, ROUND_LIMIT).to_string(),
+            ),
+            ("tempo", round(values.tempo, ROUND_LIMIT).to_string()),
+            ("note", values.note_value.to_string()),
+            ("modifier", modifier),
+        ]
+        .iter()
+        .fold(self.body.clone(), |body, (name, value)| {
+            body.replace(&format!("{{{{{}}}}}", name), value)
+        })
+    }
+}
+
+pub fn templates_directory() -> Option<PathBuf> {
+    settings::config_directory().map(|directory| directory.join("preset_templates"))
+}
+
+// Built-in templates first, then the user's
+pub fn discover() -> Vec<PresetTemplate> {
+    builtin().into_iter().chain(user_templates()).collect()
+}
+
+// In name order. Files that aren't text are skipped.
+pub fn user_templates() -> Vec<PresetTemplate> {
+    let mut user_templates: Vec<_> = templates_directory()
+        .and_then(|directory| directory.read_dir().ok())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            Some(PresetTemplate {
+                name: path.file_stem()?.to_string_lossy().into_owned(),
+                extension: path.extension()?.to_string_lossy().into_owned(),
+                body: std::fs::read_to_string(&path).ok()?,
+            })
+        })
+        .collect();
+
+    user_templates.sort_by(|a, b| a.name.cmp(&b.name));
+    user_templates
+}
+
+// Saves a template into the templates directory, e.g. one imported from another machine. Names
+// that would land outside the directory are refused.
+pub fn install(template: &PresetTemplate) -> std::io::Result<()> {
+    let file_name = format!("{}.{}", template.name, template.extension);
+    if Path::new(&file_name).file_name() != Some(file_name.as_ref()) {
+        return Err(std::io::Error::other(format!(
+            "{} isn't a valid file name",
+            file_name
+        )));
+    }
+
+    let Some(directory) = templates_directory() else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(&directory)?;
+    std::fs::write(directory.join(file_name), &template.body)
+}
+
+fn builtin() -> Vec<PresetTemplate> {
+    vec![
+        PresetTemplate {
+            name: "ValhallaDelay".to_string(),
+            extension: "vpreset".to_string(),
+            body: concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<ValhallaDelay pluginVersion=\"1.0.0\" ",
+                "presetName=\"{{tempo}} BPM {{note}} {{modifier}}\" ",
+                "DelayL_Sync=\"Milliseconds\" DelayL_Ms=\"{{delay_ms}}\" ",
+                "DelayR_Sync=\"Milliseconds\" DelayR_Ms=\"{{delay_ms}}\" ",
+                "DelayLinkLR=\"Linked\"/>\n",
+            )
+            .to_string(),
+        },
+        PresetTemplate {
+            name: "EchoBoy".to_string(),
+            extension: "xml".to_string(),
+            body: concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<SoundtoysPreset plugin=\"EchoBoy\" name=\"{{tempo}} BPM {{note}} {{modifier}}\">\n",
+                "  <Parameter id=\"TimeMode\" value=\"Time\"/>\n",
+                "  <Parameter id=\"EchoTime\" value=\"{{delay_ms}}\"/>\n",
+                "</SoundtoysPreset>\n",
+            )
+            .to_string(),
+        },
+        // REAPER preset libraries hold one chunk per preset, bracketed like its project files
+        PresetTemplate {
+            name: "ReaDelay".to_string(),
+            extension: "rpl".to_string(),
+            body: concat!(
+                "<REAPER_PRESET_LIBRARY `VST: ReaDelay (Cockos)`\n",
+                "  <PRESET `{{tempo}} BPM {{note}} {{modifier}}`\n",
+                "    LENGTH_MS {{delay_ms}}\n",
+                "    LENGTH_MUSICAL 0\n",
+                "  >\n",
+                ">\n",
+            )
+            .to_string(),
+        },
+        PresetTemplate {
+            name: "Generic XML".to_string(),
+            extension: "xml".to_string(),
+            body: concat!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+                "<DelayPreset tempo=\"{{tempo}}\" note=\"{{note}}\" modifier=\"{{modifier}}\" ",
+                "delayMs=\"{{delay_ms}}\" delaySeconds=\"{{delay_seconds}}\" />\n",
+            )
+            .to_string(),
+        },
+        PresetTemplate {
+            name: "Generic JSON".to_string(),
+            extension: "json".to_string(),
+            body: concat!(
+                "{\n",
+                "  \"tempo\": {{tempo}},\n",
+                "  \"note\": \"{{note}}\",\n",
+                "  \"modifier\": \"{{modifier}}\",\n",
+                "  \"delay_ms\": {{delay_ms}}\n",
+                "}\n",
+            )
+            .to_string(),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_placeholders() {
+        let template = PresetTemplate {
+            name: "Test".to_string(),
+            extension: "txt".to_string(),
+            body: "{{modifier}} {{note}} at {{tempo}}: {{delay_ms}} ms, {{delay_seconds}} s, \
+                   {{delay_hz}} Hz, {{unknown}}"
+                .to_string(),
+        };
+
+        let values = PresetValues {
+            tempo: 120.0,
+            note_value: NoteValue::Eighth,
+            rhythmic_modifier: RhythmicModifier::Dotted,
+            delay_ms: 375.0,
+        };
+
+        assert_eq!(
+            template.render(&values),
+            "Dotted 1/8 at 120: 375 ms, 0.375 s, 2.667 Hz, {{unknown}}"
+        );
+    }
+
+    #[test]
+    fn refuses_paths_as_names() {
+        let template = PresetTemplate {
+            name: "../../escape".to_string(),
+            extension: "txt".to_string(),
+            body: String::new(),
+        };
+        assert!(install(&template).is_err());
+    }
+
+    #[test]
+    fn builtin_json_is_valid() {
+        let values = PresetValues {
+            tempo: 93.5,
+            note_value: NoteValue::Quarter,
+            rhythmic_modifier: RhythmicModifier::Normal,
+            delay_ms: 60_000.0 / 93.5,
+        };
+
+        let json = builtin()
+            .into_iter()
+            .find(|template| template.name == "Generic JSON")
+            .unwrap()
+            .render(&values);
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["tempo"], 93.5);
+    }
+
+    #[test]
+    fn vendor_templates_take_the_delay_time() {
+        let values = PresetValues {
+            tempo: 120.0,
+            note_value: NoteValue::Eighth,
+            rhythmic_modifier: RhythmicModifier::Dotted,
+            delay_ms: 375.0,
+        };
+
+        for name in ["ValhallaDelay", "EchoBoy", "ReaDelay"] {
+            let template = builtin()
+                .into_iter()
+                .find(|template| template.name == name)
+                .unwrap();
+            let preset = template.render(&values);
+            assert!(
+                preset.contains("\"375\"") || preset.contains(" 375\n"),
+                "{}",
+                preset
+            );
+            assert!(preset.contains("120 BPM 1/8 Dotted"), "{}", preset);
+            assert!(!preset.contains("{{"), "{}", preset);
+        }
+    }
+}
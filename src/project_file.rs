@@ -0,0 +1,179 @@
+// Reads the master tempo out of DAW project files, so checking a project's BPM doesn't mean opening
+// the DAW. Only the starting tempo is read; tempo automation is ignored.
+
+use std::io::Read;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use crate::midi_file::TempoEvent;
+
+const EXTENSIONS: [&str; 3] = ["als", "rpp", "flp"];
+
+// FL Studio event IDs. Events below 64 carry a byte, below 128 a word, below 192 a double word, and
+// the rest a variable-length payload.
+const FLP_TEMPO: u8 = 156;
+const FLP_LEGACY_TEMPO: u8 = 66;
+
+pub fn is_project_file(path: &Path) -> bool {
+    extension(path).is_some_and(|extension| EXTENSIONS.contains(&extension.as_str()))
+}
+
+pub fn tempo_events(path: &Path) -> Result<Vec<TempoEvent>, String> {
+    let bytes = std::fs::read(path).map_err(|error| error.to_string())?;
+
+    let tempo = match extension(path).as_deref() {
+        Some("als") => {
+            let mut xml = String::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_string(&mut xml)
+                .map_err(|error| error.to_string())?;
+            ableton_tempo(&xml)
+        }
+        Some("rpp") => reaper_tempo(&String::from_utf8_lossy(&bytes)),
+        Some("flp") => fl_studio_tempo(&bytes)?,
+        _ => return Err("Not a supported project file".to_string()),
+    };
+
+    Ok(tempo
+        .map(|tempo| TempoEvent { beat: None, tempo })
+        .into_iter()
+        .collect())
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+// The master (Live 11 and earlier) or main (Live 12) track holds `<Tempo>`, whose `<Manual>` value
+// is the tempo with automation off. It's the first `<Tempo>` in the document.
+fn ableton_tempo(xml: &str) -> Option<f64> {
+    let tempo = &xml[xml.find("<Tempo>")?..];
+    let manual = &tempo[tempo.find("<Manual ")?..];
+    let value = &manual[manual.find("Value=\"")? + "Value=\"".len()..];
+    value[..value.find('"')?].parse().ok()
+}
+
+// A top-level line like `TEMPO 120 4 4`, i.e. BPM then time signature
+fn reaper_tempo(text: &str) -> Option<f64> {
+    text.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("TEMPO") => words.next()?.parse().ok(),
+            _ => None,
+        }
+    })
+}
+
+// An `FLhd` header chunk followed by an `FLdt` chunk of events
+fn fl_studio_tempo(bytes: &[u8]) -> Result<Option<f64>, String> {
+    let invalid = || "Not an FL Studio project".to_string();
+
+    if bytes.get(..4) != Some(b"FLhd") {
+        return Err(invalid());
+    }
+
+    let header_length = u32_at(bytes, 4).ok_or_else(invalid)? as usize;
+    let data_start = 8 + header_length;
+
+    if bytes.get(data_start..data_start + 4) != Some(b"FLdt") {
+        return Err(invalid());
+    }
+
+    let events = bytes.get(data_start + 8..).ok_or_else(invalid)?;
+    let mut legacy_tempo = None;
+    let mut position = 0;
+
+    while let Some(&id) = events.get(position) {
+        position += 1;
+
+        match id {
+            0..=63 => position += 1,
+            64..=127 => {
+                let value = u16::from_le_bytes(
+                    events
+                        .get(position..position + 2)
+                        .ok_or_else(invalid)?
+                        .try_into()
+                        .map_err(|_| invalid())?,
+                );
+                if id == FLP_LEGACY_TEMPO {
+                    legacy_tempo = Some(f64::from(value));
+                }
+                position += 2;
+            }
+            128..=191 => {
+                let value = u32_at(events, position).ok_or_else(invalid)?;
+                // Stored in thousandths of a BPM since FL Studio 11
+                if id == FLP_TEMPO {
+                    return Ok(Some(f64::from(value) / 1000.0));
+                }
+                position += 4;
+            }
+            192..=255 => {
+                // A little-endian base-128 length prefix
+                let mut length = 0;
+                let mut shift = 0;
+                loop {
+                    let byte = *events.get(position).ok_or_else(invalid)?;
+                    position += 1;
+                    length |= usize::from(byte & 0x7F) << shift;
+                    shift += 7;
+                    if byte & 0x80 == 0 || shift > 28 {
+                        break;
+                    }
+                }
+                position += length;
+            }
+        }
+    }
+
+    Ok(legacy_tempo)
+}
+
+fn u32_at(bytes: &[u8], position: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(position..position + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_ableton_tempo() {
+        let xml = r#"<Ableton><LiveSet><MainTrack><DeviceChain><Mixer>
+            <Tempo><LomId Value="0" /><Manual Value="128.5" /></Tempo>
+            </Mixer></DeviceChain></MainTrack></LiveSet></Ableton>"#;
+        assert_eq!(ableton_tempo(xml), Some(128.5));
+        assert_eq!(ableton_tempo("<Ableton />"), None);
+    }
+
+    #[test]
+    fn reads_reaper_tempo() {
+        let project = "<REAPER_PROJECT 0.1 \"7.0\"\n  RIPPLE 0\n  TEMPO 92.5 4 4\n>";
+        assert_eq!(reaper_tempo(project), Some(92.5));
+        assert_eq!(reaper_tempo("<REAPER_PROJECT\n>"), None);
+    }
+
+    #[test]
+    fn reads_fl_studio_tempo() {
+        let mut bytes = b"FLhd".to_vec();
+        bytes.extend(6u32.to_le_bytes());
+        bytes.extend([0, 0, 1, 0, 96, 0]);
+        bytes.extend(b"FLdt");
+
+        let mut events = vec![
+            // A byte event, a text event, then the tempo
+            11, 1, 194, 3, b'a', b'b', b'c', FLP_TEMPO,
+        ];
+        events.extend(140_000u32.to_le_bytes());
+        bytes.extend((events.len() as u32).to_le_bytes());
+        bytes.extend(events);
+
+        assert_eq!(fl_studio_tempo(&bytes), Ok(Some(140.0)));
+        assert!(fl_studio_tempo(b"RIFF").is_err());
+    }
+}
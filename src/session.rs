@@ -0,0 +1,90 @@
+// A snapshot of one setup, e.g. a show or a mix, to reopen later as it was. Settings are the
+// defaults every launch starts from; a session holds what's particular to one project.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::color_tag::ColorTag;
+use crate::time_signature::TimeSignature;
+use crate::{NoteValue, RhythmicModifier, Unit};
+
+pub const EXTENSION: &str = "dtsession";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub tempo: Option<f64>,
+    pub unit: Unit,
+    pub time_signature: TimeSignature,
+    // As typed, e.g. "5:4"
+    pub tuplet: String,
+    pub color_tag: ColorTag,
+    pub transpose_table: bool,
+    pub show_note_glyphs: bool,
+    pub show_quarter_ratios: bool,
+    pub show_comparison: bool,
+    pub millisecond_notes: Vec<NoteValue>,
+    pub selected_cell: (RhythmicModifier, NoteValue),
+    pub notes: String,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            tempo: None,
+            unit: Unit::Milliseconds,
+            time_signature: TimeSignature::default(),
+            tuplet: String::new(),
+            color_tag: ColorTag::None,
+            transpose_table: false,
+            show_note_glyphs: false,
+            show_quarter_ratios: false,
+            show_comparison: false,
+            millisecond_notes: Vec::new(),
+            selected_cell: (RhythmicModifier::Dotted, NoteValue::Eighth),
+            notes: String::new(),
+        }
+    }
+}
+
+pub fn is_session_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(EXTENSION))
+}
+
+pub fn save(path: &Path, session: &Session) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(session).map_err(|error| error.to_string())?;
+    std::fs::write(path, json).map_err(|error| error.to_string())
+}
+
+pub fn load(path: &Path) -> Result<Session, String> {
+    let json = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    serde_json::from_str(&json).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let session = Session {
+            tempo: Some(93.5),
+            unit: Unit::Ticks(480),
+            tuplet: "5:4".to_string(),
+            color_tag: ColorTag::Blue,
+            millisecond_notes: vec![NoteValue::SixtyFourth],
+            notes: "Verse vocal throw".to_string(),
+            ..Session::default()
+        };
+
+        let json = serde_json::to_string(&session).unwrap();
+        assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+
+        // Fields a session was saved without keep their defaults
+        let partial: Session = serde_json::from_str(r#"{"tempo": 140}"#).unwrap();
+        assert_eq!(partial.tempo, Some(140.0));
+        assert_eq!(partial.unit, Unit::Milliseconds);
+    }
+}
@@ -0,0 +1,366 @@
+#[cfg(feature = "gui")]
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "gui")]
+use iced::Theme;
+use serde::{Deserialize, Serialize};
+
+use crate::automation::ParameterRange;
+use crate::color_tag::ColorTag;
+use crate::custom_unit::CustomUnit;
+#[cfg(feature = "gui")]
+use crate::i18n::tr;
+use crate::i18n::Language;
+use crate::presets::PresetTemplate;
+use crate::tap::{TapAveraging, TapTimeout, TapTolerance, TapWindow};
+use crate::{
+    NoteNames, NoteValue, RhythmicModifier, Unit, DEFAULT_PPQN, DEFAULT_SAMPLE_RATE, DEFAULT_TEMPO,
+    ROUND_LIMIT,
+};
+
+// Persisted preferences, stored as JSON in the platform config directory. Missing or unreadable
+// files (and unknown fields) fall back to the defaults rather than failing startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: ThemeChoice,
+    pub language: Language,
+    pub min_tempo: f64,
+    pub max_tempo: f64,
+    pub tap_window: TapWindow,
+    pub transpose_table: bool,
+    pub window: Option<WindowGeometry>,
+    pub watch_clipboard: bool,
+    // Re-copy the selected cell whenever the tempo or unit changes
+    pub auto_copy: bool,
+    // "Copy value" and "Copy with unit" skip the rounding the table shows
+    pub exact_copies: bool,
+    pub decimal_separator: DecimalSeparator,
+    // The last cell clicked, which is also what presets are made from
+    pub selected_cell: (RhythmicModifier, NoteValue),
+    pub precision: i32,
+    pub note_names: NoteNames,
+    pub default_unit: Unit,
+    // Used whenever the table is in ticks
+    pub ppqn: u16,
+    // Likewise in samples
+    pub sample_rate: u32,
+    pub show_both_sample_rates: bool,
+    pub tap_timeout: TapTimeout,
+    pub tap_tolerance: TapTolerance,
+    pub tap_averaging: TapAveraging,
+    // Taken off every interval between taps
+    pub tap_latency_offset_ms: f64,
+    pub startup_tempo: f64,
+    // Note values the table keeps in milliseconds while it's in Hertz
+    pub millisecond_notes: Vec<NoteValue>,
+    // Shown in stage mode, in table order
+    pub stage_values: Vec<(NoteValue, RhythmicModifier)>,
+    // Each step gets a decrease and an increase button, smallest nearest the middle
+    pub nudge_steps: Vec<f64>,
+    // Tapped tempos outside it get a half- or double-time suggestion
+    pub preferred_tempo_range: (f64, f64),
+    // The plugin parameter normalized copies are made for
+    pub automation_range: ParameterRange,
+    pub custom_unit: CustomUnit,
+    // Columns without an entry keep the theme's color
+    pub modifier_colors: Vec<(RhythmicModifier, ColorTag)>,
+    pub color_by_range: bool,
+    // Asks GitHub for the latest release at launch. None until the first launch has asked whether
+    // to, so nothing goes out before the user agrees.
+    pub check_for_updates: Option<bool>,
+    // Multiplies fonts and spacing for very dense or very small screens
+    pub ui_scale: f64,
+    // Below 1 the window lets the DAW show through, handy while it floats on top
+    pub window_opacity: f32,
+}
+
+const DEFAULT_MIN_TEMPO: f64 = 1.0;
+const DEFAULT_MAX_TEMPO: f64 = 999.0;
+pub const MAX_PRECISION: i32 = 6;
+pub const UI_SCALE_RANGE: RangeInclusive<f64> = 0.75..=2.0;
+pub const WINDOW_OPACITY_RANGE: RangeInclusive<f32> = 0.3..=1.0;
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: ThemeChoice::default(),
+            language: Language::default(),
+            min_tempo: DEFAULT_MIN_TEMPO,
+            max_tempo: DEFAULT_MAX_TEMPO,
+            tap_window: TapWindow::default(),
+            transpose_table: false,
+            window: None,
+            watch_clipboard: false,
+            auto_copy: false,
+            exact_copies: false,
+            decimal_separator: DecimalSeparator::default(),
+            selected_cell: (RhythmicModifier::Dotted, NoteValue::Eighth),
+            precision: ROUND_LIMIT,
+            note_names: NoteNames::default(),
+            default_unit: Unit::Milliseconds,
+            ppqn: DEFAULT_PPQN,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            show_both_sample_rates: false,
+            tap_timeout: TapTimeout::default(),
+            tap_tolerance: TapTolerance::default(),
+            tap_averaging: TapAveraging::default(),
+            tap_latency_offset_ms: 0.0,
+            startup_tempo: DEFAULT_TEMPO,
+            millisecond_notes: Vec::new(),
+            stage_values: vec![
+                (NoteValue::Quarter, RhythmicModifier::Normal),
+                (NoteValue::Eighth, RhythmicModifier::Dotted),
+                (NoteValue::Eighth, RhythmicModifier::Normal),
+            ],
+            nudge_steps: vec![1.0, 5.0],
+            preferred_tempo_range: (70.0, 180.0),
+            automation_range: ParameterRange::default(),
+            custom_unit: CustomUnit::default(),
+            modifier_colors: Vec::new(),
+            color_by_range: false,
+            check_for_updates: None,
+            ui_scale: 1.0,
+            window_opacity: 1.0,
+        }
+    }
+}
+
+impl Settings {
+    // A hand-edited range is only used when it makes sense, since `f64::clamp` panics otherwise
+    pub fn tempo_range(&self) -> (f64, f64) {
+        if self.min_tempo > 0.0 && self.min_tempo <= self.max_tempo {
+            (self.min_tempo, self.max_tempo)
+        } else {
+            (DEFAULT_MIN_TEMPO, DEFAULT_MAX_TEMPO)
+        }
+    }
+
+    // `round` misbehaves with negative places, and more than this is noise
+    pub fn precision(&self) -> i32 {
+        self.precision.clamp(0, MAX_PRECISION)
+    }
+
+    // A hand-edited scale outside the slider's range could leave the window unusable
+    pub fn ui_scale(&self) -> f64 {
+        if self.ui_scale.is_finite() {
+            self.ui_scale
+                .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end())
+        } else {
+            1.0
+        }
+    }
+
+    // Any fainter and the window is easy to lose track of
+    pub fn window_opacity(&self) -> f32 {
+        if self.window_opacity.is_finite() {
+            self.window_opacity
+                .clamp(*WINDOW_OPACITY_RANGE.start(), *WINDOW_OPACITY_RANGE.end())
+        } else {
+            1.0
+        }
+    }
+
+    pub fn modifier_color(&self, rhythmic_modifier: RhythmicModifier) -> ColorTag {
+        self.modifier_colors
+            .iter()
+            .find(|(colored_modifier, _)| *colored_modifier == rhythmic_modifier)
+            .map_or(ColorTag::None, |(_, color_tag)| *color_tag)
+    }
+
+    pub fn startup_unit(&self) -> Unit {
+        self.resolve_unit(self.default_unit.clone())
+    }
+
+    // A saved custom unit follows the definition in preferences rather than the copy saved with it
+    pub fn resolve_unit(&self, unit: Unit) -> Unit {
+        match unit {
+            Unit::Custom(_) => self.custom_as_unit(),
+            unit => unit,
+        }
+    }
+
+    pub fn custom_as_unit(&self) -> Unit {
+        Unit::Custom(self.custom_unit.usable())
+    }
+
+    pub fn startup_tempo(&self) -> f64 {
+        let (min_tempo, max_tempo) = self.tempo_range();
+
+        if self.startup_tempo.is_finite() {
+            self.startup_tempo.clamp(min_tempo, max_tempo)
+        } else {
+            DEFAULT_TEMPO
+        }
+    }
+}
+
+// In logical pixels, as of when the window was last closed. Wayland never reports a position.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: f32,
+    pub height: f32,
+    pub position: Option<(f32, f32)>,
+}
+
+// The separator copied numbers use, since some European plugins and spreadsheets reject a dot.
+// The window always shows a dot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecimalSeparator {
+    #[default]
+    Dot,
+    Comma,
+}
+
+pub const DECIMAL_SEPARATORS: [DecimalSeparator; 2] =
+    [DecimalSeparator::Dot, DecimalSeparator::Comma];
+
+impl DecimalSeparator {
+    // One number at a time, so dots in whatever's copied alongside it, like a custom unit's name,
+    // are left alone
+    pub fn format(self, number: f64) -> String {
+        match self {
+            DecimalSeparator::Dot => number.to_string(),
+            DecimalSeparator::Comma => number.to_string().replace('.', ","),
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+impl Display for DecimalSeparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecimalSeparator::Dot => write!(f, "{}", tr("decimal-separator-dot")),
+            DecimalSeparator::Comma => write!(f, "{}", tr("decimal-separator-comma")),
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
+pub enum ThemeChoice {
+    System,
+    Theme(Theme),
+}
+
+#[cfg(feature = "gui")]
+impl ThemeChoice {
+    pub fn all() -> Vec<ThemeChoice> {
+        std::iter::once(ThemeChoice::System)
+            .chain(Theme::ALL.iter().cloned().map(ThemeChoice::Theme))
+            .collect()
+    }
+}
+
+#[cfg(feature = "gui")]
+impl Default for ThemeChoice {
+    fn default() -> Self {
+        ThemeChoice::Theme(Theme::Dracula)
+    }
+}
+
+#[cfg(feature = "gui")]
+impl Display for ThemeChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeChoice::System => write!(f, "{}", tr("theme-system")),
+            ThemeChoice::Theme(theme) => write!(f, "{}", theme),
+        }
+    }
+}
+
+// Saved by name, in English whatever the language, so switching languages keeps the theme
+#[cfg(feature = "gui")]
+impl ThemeChoice {
+    fn name(&self) -> String {
+        match self {
+            ThemeChoice::System => "System".to_string(),
+            ThemeChoice::Theme(theme) => theme.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+impl From<ThemeChoice> for String {
+    fn from(theme_choice: ThemeChoice) -> Self {
+        theme_choice.name()
+    }
+}
+
+#[cfg(feature = "gui")]
+impl From<String> for ThemeChoice {
+    fn from(name: String) -> Self {
+        ThemeChoice::all()
+            .into_iter()
+            .find(|theme_choice| theme_choice.name() == name)
+            .unwrap_or_default()
+    }
+}
+
+// Without the window there's nothing to theme, but the choice is kept as it was written so
+// settings saved from a terminal mode don't lose it
+#[cfg(not(feature = "gui"))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ThemeChoice(String);
+
+#[cfg(not(feature = "gui"))]
+impl Default for ThemeChoice {
+    fn default() -> Self {
+        ThemeChoice("Dracula".to_string())
+    }
+}
+
+#[cfg(feature = "gui")]
+pub fn system_theme() -> Theme {
+    match dark_light::detect() {
+        dark_light::Mode::Light => Theme::Light,
+        dark_light::Mode::Dark | dark_light::Mode::Default => Theme::Dark,
+    }
+}
+
+pub fn config_directory() -> Option<PathBuf> {
+    dirs::config_dir().map(|directory| directory.join("delay_time_calculator"))
+}
+
+pub fn path() -> Option<PathBuf> {
+    config_directory().map(|directory| directory.join("settings.json"))
+}
+
+pub fn load() -> Settings {
+    path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(settings: &Settings, path: &Path) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    let json = serde_json::to_string_pretty(settings).map_err(std::io::Error::other)?;
+    std::fs::write(path, json)
+}
+
+// Everything needed to set another machine up the same way, as one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub settings: Settings,
+    // The user's own; the built-in ones come with every copy of the app
+    #[serde(default)]
+    pub preset_templates: Vec<PresetTemplate>,
+}
+
+pub fn export_bundle(path: &Path, bundle: &Bundle) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(bundle).map_err(|error| error.to_string())?;
+    std::fs::write(path, json).map_err(|error| error.to_string())
+}
+
+pub fn import_bundle(path: &Path) -> Result<Bundle, String> {
+    let json = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    serde_json::from_str(&json).map_err(|error| error.to_string())
+}
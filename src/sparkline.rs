@@ -0,0 +1,103 @@
+// A small line of the tempo of each tap interval, drawn beside the tempo field while tapping, so a
+// drifting hand shows as a slope
+use iced::mouse::Cursor;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Stroke};
+use iced::{Point, Rectangle, Renderer, Size, Theme};
+
+pub const SPARKLINE_WIDTH: u16 = 80;
+pub const SPARKLINE_HEIGHT: u16 = 24;
+// Older intervals scroll off the left
+const MAX_POINTS: usize = 16;
+
+pub struct Sparkline {
+    pub tempos: Vec<f64>,
+}
+
+// Spread across the width, with the fastest tempo at the top. A steady run is a flat line through
+// the middle.
+fn points(tempos: &[f64], size: Size) -> Vec<Point> {
+    let tempos = &tempos[tempos.len().saturating_sub(MAX_POINTS)..];
+    let min = tempos.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = tempos.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let step = size.width / (tempos.len().max(2) - 1) as f32;
+
+    tempos
+        .iter()
+        .enumerate()
+        .map(|(index, tempo)| {
+            let height = if max > min {
+                ((tempo - min) / (max - min)) as f32
+            } else {
+                0.5
+            };
+            Point::new(index as f32 * step, (1.0 - height) * size.height)
+        })
+        .collect()
+}
+
+impl<Message> canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let palette = theme.extended_palette();
+        // Inset so the stroke isn't clipped at the edges
+        let inset = Size::new(frame.width() - 4.0, frame.height() - 4.0);
+        let points: Vec<_> = points(&self.tempos, inset)
+            .into_iter()
+            .map(|point| Point::new(point.x + 2.0, point.y + 2.0))
+            .collect();
+
+        let line = Path::new(|builder| {
+            if let Some((first, rest)) = points.split_first() {
+                builder.move_to(*first);
+                for point in rest {
+                    builder.line_to(*point);
+                }
+            }
+        });
+        frame.stroke(
+            &line,
+            Stroke::default()
+                .with_color(palette.primary.base.color)
+                .with_width(1.5),
+        );
+
+        if let Some(last) = points.last() {
+            frame.fill(&Path::circle(*last, 2.0), palette.primary.strong.color);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn places_points() {
+        let size = Size::new(30.0, 10.0);
+
+        let points = points(&[100.0, 110.0, 120.0, 110.0], size);
+        assert_eq!(
+            points,
+            [
+                Point::new(0.0, 10.0),
+                Point::new(10.0, 5.0),
+                Point::new(20.0, 0.0),
+                Point::new(30.0, 5.0),
+            ]
+        );
+
+        let steady = super::points(&[120.0, 120.0], size);
+        assert!(steady.iter().all(|point| point.y == 5.0));
+    }
+}
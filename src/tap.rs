@@ -0,0 +1,451 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+#[cfg(feature = "gui")]
+use iced::time::Instant;
+#[cfg(not(feature = "gui"))]
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{tr, tr_with};
+
+// How many of the most recent taps the tempo is averaged over
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TapWindow {
+    Last(usize),
+    #[default]
+    All,
+}
+
+pub const TAP_WINDOWS: [TapWindow; 4] = [
+    TapWindow::Last(4),
+    TapWindow::Last(8),
+    TapWindow::Last(16),
+    TapWindow::All,
+];
+
+impl Display for TapWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TapWindow::Last(count) => {
+                write!(f, "{}", tr_with("tap-window-last", &[("count", count)]))
+            }
+            TapWindow::All => write!(f, "{}", tr("tap-window-all")),
+        }
+    }
+}
+
+// A pause longer than this means the next tap starts a new run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TapTimeout {
+    #[default]
+    Never,
+    Seconds(u8),
+}
+
+pub const TAP_TIMEOUTS: [TapTimeout; 5] = [
+    TapTimeout::Never,
+    TapTimeout::Seconds(2),
+    TapTimeout::Seconds(3),
+    TapTimeout::Seconds(5),
+    TapTimeout::Seconds(10),
+];
+
+impl Display for TapTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TapTimeout::Never => write!(f, "{}", tr("tap-timeout-never")),
+            TapTimeout::Seconds(seconds) => write!(
+                f,
+                "{}",
+                tr_with("tap-timeout-seconds", &[("seconds", seconds)])
+            ),
+        }
+    }
+}
+
+// How far an interval can stray from the run's median before it's treated as a mis-tap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TapTolerance {
+    Off,
+    Percent(u8),
+}
+
+impl Default for TapTolerance {
+    fn default() -> Self {
+        TapTolerance::Percent(25)
+    }
+}
+
+pub const TAP_TOLERANCES: [TapTolerance; 4] = [
+    TapTolerance::Off,
+    TapTolerance::Percent(10),
+    TapTolerance::Percent(25),
+    TapTolerance::Percent(50),
+];
+
+impl Display for TapTolerance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TapTolerance::Off => write!(f, "{}", tr("tap-tolerance-off")),
+            TapTolerance::Percent(percent) => write!(
+                f,
+                "{}",
+                tr_with("tap-tolerance-percent", &[("percent", percent)])
+            ),
+        }
+    }
+}
+
+// The simple mean lags behind a tempo that drifts while tapping along; the weighted one follows it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TapAveraging {
+    #[default]
+    Mean,
+    Weighted,
+}
+
+pub const TAP_AVERAGINGS: [TapAveraging; 2] = [TapAveraging::Mean, TapAveraging::Weighted];
+
+impl Display for TapAveraging {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TapAveraging::Mean => write!(f, "{}", tr("tap-averaging-mean")),
+            TapAveraging::Weighted => write!(f, "{}", tr("tap-averaging-weighted")),
+        }
+    }
+}
+
+// In weighted averaging, each interval counts this fraction as much as the one after it
+const OLDER_INTERVAL_WEIGHT: f64 = 0.8;
+
+// A median of fewer intervals than this can't tell the mis-tap from the good ones
+const MIN_INTERVALS_TO_REJECT: usize = 3;
+
+// Every tap is kept so the window can be changed mid-run and the tempo recalculated
+pub struct TapTempo {
+    taps: Vec<Instant>,
+    window: TapWindow,
+    timeout: TapTimeout,
+    tolerance: TapTolerance,
+    averaging: TapAveraging,
+    // In seconds, taken off every interval to make up for input latency that runs taps slow
+    latency_offset: f64,
+}
+
+impl TapTempo {
+    pub fn new(
+        window: TapWindow,
+        timeout: TapTimeout,
+        tolerance: TapTolerance,
+        averaging: TapAveraging,
+        latency_offset_ms: f64,
+    ) -> Self {
+        Self {
+            taps: Vec::new(),
+            window,
+            timeout,
+            tolerance,
+            averaging,
+            latency_offset: latency_offset_ms / 1000.0,
+        }
+    }
+
+    pub fn tap(&mut self) -> Option<f64> {
+        self.taps.push(Instant::now());
+        self.tempo()
+    }
+
+    pub fn tempo(&self) -> Option<f64> {
+        let intervals = self.intervals();
+        let rejected = rejected(&intervals, self.tolerance);
+        let kept: Vec<f64> = intervals
+            .iter()
+            .zip(&rejected)
+            .filter(|(_, is_rejected)| !**is_rejected)
+            .map(|(interval, _)| *interval)
+            .collect();
+
+        if kept.is_empty() {
+            return None;
+        }
+
+        let interval = match self.averaging {
+            TapAveraging::Mean => kept.iter().sum::<f64>() / kept.len() as f64,
+            TapAveraging::Weighted => weighted_mean(&kept),
+        };
+        (interval > 0.0).then(|| 60.0 / interval)
+    }
+
+    // Intervals left out of the tempo for straying too far from the median
+    pub fn rejected_count(&self) -> usize {
+        rejected(&self.intervals(), self.tolerance)
+            .into_iter()
+            .filter(|is_rejected| *is_rejected)
+            .count()
+    }
+
+    pub fn last_tap_was_rejected(&self) -> bool {
+        rejected(&self.intervals(), self.tolerance)
+            .last()
+            .is_some_and(|is_rejected| *is_rejected)
+    }
+
+    pub fn set_tolerance(&mut self, tolerance: TapTolerance) {
+        self.tolerance = tolerance;
+    }
+
+    pub fn set_averaging(&mut self, averaging: TapAveraging) {
+        self.averaging = averaging;
+    }
+
+    pub fn set_window(&mut self, window: TapWindow) {
+        self.window = window;
+    }
+
+    pub fn window(&self) -> TapWindow {
+        self.window
+    }
+
+    pub fn set_latency_offset(&mut self, latency_offset_ms: f64) {
+        self.latency_offset = latency_offset_ms / 1000.0;
+    }
+
+    pub fn set_timeout(&mut self, timeout: TapTimeout) {
+        self.timeout = timeout;
+    }
+
+    // Whether the run has paused for longer than the timeout, so the next tap should start over
+    pub fn is_expired(&self) -> bool {
+        match (self.timeout, self.taps.last()) {
+            (TapTimeout::Seconds(seconds), Some(last)) => {
+                last.elapsed() > Duration::from_secs(u64::from(seconds))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn tap_count(&self) -> usize {
+        self.taps.len()
+    }
+
+    // The number of taps the current tempo is averaged over
+    pub fn used_count(&self) -> usize {
+        match self.window {
+            TapWindow::Last(count) => self.taps.len().min(count),
+            TapWindow::All => self.taps.len(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.taps.clear();
+    }
+
+    // The tempo each interval in the run would be on its own, ignoring the window and rejections
+    pub fn interval_tempos(&self) -> Vec<f64> {
+        self.taps
+            .windows(2)
+            .map(|pair| self.interval(pair[0], pair[1]))
+            .filter(|interval| *interval > 0.0)
+            .map(|interval| 60.0 / interval)
+            .collect()
+    }
+
+    // In seconds, between each pair of taps in the window
+    fn intervals(&self) -> Vec<f64> {
+        self.taps[self.taps.len() - self.used_count()..]
+            .windows(2)
+            .map(|pair| self.interval(pair[0], pair[1]))
+            .collect()
+    }
+
+    fn interval(&self, earlier: Instant, later: Instant) -> f64 {
+        later.duration_since(earlier).as_secs_f64() - self.latency_offset
+    }
+}
+
+// Oldest first, so the last interval has the most weight
+fn weighted_mean(intervals: &[f64]) -> f64 {
+    let weights: Vec<f64> = (0..intervals.len())
+        .map(|index| OLDER_INTERVAL_WEIGHT.powi((intervals.len() - 1 - index) as i32))
+        .collect();
+
+    intervals
+        .iter()
+        .zip(&weights)
+        .map(|(interval, weight)| interval * weight)
+        .sum::<f64>()
+        / weights.iter().sum::<f64>()
+}
+
+// A late tap leaves one long interval and an extra tap splits one into two short ones, so whole
+// intervals are dropped rather than taps
+fn rejected(intervals: &[f64], tolerance: TapTolerance) -> Vec<bool> {
+    let TapTolerance::Percent(percent) = tolerance else {
+        return vec![false; intervals.len()];
+    };
+
+    if intervals.len() < MIN_INTERVALS_TO_REJECT {
+        return vec![false; intervals.len()];
+    }
+
+    let mut sorted = intervals.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let middle = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2.0
+    } else {
+        sorted[middle]
+    };
+
+    let limit = median * f64::from(percent) / 100.0;
+    let rejected: Vec<bool> = intervals
+        .iter()
+        .map(|interval| (interval - median).abs() > limit)
+        .collect();
+
+    // Too tight a tolerance for a drifting run shouldn't leave nothing to average
+    if rejected.iter().all(|is_rejected| *is_rejected) {
+        vec![false; intervals.len()]
+    } else {
+        rejected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use round::round;
+
+    use super::*;
+
+    fn rounded_tempo(tap_tempo: &TapTempo) -> Option<f64> {
+        tap_tempo.tempo().map(|tempo| round(tempo, 6))
+    }
+
+    fn taps(intervals_ms: &[u64]) -> TapTempo {
+        let start = Instant::now();
+        let mut tap_tempo = TapTempo::new(
+            TapWindow::All,
+            TapTimeout::Never,
+            TapTolerance::Off,
+            TapAveraging::Mean,
+            0.0,
+        );
+        tap_tempo.taps.push(start);
+
+        for interval in intervals_ms {
+            let previous = *tap_tempo.taps.last().unwrap();
+            tap_tempo
+                .taps
+                .push(previous + Duration::from_millis(*interval));
+        }
+
+        tap_tempo
+    }
+
+    #[test]
+    fn needs_two_taps() {
+        assert_eq!(taps(&[]).tempo(), None);
+        assert_eq!(rounded_tempo(&taps(&[500])), Some(120.0));
+    }
+
+    #[test]
+    fn interval_tempos_span_the_run() {
+        let mut tap_tempo = taps(&[500, 600, 750]);
+        tap_tempo.set_window(TapWindow::Last(2));
+        let tempos: Vec<_> = tap_tempo
+            .interval_tempos()
+            .into_iter()
+            .map(|tempo| round(tempo, 6))
+            .collect();
+        assert_eq!(tempos, [120.0, 100.0, 80.0]);
+    }
+
+    #[test]
+    fn window_averages_only_recent_taps() {
+        // Slows from 120 to 100 BPM
+        let mut tap_tempo = taps(&[500, 500, 500, 600, 600, 600]);
+        assert_eq!(tap_tempo.used_count(), 7);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(round(6.0 / 3.3 * 60.0, 6)));
+
+        tap_tempo.set_window(TapWindow::Last(4));
+        assert_eq!(tap_tempo.used_count(), 4);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(100.0));
+    }
+
+    #[test]
+    fn latency_offset_shortens_intervals() {
+        let mut tap_tempo = taps(&[504, 504, 504]);
+        tap_tempo.set_latency_offset(4.0);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(120.0));
+        assert_eq!(
+            tap_tempo
+                .interval_tempos()
+                .iter()
+                .map(|tempo| round(*tempo, 6))
+                .collect::<Vec<_>>(),
+            [120.0; 3]
+        );
+    }
+
+    #[test]
+    fn weighted_averaging_follows_drift() {
+        let mut tap_tempo = taps(&[500, 500, 500, 600, 600, 600]);
+        let mean = tap_tempo.tempo().unwrap();
+
+        tap_tempo.set_averaging(TapAveraging::Weighted);
+        let weighted = tap_tempo.tempo().unwrap();
+        assert!(weighted < mean && weighted > 100.0);
+
+        // A steady run comes out the same either way
+        let mut tap_tempo = taps(&[500, 500, 500]);
+        tap_tempo.set_averaging(TapAveraging::Weighted);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(120.0));
+    }
+
+    #[test]
+    fn expires_after_a_pause() {
+        let mut tap_tempo = taps(&[]);
+        assert!(!tap_tempo.is_expired());
+
+        tap_tempo.set_timeout(TapTimeout::Seconds(2));
+        assert!(!tap_tempo.is_expired());
+
+        tap_tempo.taps = vec![Instant::now() - Duration::from_secs(3)];
+        assert!(tap_tempo.is_expired());
+    }
+
+    #[test]
+    fn ignores_mis_taps() {
+        // A doubled tap, then a missed one
+        let mut tap_tempo = taps(&[500, 500, 250, 250, 500, 1000, 500]);
+        assert_eq!(tap_tempo.rejected_count(), 0);
+
+        tap_tempo.set_tolerance(TapTolerance::Percent(25));
+        assert_eq!(tap_tempo.rejected_count(), 3);
+        assert!(!tap_tempo.last_tap_was_rejected());
+        assert_eq!(rounded_tempo(&tap_tempo), Some(120.0));
+
+        tap_tempo
+            .taps
+            .push(*tap_tempo.taps.last().unwrap() + Duration::from_millis(900));
+        assert!(tap_tempo.last_tap_was_rejected());
+    }
+
+    #[test]
+    fn too_few_intervals_to_reject() {
+        let mut tap_tempo = taps(&[500, 1000]);
+        tap_tempo.set_tolerance(TapTolerance::Percent(10));
+        assert_eq!(tap_tempo.rejected_count(), 0);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(80.0));
+    }
+
+    #[test]
+    fn window_larger_than_run_uses_every_tap() {
+        let mut tap_tempo = taps(&[500, 500]);
+        tap_tempo.set_window(TapWindow::Last(8));
+        assert_eq!(tap_tempo.used_count(), 3);
+        assert_eq!(rounded_tempo(&tap_tempo), Some(120.0));
+    }
+}
@@ -0,0 +1,63 @@
+// Tapping along with the pulse at a known tempo shows how far this machine's intervals stray from
+// the real ones. The average difference becomes the tap latency offset.
+
+use iced::time::Instant;
+
+pub const CALIBRATION_TEMPO: f64 = 100.0;
+// Enough intervals that one uneven tap barely moves the average
+pub const CALIBRATION_TAPS: usize = 17;
+
+#[derive(Debug, Default)]
+pub struct TapCalibration {
+    taps: Vec<Instant>,
+}
+
+impl TapCalibration {
+    pub fn tap(&mut self) {
+        self.taps.push(Instant::now());
+    }
+
+    pub fn remaining_taps(&self) -> usize {
+        CALIBRATION_TAPS.saturating_sub(self.taps.len())
+    }
+
+    // In milliseconds, once every tap is in
+    pub fn offset_ms(&self) -> Option<f64> {
+        (self.remaining_taps() == 0).then(|| offset_ms(&self.taps))
+    }
+}
+
+// Only the first and last taps matter to the mean interval, since the ones between cancel out
+fn offset_ms(taps: &[Instant]) -> f64 {
+    let (Some(first), Some(last)) = (taps.first(), taps.last()) else {
+        return 0.0;
+    };
+    let intervals = taps.len().saturating_sub(1).max(1) as f64;
+
+    last.duration_since(*first).as_secs_f64() * 1000.0 / intervals - 60_000.0 / CALIBRATION_TEMPO
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn measures_the_average_drift() {
+        let start = Instant::now();
+        // 600 ms apart at 100 BPM, so these run 4 ms slow
+        let taps: Vec<Instant> = (0..CALIBRATION_TAPS as u64)
+            .map(|tap| start + Duration::from_millis(tap * 604))
+            .collect();
+
+        let calibration = TapCalibration {
+            taps: taps[..CALIBRATION_TAPS - 1].to_vec(),
+        };
+        assert_eq!(calibration.remaining_taps(), 1);
+        assert_eq!(calibration.offset_ms(), None);
+
+        let calibration = TapCalibration { taps };
+        assert_eq!(calibration.offset_ms().map(|ms| ms.round()), Some(4.0));
+    }
+}
@@ -0,0 +1,68 @@
+// Everything the buttons, keys, and scroll wheel can do to the tempo. Plain data rather than
+// closures, so actions can be compared, saved, and sent from scripts.
+
+use round::round;
+use serde::{Deserialize, Serialize};
+
+use crate::ROUND_LIMIT;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TempoAction {
+    Halve,
+    Double,
+    NudgeBy(f64),
+    RoundToInt,
+    SetTo(f64),
+}
+
+impl TempoAction {
+    // Only setting the tempo outright works without one to start from
+    pub fn apply(&self, tempo: Option<f64>) -> Option<f64> {
+        match self {
+            TempoAction::Halve => tempo.map(|tempo| tempo / 2.0),
+            TempoAction::Double => tempo.map(|tempo| tempo * 2.0),
+            // Rounded so repeated fractional steps don't accumulate float error
+            TempoAction::NudgeBy(step) => tempo.map(|tempo| round(tempo + step, ROUND_LIMIT)),
+            TempoAction::RoundToInt => tempo.map(f64::round),
+            TempoAction::SetTo(tempo) => Some(*tempo),
+        }
+    }
+
+    // Nudges arrive in bursts while scrolling or holding a key; only jumps are worth keeping in the
+    // history
+    pub fn is_nudge(&self) -> bool {
+        matches!(self, TempoAction::NudgeBy(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies() {
+        assert_eq!(TempoAction::Halve.apply(Some(120.0)), Some(60.0));
+        assert_eq!(TempoAction::Double.apply(Some(120.0)), Some(240.0));
+        assert_eq!(TempoAction::NudgeBy(5.0).apply(Some(120.0)), Some(125.0));
+        assert_eq!(TempoAction::NudgeBy(-1.0).apply(Some(120.0)), Some(119.0));
+        assert_eq!(TempoAction::RoundToInt.apply(Some(93.5)), Some(94.0));
+        assert_eq!(TempoAction::SetTo(128.0).apply(None), Some(128.0));
+        assert_eq!(TempoAction::Double.apply(None), None);
+
+        // Repeated fractional nudges don't drift
+        let tempo = (0..10).try_fold(120.0, |tempo, _| {
+            TempoAction::NudgeBy(0.1).apply(Some(tempo))
+        });
+        assert_eq!(tempo, Some(121.0));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let actions = [TempoAction::Halve, TempoAction::NudgeBy(0.5)];
+        let json = serde_json::to_string(&actions).unwrap();
+        assert_eq!(
+            serde_json::from_str::<[TempoAction; 2]>(&json).unwrap(),
+            actions
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeSignature {
+    pub beats: u8,
+    pub note_value: u8,
+}
+
+impl TimeSignature {
+    const fn new(beats: u8, note_value: u8) -> Self {
+        Self { beats, note_value }
+    }
+
+    // Tempo is always in quarter notes per minute, so a bar of 6/8 is three quarter notes long
+    pub fn quarter_notes_per_bar(&self) -> f64 {
+        f64::from(self.beats) * 4.0 / f64::from(self.note_value)
+    }
+
+    pub fn seconds_per_bar(&self, tempo: f64) -> f64 {
+        self.quarter_notes_per_bar() * 60.0 / tempo
+    }
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        TimeSignature::new(4, 4)
+    }
+}
+
+impl Display for TimeSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.beats, self.note_value)
+    }
+}
+
+pub const TIME_SIGNATURES: [TimeSignature; 9] = [
+    TimeSignature::new(2, 4),
+    TimeSignature::new(3, 4),
+    TimeSignature::new(4, 4),
+    TimeSignature::new(5, 4),
+    TimeSignature::new(6, 4),
+    TimeSignature::new(3, 8),
+    TimeSignature::new(6, 8),
+    TimeSignature::new(7, 8),
+    TimeSignature::new(12, 8),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::TimeSignature;
+
+    #[test]
+    fn bar_lengths() {
+        assert_eq!(TimeSignature::new(4, 4).quarter_notes_per_bar(), 4.0);
+        assert_eq!(TimeSignature::new(6, 8).quarter_notes_per_bar(), 3.0);
+        assert_eq!(TimeSignature::new(7, 8).quarter_notes_per_bar(), 3.5);
+        assert_eq!(TimeSignature::new(4, 4).seconds_per_bar(120.0), 2.0);
+        assert_eq!(TimeSignature::new(3, 4).seconds_per_bar(90.0), 2.0);
+    }
+}
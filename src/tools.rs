@@ -0,0 +1,117 @@
+pub mod audio_tap;
+pub mod buffer_latency;
+pub mod comb_tuning;
+pub mod count_in;
+pub mod echo_schedule;
+pub mod envelope_times;
+pub mod feedback_decay;
+pub mod flam_offsets;
+pub mod groove_offsets;
+pub mod haas;
+pub mod lfo_rates;
+pub mod loop_length;
+pub mod midi_clock;
+pub mod midi_tap;
+pub mod multitap;
+pub mod ping_pong;
+pub mod polyrhythm;
+pub mod section_length;
+pub mod sidechain_pump;
+pub mod speaker_alignment;
+pub mod tempo_match;
+pub mod tempo_ramp;
+pub mod timecode_chase;
+pub mod turntable_pitch;
+
+use std::fmt::Display;
+
+use crate::i18n::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tool {
+    None,
+    SpeakerAlignment,
+    TimecodeChase,
+    MidiTap,
+    AudioTap,
+    FeedbackDecay,
+    PingPong,
+    MidiClock,
+    SectionLength,
+    LfoRates,
+    Haas,
+    CombTuning,
+    BufferLatency,
+    TempoMatch,
+    GrooveOffsets,
+    TempoRamp,
+    Multitap,
+    SidechainPump,
+    LoopLength,
+    Polyrhythm,
+    FlamOffsets,
+    CountIn,
+    TurntablePitch,
+    EnvelopeTimes,
+    EchoSchedule,
+}
+
+pub const TOOLS: [Tool; 25] = [
+    Tool::None,
+    Tool::SpeakerAlignment,
+    Tool::TimecodeChase,
+    Tool::MidiTap,
+    Tool::AudioTap,
+    Tool::FeedbackDecay,
+    Tool::PingPong,
+    Tool::MidiClock,
+    Tool::SectionLength,
+    Tool::LfoRates,
+    Tool::Haas,
+    Tool::CombTuning,
+    Tool::BufferLatency,
+    Tool::TempoMatch,
+    Tool::GrooveOffsets,
+    Tool::TempoRamp,
+    Tool::Multitap,
+    Tool::SidechainPump,
+    Tool::LoopLength,
+    Tool::Polyrhythm,
+    Tool::FlamOffsets,
+    Tool::CountIn,
+    Tool::TurntablePitch,
+    Tool::EnvelopeTimes,
+    Tool::EchoSchedule,
+];
+
+impl Display for Tool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tool::None => write!(f, "{}", tr("tool-none")),
+            Tool::SpeakerAlignment => write!(f, "{}", tr("tool-speaker-alignment")),
+            Tool::TimecodeChase => write!(f, "{}", tr("tool-timecode-chase")),
+            Tool::MidiTap => write!(f, "{}", tr("tool-midi-tap")),
+            Tool::AudioTap => write!(f, "{}", tr("tool-audio-tap")),
+            Tool::FeedbackDecay => write!(f, "{}", tr("tool-feedback-decay")),
+            Tool::PingPong => write!(f, "{}", tr("tool-ping-pong")),
+            Tool::MidiClock => write!(f, "{}", tr("tool-midi-clock")),
+            Tool::SectionLength => write!(f, "{}", tr("tool-section-length")),
+            Tool::LfoRates => write!(f, "{}", tr("tool-lfo-rates")),
+            Tool::Haas => write!(f, "{}", tr("tool-haas")),
+            Tool::CombTuning => write!(f, "{}", tr("tool-comb-tuning")),
+            Tool::BufferLatency => write!(f, "{}", tr("tool-buffer-latency")),
+            Tool::TempoMatch => write!(f, "{}", tr("tool-tempo-match")),
+            Tool::GrooveOffsets => write!(f, "{}", tr("tool-groove-offsets")),
+            Tool::TempoRamp => write!(f, "{}", tr("tool-tempo-ramp")),
+            Tool::Multitap => write!(f, "{}", tr("tool-multitap")),
+            Tool::SidechainPump => write!(f, "{}", tr("tool-sidechain-pump")),
+            Tool::LoopLength => write!(f, "{}", tr("tool-loop-length")),
+            Tool::Polyrhythm => write!(f, "{}", tr("tool-polyrhythm")),
+            Tool::FlamOffsets => write!(f, "{}", tr("tool-flam-offsets")),
+            Tool::CountIn => write!(f, "{}", tr("tool-count-in")),
+            Tool::TurntablePitch => write!(f, "{}", tr("tool-turntable-pitch")),
+            Tool::EnvelopeTimes => write!(f, "{}", tr("tool-envelope-times")),
+            Tool::EchoSchedule => write!(f, "{}", tr("tool-echo-schedule")),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use iced::widget::{button, pick_list, row, text};
+use iced::{Alignment, Element, Subscription};
+
+use crate::i18n::{tr, tr_with};
+use crate::{audio, SPACING};
+
+pub struct AudioTap {
+    device_names: Vec<String>,
+    device_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    RefreshDevices,
+    SelectDevice(String),
+    Disconnect,
+    OnsetDetected,
+}
+
+impl Default for AudioTap {
+    fn default() -> Self {
+        Self {
+            device_names: audio::input_device_names(),
+            device_name: None,
+        }
+    }
+}
+
+impl AudioTap {
+    // Returns whether the message should register a tap
+    pub fn update(&mut self, message: Message) -> bool {
+        match message {
+            Message::RefreshDevices => self.device_names = audio::input_device_names(),
+            Message::SelectDevice(device_name) => self.device_name = Some(device_name),
+            Message::Disconnect => self.device_name = None,
+            Message::OnsetDetected => return true,
+        }
+
+        false
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if !audio::IS_SUPPORTED {
+            return text(tr_with(
+                "tool-needs-feature",
+                &[("tool", &tr("tool-audio-tap")), ("feature", &"audio")],
+            ))
+            .into();
+        }
+
+        row![
+            text(tr("audio-input")),
+            pick_list(
+                self.device_names.as_slice(),
+                self.device_name.clone(),
+                Message::SelectDevice
+            ),
+            button(tr("tool-refresh")).on_press(Message::RefreshDevices),
+            button(tr("tool-disconnect"))
+                .on_press_maybe(self.device_name.as_ref().map(|_| Message::Disconnect)),
+            text(match self.device_name {
+                Some(_) => tr("listening-for-hits"),
+                None => "",
+            }),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        match &self.device_name {
+            Some(device_name) => {
+                audio::listen(device_name.clone()).map(|()| Message::OnsetDetected)
+            }
+            None => Subscription::none(),
+        }
+    }
+}
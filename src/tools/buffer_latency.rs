@@ -0,0 +1,116 @@
+use iced::widget::{column, pick_list, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{ROUND_LIMIT, SPACING};
+
+pub const SAMPLE_RATES: [u32; 6] = [44_100, 48_000, 88_200, 96_000, 176_400, 192_000];
+const BUFFER_SIZES: [u32; 7] = [32, 64, 128, 256, 512, 1024, 2048];
+const LABEL_WIDTH: u16 = 120;
+
+pub struct BufferLatency {
+    sample_rate: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectSampleRate(u32),
+}
+
+impl Default for BufferLatency {
+    fn default() -> Self {
+        Self {
+            sample_rate: 48_000,
+        }
+    }
+}
+
+impl BufferLatency {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::SelectSampleRate(sample_rate) => self.sample_rate = sample_rate,
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("tool-sample-rate")),
+            pick_list(
+                SAMPLE_RATES,
+                Some(self.sample_rate),
+                Message::SelectSampleRate
+            ),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let buffer_rows = BUFFER_SIZES.iter().map(|buffer_size| {
+            let latency = latency(*buffer_size, self.sample_rate);
+            let note = tempo
+                .map(|tempo| nearest_note(latency, tempo))
+                .unwrap_or_default();
+
+            row![
+                text(tr_with("sample-count", &[("samples", buffer_size)])).width(LABEL_WIDTH),
+                text(format!("{} ms", round(latency, ROUND_LIMIT))).width(100),
+                // An input and an output buffer, before any converter or plugin latency
+                text(tr_with(
+                    "round-trip",
+                    &[("ms", &round(latency * 2.0, ROUND_LIMIT))]
+                ))
+                .width(160),
+                text(note),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        });
+
+        column![
+            input_row,
+            Column::with_children(buffer_rows).spacing(SPACING / 2)
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+fn latency(buffer_size: u32, sample_rate: u32) -> f64 {
+    f64::from(buffer_size) * 1000.0 / f64::from(sample_rate)
+}
+
+// The closest straight 1/n note, so a buffer can be read against the table's values
+fn nearest_note(latency: f64, tempo: f64) -> String {
+    let whole_note = 240_000.0 / tempo;
+    let division = 2_f64.powf((whole_note / latency).log2().round());
+
+    tr_with(
+        "nearest-straight-note",
+        &[
+            ("division", &division),
+            (
+                "percent",
+                &round(latency / (whole_note / division) * 100.0, 0),
+            ),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_is_buffer_over_sample_rate() {
+        assert_eq!(latency(480, 48_000), 10.0);
+        assert_eq!(latency(2048, 44_100), 2048.0 * 1000.0 / 44_100.0);
+    }
+
+    #[test]
+    fn nearest_note_rounds_to_a_power_of_two_division() {
+        // A 1/64 note at 120 BPM is 31.25 ms
+        assert_eq!(nearest_note(31.25, 120.0), "≈ 1/64 note (100%)");
+        assert_eq!(nearest_note(40.0, 120.0), "≈ 1/64 note (128%)");
+    }
+}
@@ -0,0 +1,179 @@
+use iced::widget::{column, row, text, text_input};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{ROUND_LIMIT, SPACING};
+
+const A4_HZ: f64 = 440.0;
+// MIDI note number of A4
+const A4_NOTE: i32 = 69;
+// C-1, about 8.18 Hz
+const LOWEST_NOTE: i32 = 0;
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+pub struct CombTuning {
+    delay_text: String,
+    note_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreDelay(String),
+    StoreNote(String),
+}
+
+impl Default for CombTuning {
+    fn default() -> Self {
+        Self {
+            delay_text: "2.273".to_string(),
+            note_text: "A4".to_string(),
+        }
+    }
+}
+
+impl CombTuning {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::StoreDelay(text) => self.delay_text = text,
+            Message::StoreNote(text) => self.note_text = text,
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        // A comb filter's fundamental is the inverse of its delay
+        let pitch = match self.delay_text.trim().parse::<f64>() {
+            Ok(delay) if delay > 0.0 => {
+                let frequency = 1000.0 / delay;
+                let (note, cents) = nearest_note(frequency);
+                tr_with(
+                    "comb-pitch",
+                    &[
+                        ("hz", &round(frequency, 2)),
+                        ("note", &note_name(note)),
+                        ("cents", &format!("{:+}", round(cents, 1))),
+                    ],
+                )
+            }
+            _ => tr("delay-prompt").to_string(),
+        };
+
+        let delay = match parse_note(&self.note_text) {
+            Some(note) => {
+                let frequency = note_frequency(note);
+                tr_with(
+                    "comb-delay",
+                    &[
+                        ("hz", &round(frequency, 2)),
+                        ("ms", &round(1000.0 / frequency, ROUND_LIMIT)),
+                    ],
+                )
+            }
+            None => tr("note-prompt").to_string(),
+        };
+
+        column![
+            row![
+                text(tr("tool-delay-ms")),
+                text_input("", &self.delay_text)
+                    .on_input(Message::StoreDelay)
+                    .width(80),
+                text(pitch),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center),
+            row![
+                text(tr("note")),
+                text_input("", &self.note_text)
+                    .on_input(Message::StoreNote)
+                    .width(80),
+                text(delay),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+fn note_frequency(note: i32) -> f64 {
+    A4_HZ * 2f64.powf(f64::from(note - A4_NOTE) / 12.0)
+}
+
+// The closest equal-tempered MIDI note and how far the frequency sits from it
+fn nearest_note(frequency: f64) -> (i32, f64) {
+    let semitones = 12.0 * (frequency / A4_HZ).log2();
+    let offset = semitones.round();
+    (A4_NOTE + offset as i32, (semitones - offset) * 100.0)
+}
+
+// E.g. "≈ C-1 +12¢", for the main table's Hz cells. Rates below the lowest MIDI note are heard
+// as repeats rather than a pitch, so they get no label.
+pub fn pitch_label(frequency: f64) -> Option<String> {
+    let (note, cents) = nearest_note(frequency);
+    (note >= LOWEST_NOTE).then(|| format!("≈ {} {:+}¢", note_name(note), cents.round() as i32))
+}
+
+// Octaves are numbered so that middle C (MIDI 60) is C4
+fn note_name(note: i32) -> String {
+    let name = NOTE_NAMES[note.rem_euclid(12) as usize];
+    format!("{}{}", name, note.div_euclid(12) - 1)
+}
+
+fn parse_note(text: &str) -> Option<i32> {
+    let text = text.trim();
+    let mut chars = text.chars();
+
+    let pitch_class = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+
+    let rest = chars.as_str();
+    let (accidental, octave) = match rest.chars().next() {
+        Some('#' | '♯') => (1, &rest[rest.chars().next()?.len_utf8()..]),
+        Some('b' | '♭') => (-1, &rest[rest.chars().next()?.len_utf8()..]),
+        _ => (0, rest),
+    };
+
+    let octave = octave.parse::<i32>().ok()?;
+    Some((octave + 1) * 12 + pitch_class + accidental)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_note_names() {
+        assert_eq!(parse_note("A4"), Some(69));
+        assert_eq!(parse_note("c4"), Some(60));
+        assert_eq!(parse_note("C#3"), Some(49));
+        assert_eq!(parse_note("Eb2"), Some(39));
+        assert_eq!(parse_note("A-1"), Some(9));
+        assert_eq!(parse_note("H2"), None);
+        assert_eq!(parse_note("A"), None);
+    }
+
+    #[test]
+    fn delay_to_pitch_and_back() {
+        let (note, cents) = nearest_note(1000.0 / 2.272727);
+        assert_eq!(note_name(note), "A4");
+        assert!(cents.abs() < 0.1);
+
+        assert_eq!(note_name(60), "C4");
+        assert_eq!(pitch_label(8.25).as_deref(), Some("≈ C-1 +16¢"));
+        assert_eq!(pitch_label(7.8), None);
+        assert_eq!(pitch_label(2.0), None);
+        assert_eq!(round(note_frequency(60), 2), 261.63);
+    }
+}
@@ -0,0 +1,119 @@
+use iced::widget::{button, column, pick_list, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::tools::section_length::FRAME_RATES;
+use crate::{ROUND_LIMIT, SPACING};
+
+const COUNT_IN_BARS: [u8; 2] = [1, 2];
+const LABEL_WIDTH: u16 = 120;
+
+pub struct CountIn {
+    frame_rate: f64,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectFrameRate(f64),
+    Copy(f64),
+}
+
+impl Default for CountIn {
+    fn default() -> Self {
+        Self { frame_rate: 24.0 }
+    }
+}
+
+impl CountIn {
+    // Returns a duration in seconds to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::SelectFrameRate(frame_rate) => self.frame_rate = frame_rate,
+            Message::Copy(seconds) => return Some(seconds),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr_with(
+                "count-in-heading",
+                &[("time-signature", &time_signature)]
+            )),
+            text(tr("frame-rate")),
+            pick_list(FRAME_RATES, Some(self.frame_rate), Message::SelectFrameRate),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let Some(tempo) = tempo else {
+            return column![input_row, text(tr("count-in-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let count_in_rows = COUNT_IN_BARS.map(|bars| {
+            let seconds = count_in_seconds(tempo, time_signature, bars);
+            let label = match bars {
+                1 => tr("one-bar").to_string(),
+                bars => tr_with("bar-count", &[("bars", &bars)]),
+            };
+
+            row![
+                text(label).width(LABEL_WIDTH),
+                text(format!("{} s", round(seconds, ROUND_LIMIT))).width(100),
+                text(tr_with(
+                    "frame-count",
+                    &[("frames", &frames(seconds, self.frame_rate))]
+                ))
+                .width(100),
+                text(tr_with(
+                    "click-count",
+                    &[("clicks", &(time_signature.beats * bars))]
+                ))
+                .width(100),
+                button(tr("tool-copy")).on_press(Message::Copy(seconds)),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        });
+
+        column![
+            input_row,
+            Column::with_children(count_in_rows).spacing(SPACING / 2),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+fn count_in_seconds(tempo: f64, time_signature: TimeSignature, bars: u8) -> f64 {
+    f64::from(bars) * time_signature.seconds_per_bar(tempo)
+}
+
+// Rounded up, so a pre-roll set from it never cuts the count-in short. Float error is rounded off
+// first so durations that land on a frame don't gain one.
+fn frames(seconds: f64, frame_rate: f64) -> f64 {
+    round(seconds * frame_rate, ROUND_LIMIT).ceil()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_in_durations() {
+        // Two bars of 4/4 at 120 BPM is four seconds
+        let seconds = count_in_seconds(120.0, TimeSignature::default(), 2);
+        assert_eq!(seconds, 4.0);
+        assert_eq!(frames(seconds, 24.0), 96.0);
+        assert_eq!(frames(seconds, 29.97), 120.0);
+
+        let seconds = count_in_seconds(100.0, TimeSignature::default(), 1);
+        assert_eq!(frames(seconds, 25.0), 60.0);
+    }
+}
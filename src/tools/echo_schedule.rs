@@ -0,0 +1,187 @@
+use iced::widget::{button, column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{NoteValue, RhythmicModifier, NOTE_VALUES, RHYTHMIC_MODIFIER, ROUND_LIMIT, SPACING};
+
+const MAX_REPEATS: u32 = 64;
+const LABEL_WIDTH: u16 = 80;
+
+pub struct EchoSchedule {
+    note_value: NoteValue,
+    rhythmic_modifier: RhythmicModifier,
+    repeats_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectNoteValue(NoteValue),
+    SelectRhythmicModifier(RhythmicModifier),
+    StoreRepeats(String),
+    Copy(Vec<(f64, Position)>),
+}
+
+// A bar and the beat within it, both one-based like a DAW's ruler, with the beat keeping its
+// fraction
+pub type Position = (u64, f64);
+
+impl Default for EchoSchedule {
+    fn default() -> Self {
+        Self {
+            note_value: NoteValue::Quarter,
+            rhythmic_modifier: RhythmicModifier::Dotted,
+            repeats_text: "4".to_string(),
+        }
+    }
+}
+
+impl EchoSchedule {
+    // Returns each repeat's time in milliseconds and where it lands, to copy
+    pub fn update(&mut self, message: Message) -> Option<Vec<(f64, Position)>> {
+        match message {
+            Message::SelectNoteValue(note_value) => self.note_value = note_value,
+            Message::SelectRhythmicModifier(rhythmic_modifier) => {
+                self.rhythmic_modifier = rhythmic_modifier
+            }
+            Message::StoreRepeats(text) => self.repeats_text = text,
+            Message::Copy(list) => return Some(list),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let repeats = self.repeats();
+        let schedule = tempo.zip(repeats).map(|(tempo, repeats)| {
+            let delay = delay_ms(tempo, self.note_value, self.rhythmic_modifier);
+            schedule(delay, repeats, tempo, time_signature)
+        });
+
+        let input_row = row![
+            pick_list(NOTE_VALUES, Some(self.note_value), Message::SelectNoteValue),
+            pick_list(
+                RHYTHMIC_MODIFIER,
+                Some(self.rhythmic_modifier),
+                Message::SelectRhythmicModifier
+            ),
+            text(tr("echo-repeats")),
+            text_input("", &self.repeats_text)
+                .on_input(Message::StoreRepeats)
+                .width(60),
+            button(tr("tool-copy")).on_press_maybe(schedule.as_ref().map(|schedule| {
+                Message::Copy(
+                    schedule
+                        .iter()
+                        .map(|(ms, position)| (round(*ms, ROUND_LIMIT), *position))
+                        .collect(),
+                )
+            })),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let schedule_rows: Element<_> = match schedule {
+            Some(schedule) => Column::with_children(schedule.into_iter().enumerate().map(
+                |(index, (ms, position))| {
+                    row![
+                        text(tr_with("echo-repeat", &[("repeat", &(index + 1))]))
+                            .width(LABEL_WIDTH),
+                        text(format!("{} ms", round(ms, ROUND_LIMIT))).width(100),
+                        text(format!("{}:{}", position.0, position.1)),
+                    ]
+                    .spacing(SPACING)
+                    .into()
+                },
+            ))
+            .spacing(SPACING / 2)
+            .into(),
+            None if repeats.is_none() => {
+                text(tr_with("echo-repeats-prompt", &[("max", &MAX_REPEATS)])).into()
+            }
+            None => text(tr("echo-schedule-prompt")).into(),
+        };
+
+        column![
+            input_row,
+            text(tr_with(
+                "echo-schedule-heading",
+                &[("time-signature", &time_signature)]
+            )),
+            schedule_rows,
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+
+    fn repeats(&self) -> Option<u32> {
+        self.repeats_text
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|repeats| (1..=MAX_REPEATS).contains(repeats))
+    }
+}
+
+fn delay_ms(tempo: f64, note_value: NoteValue, rhythmic_modifier: RhythmicModifier) -> f64 {
+    60_000.0 / tempo * note_value.beats() * rhythmic_modifier.factor()
+}
+
+// Each repeat is a whole multiple of the delay, so nothing drifts from adding them up
+fn schedule(
+    delay: f64,
+    repeats: u32,
+    tempo: f64,
+    time_signature: TimeSignature,
+) -> Vec<(f64, Position)> {
+    (1..=repeats)
+        .map(|repeat| {
+            let ms = delay * f64::from(repeat);
+            (ms, bars_and_beats(ms, tempo, time_signature))
+        })
+        .collect()
+}
+
+// Shown as bars:beats, e.g. 2:1.5
+fn bars_and_beats(ms: f64, tempo: f64, time_signature: TimeSignature) -> Position {
+    let quarter_notes = ms * tempo / 60_000.0;
+    // Rounded first so triplets that land on a beat don't show as the end of the one before
+    let beats = round(
+        quarter_notes * f64::from(time_signature.note_value) / 4.0,
+        6,
+    );
+    let beats_per_bar = f64::from(time_signature.beats);
+    let bar = (beats / beats_per_bar).floor() as u64 + 1;
+    let beat = beats % beats_per_bar + 1.0;
+    (bar, round(beat, ROUND_LIMIT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_land_on_multiples_of_the_delay() {
+        // A dotted eighth at 120 BPM is 375 ms, three quarters of a beat
+        let delay = delay_ms(120.0, NoteValue::Eighth, RhythmicModifier::Dotted);
+        let repeats = schedule(delay, 6, 120.0, TimeSignature::default());
+        let positions: Vec<_> = repeats.iter().map(|(_, position)| *position).collect();
+        assert_eq!(
+            positions,
+            [
+                (1, 1.75),
+                (1, 2.5),
+                (1, 3.25),
+                (1, 4.0),
+                (1, 4.75),
+                (2, 1.5)
+            ]
+        );
+        assert_eq!(repeats[5].0, 2250.0);
+
+        let delay = delay_ms(100.0, NoteValue::Quarter, RhythmicModifier::Triplet);
+        let repeats = schedule(delay, 3, 100.0, TimeSignature::default());
+        assert_eq!(repeats[2].1, (1, 3.0));
+    }
+}
@@ -0,0 +1,85 @@
+use iced::widget::{button, column, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::tr;
+use crate::{ROUND_LIMIT, SPACING};
+
+// Note lengths, as denominators of a whole note, and what each sounds like
+const GATE_LENGTHS: [(u32, &str); 4] = [
+    (32, "gate-stutters"),
+    (16, "gate-tight"),
+    (8, "gate-choppy"),
+    (4, "gate-pumping"),
+];
+const RELEASE_TIMES: [(u32, &str); 5] = [
+    (16, "release-plucks"),
+    (8, "release-stabs"),
+    (4, "release-leads"),
+    (2, "release-pads"),
+    (1, "release-swells"),
+];
+const LABEL_WIDTH: u16 = 60;
+
+pub struct EnvelopeTimes;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Copy(f64),
+}
+
+impl EnvelopeTimes {
+    // Returns a duration in milliseconds to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::Copy(ms) => Some(ms),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let Some(tempo) = tempo else {
+            return text(tr("envelope-times-prompt")).into();
+        };
+
+        let time_rows = |times: &[(u32, &'static str)]| {
+            Column::with_children(times.iter().map(|(denominator, use_for)| {
+                let ms = note_ms(tempo, *denominator);
+                row![
+                    text(format!("1/{}", denominator)).width(LABEL_WIDTH),
+                    text(format!("{} ms", round(ms, ROUND_LIMIT))).width(100),
+                    text(tr(use_for)).width(120),
+                    button(tr("tool-copy")).on_press(Message::Copy(ms)),
+                ]
+                .spacing(SPACING)
+                .align_y(Alignment::Center)
+                .into()
+            }))
+            .spacing(SPACING / 2)
+        };
+
+        column![
+            text(tr("gate-lengths")),
+            time_rows(&GATE_LENGTHS),
+            text(tr("release-times")),
+            time_rows(&RELEASE_TIMES),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+fn note_ms(tempo: f64, denominator: u32) -> f64 {
+    60_000.0 / tempo * 4.0 / f64::from(denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_follow_the_tempo() {
+        assert_eq!(note_ms(120.0, 16), 125.0);
+        assert_eq!(note_ms(120.0, 2), 1000.0);
+        assert_eq!(note_ms(60.0, 1), 4000.0);
+    }
+}
@@ -0,0 +1,137 @@
+use iced::widget::{button, column, row, text, text_input};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{ROUND_LIMIT, SPACING};
+
+// The conventional threshold for a tail being inaudible
+const SILENCE_DB: f64 = -60.0;
+
+pub struct FeedbackDecay {
+    delay_text: String,
+    feedback_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreDelay(String),
+    StoreFeedback(String),
+    UseDelay(f64),
+}
+
+impl Default for FeedbackDecay {
+    fn default() -> Self {
+        Self {
+            delay_text: "500".to_string(),
+            feedback_text: "50".to_string(),
+        }
+    }
+}
+
+impl FeedbackDecay {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::StoreDelay(text) => self.delay_text = text,
+            Message::StoreFeedback(text) => self.feedback_text = text,
+            Message::UseDelay(delay) => self.delay_text = round(delay, ROUND_LIMIT).to_string(),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let quarter_note = tempo.map(|tempo| 60_000.0 / tempo);
+
+        let input_row = row![
+            text(tr("tool-delay-ms")),
+            text_input("", &self.delay_text)
+                .on_input(Message::StoreDelay)
+                .width(80),
+            button(tr("use-quarter-note")).on_press_maybe(quarter_note.map(Message::UseDelay)),
+            text(tr("feedback-percent")),
+            text_input("", &self.feedback_text)
+                .on_input(Message::StoreFeedback)
+                .width(60),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let delay = self.delay_text.trim().parse::<f64>().ok();
+        let feedback = self.feedback_text.trim().parse::<f64>().ok();
+
+        let result = match (delay, feedback) {
+            (Some(delay), Some(feedback)) if delay > 0.0 => match decay(delay, feedback / 100.0) {
+                Some(decay) => {
+                    let beats = quarter_note
+                        .map(|quarter_note| {
+                            format!(
+                                " ({})",
+                                tr_with(
+                                    "feedback-decay-beats",
+                                    &[("beats", &round(decay.time / quarter_note, 2))]
+                                )
+                            )
+                        })
+                        .unwrap_or_default();
+
+                    tr_with(
+                        "feedback-decay-result",
+                        &[
+                            ("repeats", &decay.audible_repeats),
+                            ("threshold", &SILENCE_DB),
+                            ("ms", &round(decay.time, ROUND_LIMIT)),
+                            ("beats", &beats),
+                        ],
+                    )
+                }
+                None => tr("feedback-out-of-range").to_string(),
+            },
+            _ => tr("feedback-decay-prompt").to_string(),
+        };
+
+        column![input_row, text(result)].spacing(SPACING).into()
+    }
+}
+
+struct Decay {
+    audible_repeats: u64,
+    // Milliseconds until the first repeat falls below the silence threshold
+    time: f64,
+}
+
+// Each repeat is quieter than the last by the feedback gain, i.e. 20·log10(feedback) dB
+fn decay(delay: f64, feedback: f64) -> Option<Decay> {
+    if !(feedback > 0.0 && feedback < 1.0) {
+        return None;
+    }
+
+    let db_per_repeat = 20.0 * feedback.log10();
+    let silent_repeat = (SILENCE_DB / db_per_repeat).ceil();
+
+    Some(Decay {
+        audible_repeats: silent_repeat as u64 - 1,
+        time: silent_repeat * delay,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_fade_below_silence() {
+        // Half gain loses about 6 dB a repeat, so the tenth repeat is the first below -60 dB
+        let decay = decay(250.0, 0.5).unwrap();
+        assert_eq!(decay.audible_repeats, 9);
+        assert_eq!(decay.time, 2500.0);
+
+        let decay = super::decay(100.0, 0.9).unwrap();
+        assert_eq!(decay.audible_repeats, 65);
+    }
+
+    #[test]
+    fn feedback_must_decay() {
+        assert!(decay(250.0, 0.0).is_none());
+        assert!(decay(250.0, 1.0).is_none());
+        assert!(decay(250.0, 1.5).is_none());
+    }
+}
@@ -0,0 +1,110 @@
+use iced::widget::{button, column, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{ROUND_LIMIT, SPACING};
+
+// Fractions of a bar, as their denominators, that follow the tempo, each with what it's used for
+const BAR_DIVISIONS: [(u32, &str); 3] = [
+    (192, "offset-humanization"),
+    (128, "offset-flams"),
+    (96, "offset-strum-spreads"),
+];
+// Fixed offsets, which sound the same at any tempo
+const FIXED_OFFSETS_MS: [(f64, &str); 6] = [
+    (5.0, "offset-humanization"),
+    (10.0, "offset-humanization"),
+    (15.0, "offset-flams"),
+    (20.0, "offset-flams"),
+    (25.0, "offset-strum-spreads"),
+    (30.0, "offset-strum-spreads"),
+];
+const LABEL_WIDTH: u16 = 120;
+
+pub struct FlamOffsets;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Copy(f64),
+}
+
+impl FlamOffsets {
+    // Returns a duration in milliseconds to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::Copy(ms) => Some(ms),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let offset_row = |label: String, ms: f64, use_for: &'static str| {
+            row![
+                text(label).width(LABEL_WIDTH),
+                text(format!("{} ms", round(ms, ROUND_LIMIT))).width(100),
+                text(tr(use_for)).width(120),
+                button(tr("tool-copy")).on_press(Message::Copy(ms)),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        };
+
+        let tempo_rows: Element<_> = match tempo {
+            Some(tempo) => {
+                Column::with_children(bar_offsets(tempo, time_signature).into_iter().map(
+                    |(division, ms, use_for)| {
+                        offset_row(
+                            tr_with("fraction-of-bar", &[("fraction", &division)]),
+                            ms,
+                            use_for,
+                        )
+                    },
+                ))
+                .spacing(SPACING / 2)
+                .into()
+            }
+            None => text(tr("flam-offsets-prompt")).into(),
+        };
+
+        let fixed_rows = FIXED_OFFSETS_MS
+            .map(|(ms, use_for)| offset_row(tr("offset-fixed").to_string(), ms, use_for));
+
+        column![
+            text(tr_with(
+                "flam-offsets-heading",
+                &[("time-signature", &time_signature)]
+            )),
+            tempo_rows,
+            Column::with_children(fixed_rows).spacing(SPACING / 2),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+fn bar_offsets(tempo: f64, time_signature: TimeSignature) -> Vec<(u32, f64, &'static str)> {
+    let ms_per_bar = time_signature.seconds_per_bar(tempo) * 1000.0;
+
+    BAR_DIVISIONS
+        .iter()
+        .map(|(division, use_for)| (*division, ms_per_bar / f64::from(*division), *use_for))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bar_offsets_follow_the_tempo() {
+        // A 4/4 bar at 120 BPM lasts two seconds
+        let offsets = bar_offsets(120.0, TimeSignature::default());
+        let ms: Vec<_> = offsets
+            .iter()
+            .map(|(_, ms, _)| round(*ms, ROUND_LIMIT))
+            .collect();
+        assert_eq!(ms, [10.417, 15.625, 20.833]);
+    }
+}
@@ -0,0 +1,162 @@
+use iced::widget::{button, column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{ROUND_LIMIT, SPACING};
+
+// Common sequencer and DAW resolutions
+const PPQS: [u32; 4] = [96, 480, 960, 1920];
+const LABEL_WIDTH: u16 = 60;
+
+pub struct GrooveOffsets {
+    swing_text: String,
+    ppq: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreSwing(String),
+    SelectPpq(u32),
+    Copy(String),
+}
+
+impl Default for GrooveOffsets {
+    fn default() -> Self {
+        Self {
+            // Roughly MPC-style 16th swing
+            swing_text: "58".to_string(),
+            ppq: 960,
+        }
+    }
+}
+
+impl GrooveOffsets {
+    // Returns the CSV to copy
+    pub fn update(&mut self, message: Message) -> Option<String> {
+        match message {
+            Message::StoreSwing(text) => self.swing_text = text,
+            Message::SelectPpq(ppq) => self.ppq = ppq,
+            Message::Copy(csv) => return Some(csv),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let swing = self
+            .swing_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|swing| (50.0..100.0).contains(swing));
+
+        let offsets = match (tempo, swing) {
+            (Some(tempo), Some(swing)) => Some(offsets(tempo, swing, self.ppq, time_signature)),
+            _ => None,
+        };
+
+        let input_row = row![
+            text(tr("swing-percent")),
+            text_input("", &self.swing_text)
+                .on_input(Message::StoreSwing)
+                .width(60),
+            text("PPQ"),
+            pick_list(PPQS, Some(self.ppq), Message::SelectPpq),
+            button(tr("copy-csv"))
+                .on_press_maybe(offsets.as_ref().map(|offsets| Message::Copy(csv(offsets)))),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let Some(offsets) = offsets else {
+            let hint = match tempo {
+                Some(_) => tr("swing-range"),
+                None => tr("groove-offsets-prompt"),
+            };
+            return column![input_row, text(hint)].spacing(SPACING).into();
+        };
+
+        let offset_rows = offsets.iter().map(|offset| {
+            row![
+                text(tr_with("sixteenth-step", &[("step", &offset.step)])).width(LABEL_WIDTH),
+                text(format!("{:+} ms", round(offset.milliseconds, ROUND_LIMIT))).width(100),
+                text(tr_with(
+                    "tick-offset",
+                    &[("ticks", &format!("{:+}", offset.ticks))]
+                )),
+            ]
+            .spacing(SPACING)
+            .into()
+        });
+
+        column![
+            input_row,
+            Column::with_children(offset_rows).spacing(SPACING / 2)
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+struct Offset {
+    step: usize,
+    milliseconds: f64,
+    ticks: i64,
+}
+
+// Swing is where the off-beat 16th lands within its 8th note, so 50% is straight and 66.7% is a
+// triplet feel. Only the off-beats move; one bar's worth of 16ths is listed.
+fn offsets(tempo: f64, swing: f64, ppq: u32, time_signature: TimeSignature) -> Vec<Offset> {
+    let steps = (time_signature.quarter_notes_per_bar() * 4.0).round() as usize;
+    let offset_beats = (swing / 100.0 - 0.5) * 0.5;
+
+    (0..steps)
+        .map(|index| {
+            let beats = if index % 2 == 1 { offset_beats } else { 0.0 };
+
+            Offset {
+                step: index + 1,
+                milliseconds: beats * 60_000.0 / tempo,
+                ticks: (beats * f64::from(ppq)).round() as i64,
+            }
+        })
+        .collect()
+}
+
+fn csv(offsets: &[Offset]) -> String {
+    std::iter::once("step,offset_ms,offset_ticks".to_string())
+        .chain(offsets.iter().map(|offset| {
+            format!(
+                "{},{},{}",
+                offset.step,
+                round(offset.milliseconds, ROUND_LIMIT),
+                offset.ticks
+            )
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triplet_swing_moves_off_beats_onto_the_last_triplet() {
+        let offsets = offsets(120.0, 200.0 / 3.0, 960, TimeSignature::default());
+        assert_eq!(offsets.len(), 16);
+        assert_eq!(offsets[0].milliseconds, 0.0);
+        assert_eq!(round(offsets[1].milliseconds, 3), 41.667);
+        assert_eq!(offsets[1].ticks, 80);
+        assert_eq!(offsets[2].ticks, 0);
+    }
+
+    #[test]
+    fn straight_swing_has_no_offsets() {
+        let offsets = offsets(120.0, 50.0, 480, TimeSignature::default());
+        assert!(offsets.iter().all(|offset| offset.ticks == 0));
+        assert!(csv(&offsets).starts_with("step,offset_ms,offset_ticks\n1,0,0\n2,0,0"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::RangeInclusive;
+
+use iced::widget::{button, column, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{RhythmicModifier, RHYTHMIC_MODIFIER, ROUND_LIMIT, SPACING};
+
+// Offsets in this window fuse with the direct sound into one wider image instead of an echo
+pub const HAAS_ZONE_MS: RangeInclusive<f64> = 1.0..=35.0;
+
+// Note value denominators, reaching past the table's 1/128 where Haas-sized values live at most
+// tempos
+const DIVISIONS: [u32; 6] = [16, 32, 64, 128, 256, 512];
+const LABEL_WIDTH: u16 = 140;
+
+pub struct Haas;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Copy(f64),
+}
+
+impl Haas {
+    // Returns the offset to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::Copy(offset) => Some(offset),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let Some(tempo) = tempo else {
+            return text(tr("haas-prompt")).into();
+        };
+
+        let offsets = offsets(tempo);
+
+        let header = text(tr_with(
+            "haas-heading",
+            &[("min", HAAS_ZONE_MS.start()), ("max", HAAS_ZONE_MS.end())],
+        ));
+
+        if offsets.is_empty() {
+            return column![header, text(tr("haas-none"))]
+                .spacing(SPACING)
+                .into();
+        }
+
+        let offset_rows = offsets
+            .into_iter()
+            .map(|(division, rhythmic_modifier, offset)| {
+                let offset = round(offset, ROUND_LIMIT);
+                let label = match rhythmic_modifier {
+                    RhythmicModifier::Normal => format!("1/{}", division),
+                    rhythmic_modifier => format!("1/{} {}", division, rhythmic_modifier),
+                };
+
+                row![
+                    text(label).width(LABEL_WIDTH),
+                    text(format!("{} ms", offset)).width(100),
+                    button(tr("tool-copy")).on_press(Message::Copy(offset)),
+                ]
+                .spacing(SPACING)
+                .align_y(Alignment::Center)
+                .into()
+            });
+
+        column![
+            header,
+            Column::with_children(offset_rows).spacing(SPACING / 2)
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+// The subdivisions at this tempo that fall inside the Haas zone, in ms
+fn offsets(tempo: f64) -> Vec<(u32, RhythmicModifier, f64)> {
+    DIVISIONS
+        .iter()
+        .flat_map(|division| {
+            RHYTHMIC_MODIFIER.map(|rhythmic_modifier| {
+                let offset =
+                    60_000.0 / tempo * 4.0 / f64::from(*division) * rhythmic_modifier.factor();
+                (*division, rhythmic_modifier, offset)
+            })
+        })
+        .filter(|(_, _, offset)| HAAS_ZONE_MS.contains(offset))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_stay_in_the_zone() {
+        // At 120 BPM a 1/64 is 31.25 ms, and everything shorter fits down to a 1/512 triplet
+        let offsets = offsets(120.0);
+        assert_eq!(offsets.len(), 11);
+        assert_eq!(offsets[0], (64, RhythmicModifier::Normal, 31.25));
+        assert!(offsets
+            .iter()
+            .all(|(_, _, offset)| HAAS_ZONE_MS.contains(offset)));
+    }
+}
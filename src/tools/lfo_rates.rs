@@ -0,0 +1,87 @@
+use iced::widget::{button, column, row, text, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{ROUND_LIMIT, SPACING};
+
+// Cycle lengths in bars
+const CYCLE_LENGTHS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const LABEL_WIDTH: u16 = 120;
+
+pub struct LfoRates;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Copy(f64),
+}
+
+impl LfoRates {
+    // Returns the rate to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::Copy(rate) => Some(rate),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let Some(tempo) = tempo else {
+            return text(tr("lfo-rates-prompt")).into();
+        };
+
+        let seconds_per_bar = time_signature.seconds_per_bar(tempo);
+
+        let rate_rows = CYCLE_LENGTHS.map(|bars| {
+            let (rate, period) = cycle(bars, seconds_per_bar);
+            let rate = round(rate, ROUND_LIMIT);
+            let label = if bars < 1.0 {
+                tr_with("fraction-of-bar", &[("fraction", &(1.0 / bars))])
+            } else if bars == 1.0 {
+                tr("one-bar").to_string()
+            } else {
+                tr_with("bar-count", &[("bars", &bars)])
+            };
+
+            row![
+                text(label).width(LABEL_WIDTH),
+                text(format!("{} Hz", rate)).width(100),
+                text(format!("{} ms", round(period, ROUND_LIMIT))).width(100),
+                button(tr("tool-copy")).on_press(Message::Copy(rate)),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        });
+
+        column![
+            text(tr_with(
+                "lfo-rates-heading",
+                &[("time-signature", &time_signature)]
+            )),
+            Column::with_children(rate_rows).spacing(SPACING / 2)
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+// The rate in Hz and period in ms of an LFO that cycles once every `bars`
+fn cycle(bars: f64, seconds_per_bar: f64) -> (f64, f64) {
+    let period = bars * seconds_per_bar;
+    (1.0 / period, period * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates_follow_the_bar() {
+        // A 4/4 bar at 120 BPM lasts two seconds
+        let seconds_per_bar = TimeSignature::default().seconds_per_bar(120.0);
+        assert_eq!(cycle(1.0, seconds_per_bar), (0.5, 2000.0));
+        assert_eq!(cycle(0.25, seconds_per_bar), (2.0, 500.0));
+        assert_eq!(cycle(8.0, seconds_per_bar), (0.0625, 16_000.0));
+    }
+}
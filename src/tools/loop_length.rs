@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+use iced::widget::{button, column, pick_list, row, text, text_input};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{ROUND_LIMIT, SPACING};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    Seconds,
+    Milliseconds,
+}
+
+const DURATION_UNITS: [DurationUnit; 2] = [DurationUnit::Seconds, DurationUnit::Milliseconds];
+
+impl Display for DurationUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationUnit::Seconds => write!(f, "s"),
+            DurationUnit::Milliseconds => write!(f, "ms"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    Beats,
+    Bars,
+}
+
+const LENGTH_UNITS: [LengthUnit; 2] = [LengthUnit::Beats, LengthUnit::Bars];
+
+impl Display for LengthUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LengthUnit::Beats => write!(f, "{}", tr("length-beats")),
+            LengthUnit::Bars => write!(f, "{}", tr("length-bars")),
+        }
+    }
+}
+
+pub struct LoopLength {
+    duration_text: String,
+    duration_unit: DurationUnit,
+    length_text: String,
+    length_unit: LengthUnit,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreDuration(String),
+    SelectDurationUnit(DurationUnit),
+    StoreLength(String),
+    SelectLengthUnit(LengthUnit),
+    Load(f64),
+}
+
+impl Default for LoopLength {
+    fn default() -> Self {
+        Self {
+            duration_text: String::new(),
+            duration_unit: DurationUnit::Seconds,
+            // Sample-pack loops are most often a bar or a few
+            length_text: "4".to_string(),
+            length_unit: LengthUnit::Bars,
+        }
+    }
+}
+
+impl LoopLength {
+    // Returns a tempo for the main table to switch to
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::StoreDuration(text) => self.duration_text = text,
+            Message::SelectDurationUnit(unit) => self.duration_unit = unit,
+            Message::StoreLength(text) => self.length_text = text,
+            Message::SelectLengthUnit(unit) => self.length_unit = unit,
+            Message::Load(tempo) => return Some(tempo),
+        }
+
+        None
+    }
+
+    pub fn view(&self, time_signature: TimeSignature) -> Element<'_, Message> {
+        let tempo = self.tempo(time_signature);
+
+        let input_row = row![
+            text(tr("loop-length")),
+            text_input("", &self.duration_text)
+                .on_input(Message::StoreDuration)
+                .width(80),
+            pick_list(
+                DURATION_UNITS,
+                Some(self.duration_unit),
+                Message::SelectDurationUnit
+            ),
+            text(tr("loop-holding")),
+            text_input("", &self.length_text)
+                .on_input(Message::StoreLength)
+                .width(60),
+            pick_list(
+                LENGTH_UNITS,
+                Some(self.length_unit),
+                Message::SelectLengthUnit
+            ),
+            button(tr("tool-load")).on_press_maybe(tempo.map(Message::Load)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let result = match tempo {
+            Some(tempo) if self.length_unit == LengthUnit::Bars => tr_with(
+                "loop-tempo-in",
+                &[
+                    ("tempo", &round(tempo, ROUND_LIMIT)),
+                    ("time-signature", &time_signature),
+                ],
+            ),
+            Some(tempo) => tr_with("loop-tempo", &[("tempo", &round(tempo, ROUND_LIMIT))]),
+            None => tr("loop-length-prompt").to_string(),
+        };
+
+        column![input_row, text(result)].spacing(SPACING).into()
+    }
+
+    fn tempo(&self, time_signature: TimeSignature) -> Option<f64> {
+        let parse = |text: &str| {
+            text.trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value > 0.0)
+        };
+
+        let seconds = match self.duration_unit {
+            DurationUnit::Seconds => parse(&self.duration_text)?,
+            DurationUnit::Milliseconds => parse(&self.duration_text)? / 1000.0,
+        };
+
+        let quarter_notes = match self.length_unit {
+            LengthUnit::Beats => parse(&self.length_text)?,
+            LengthUnit::Bars => parse(&self.length_text)? * time_signature.quarter_notes_per_bar(),
+        };
+
+        Some(loop_tempo(seconds, quarter_notes))
+    }
+}
+
+fn loop_tempo(seconds: f64, quarter_notes: f64) -> f64 {
+    quarter_notes * 60.0 / seconds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tempo_from_loop_length() {
+        assert_eq!(loop_tempo(2.0, 4.0), 120.0);
+
+        let mut loop_length = LoopLength::default();
+        let four_four = TimeSignature::default();
+
+        loop_length.update(Message::StoreDuration("7.5".to_string()));
+        assert_eq!(loop_length.tempo(four_four), Some(128.0));
+
+        loop_length.update(Message::SelectDurationUnit(DurationUnit::Milliseconds));
+        loop_length.update(Message::StoreDuration("1875".to_string()));
+        loop_length.update(Message::SelectLengthUnit(LengthUnit::Beats));
+        assert_eq!(loop_length.tempo(four_four), Some(128.0));
+
+        loop_length.update(Message::StoreDuration("0".to_string()));
+        assert_eq!(loop_length.tempo(four_four), None);
+    }
+}
@@ -0,0 +1,150 @@
+use iced::widget::{button, checkbox, column, pick_list, row, text};
+use iced::{Alignment, Element};
+
+use crate::i18n::{tr, tr_with};
+use crate::midi::{self, ClockCommand, ClockOutput};
+use crate::SPACING;
+
+pub struct MidiClock {
+    port_names: Vec<String>,
+    port_name: Option<String>,
+    send_mtc: bool,
+    output: Option<ClockOutput>,
+    is_running: bool,
+    // The tempo the clock was last told about, so unchanged tempos aren't resent
+    tempo: Option<f64>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    RefreshPorts,
+    SelectPort(String),
+    Disconnect,
+    SendMtc(bool),
+    Start,
+    Stop,
+}
+
+impl Default for MidiClock {
+    fn default() -> Self {
+        Self {
+            port_names: midi::output_port_names(),
+            port_name: None,
+            send_mtc: false,
+            output: None,
+            is_running: false,
+            tempo: None,
+            error: None,
+        }
+    }
+}
+
+impl MidiClock {
+    pub fn update(&mut self, message: Message, tempo: Option<f64>) {
+        match message {
+            Message::RefreshPorts => self.port_names = midi::output_port_names(),
+            Message::SelectPort(port_name) => {
+                self.port_name = Some(port_name);
+                self.connect(tempo);
+            }
+            Message::Disconnect => {
+                self.stop();
+                self.port_name = None;
+                self.output = None;
+            }
+            Message::SendMtc(send_mtc) => {
+                self.send_mtc = send_mtc;
+                self.connect(tempo);
+            }
+            Message::Start => {
+                if let Some(output) = &self.output {
+                    output.send(ClockCommand::Start);
+                    self.is_running = true;
+                }
+            }
+            Message::Stop => self.stop(),
+        }
+    }
+
+    // Called after every app update so the clock follows tapped, typed, and nudged tempos alike
+    pub fn set_tempo(&mut self, tempo: Option<f64>) {
+        if let (Some(output), Some(tempo)) = (&self.output, tempo) {
+            if self.tempo != Some(tempo) {
+                output.send(ClockCommand::SetTempo(tempo));
+                self.tempo = Some(tempo);
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if !midi::IS_SUPPORTED {
+            return text(tr_with(
+                "tool-needs-feature",
+                &[("tool", &tr("tool-midi-clock")), ("feature", &"midi")],
+            ))
+            .into();
+        }
+
+        let port_row = row![
+            text(tr("midi-output")),
+            pick_list(
+                self.port_names.as_slice(),
+                self.port_name.clone(),
+                Message::SelectPort
+            ),
+            button(tr("tool-refresh")).on_press(Message::RefreshPorts),
+            button(tr("tool-disconnect"))
+                .on_press_maybe(self.port_name.as_ref().map(|_| Message::Disconnect)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let is_connected = self.output.is_some();
+
+        let status = match (&self.error, is_connected, self.is_running) {
+            (Some(error), _, _) => error.clone(),
+            (None, false, _) => tr("midi-clock-prompt").to_string(),
+            (None, true, false) => tr("midi-clock-stopped").to_string(),
+            (None, true, true) => tr("midi-clock-running").to_string(),
+        };
+
+        let transport_row = row![
+            button(tr("transport-start")).on_press_maybe(is_connected.then_some(Message::Start)),
+            button(tr("transport-stop"))
+                .on_press_maybe((is_connected && self.is_running).then_some(Message::Stop)),
+            checkbox(tr("send-mtc"), self.send_mtc).on_toggle(Message::SendMtc),
+            text(status),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        column![port_row, transport_row].spacing(SPACING).into()
+    }
+
+    // Reconnecting restarts the clock thread, so the transport is left stopped
+    fn connect(&mut self, tempo: Option<f64>) {
+        self.stop();
+        self.output = None;
+
+        let (Some(port_name), Some(tempo)) = (&self.port_name, tempo.or(self.tempo)) else {
+            return;
+        };
+
+        match midi::clock_output(port_name, tempo, self.send_mtc) {
+            Ok(output) => {
+                self.output = Some(output);
+                self.tempo = Some(tempo);
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    fn stop(&mut self) {
+        if let (Some(output), true) = (&self.output, self.is_running) {
+            output.send(ClockCommand::Stop);
+        }
+        self.is_running = false;
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use iced::widget::{button, column, pick_list, row, text};
+use iced::{Alignment, Element, Subscription};
+
+use crate::i18n::{tr, tr_with};
+use crate::{midi, SPACING};
+
+// Footswitches send 127 when pressed and 0 when released; only the press should tap
+const CONTROL_CHANGE_PRESSED: u8 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Trigger {
+    Note(u8),
+    ControlChange(u8),
+}
+
+impl Trigger {
+    // Channel is ignored so any pad or pedal on the selected port works
+    fn from_message(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [status, note, velocity] if status & 0xF0 == 0x90 && *velocity > 0 => {
+                Some(Trigger::Note(*note))
+            }
+            [status, controller, value]
+                if status & 0xF0 == 0xB0 && *value >= CONTROL_CHANGE_PRESSED =>
+            {
+                Some(Trigger::ControlChange(*controller))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Trigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trigger::Note(note) => write!(f, "{}", tr_with("trigger-note", &[("note", note)])),
+            Trigger::ControlChange(controller) => write!(f, "CC {}", controller),
+        }
+    }
+}
+
+pub struct MidiTap {
+    port_names: Vec<String>,
+    port_name: Option<String>,
+    trigger: Trigger,
+    is_learning: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    RefreshPorts,
+    SelectPort(String),
+    Disconnect,
+    Learn,
+    MidiReceived(Vec<u8>),
+}
+
+impl Default for MidiTap {
+    fn default() -> Self {
+        Self {
+            port_names: midi::input_port_names(),
+            port_name: None,
+            // C1, the usual kick pad note
+            trigger: Trigger::Note(36),
+            is_learning: false,
+        }
+    }
+}
+
+impl MidiTap {
+    // Returns whether the message should register a tap
+    pub fn update(&mut self, message: Message) -> bool {
+        match message {
+            Message::RefreshPorts => self.port_names = midi::input_port_names(),
+            Message::SelectPort(port_name) => self.port_name = Some(port_name),
+            Message::Disconnect => self.port_name = None,
+            Message::Learn => self.is_learning = true,
+            Message::MidiReceived(bytes) => {
+                let Some(trigger) = Trigger::from_message(&bytes) else {
+                    return false;
+                };
+
+                if self.is_learning {
+                    self.trigger = trigger;
+                    self.is_learning = false;
+                    return false;
+                }
+
+                return trigger == self.trigger;
+            }
+        }
+
+        false
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        if !midi::IS_SUPPORTED {
+            return text(tr_with(
+                "tool-needs-feature",
+                &[("tool", &tr("tool-midi-tap")), ("feature", &"midi")],
+            ))
+            .into();
+        }
+
+        let port_row = row![
+            text(tr("midi-input")),
+            pick_list(
+                self.port_names.as_slice(),
+                self.port_name.clone(),
+                Message::SelectPort
+            ),
+            button(tr("tool-refresh")).on_press(Message::RefreshPorts),
+            button(tr("tool-disconnect"))
+                .on_press_maybe(self.port_name.as_ref().map(|_| Message::Disconnect)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let trigger_text = if self.is_learning {
+            tr("trigger-learning").to_string()
+        } else {
+            tr_with("trigger", &[("trigger", &self.trigger)])
+        };
+
+        let trigger_row = row![
+            text(trigger_text),
+            button(tr("learn")).on_press_maybe((!self.is_learning).then_some(Message::Learn)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        column![port_row, trigger_row].spacing(SPACING).into()
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        match &self.port_name {
+            Some(port_name) => midi::listen(port_name.clone()).map(Message::MidiReceived),
+            None => Subscription::none(),
+        }
+    }
+}
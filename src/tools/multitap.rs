@@ -0,0 +1,256 @@
+use iced::mouse::Cursor;
+use iced::widget::canvas::{self, Frame, Geometry, Path, Stroke};
+use iced::widget::{button, canvas as canvas_widget, column, pick_list, row, text, Column};
+use iced::{Alignment, Element, Length, Point, Rectangle, Renderer, Theme};
+use round::round;
+
+use super::buffer_latency::SAMPLE_RATES;
+use crate::i18n::{tr, tr_with};
+use crate::{NoteValue, RhythmicModifier, NOTE_VALUES, RHYTHMIC_MODIFIER, ROUND_LIMIT, SPACING};
+
+const LABEL_WIDTH: u16 = 140;
+const TIMELINE_HEIGHT: u16 = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Step {
+    note_value: NoteValue,
+    rhythmic_modifier: RhythmicModifier,
+}
+
+impl Step {
+    fn milliseconds(&self, tempo: f64) -> f64 {
+        60_000.0 / tempo * self.note_value.beats() * self.rhythmic_modifier.factor()
+    }
+}
+
+pub struct Multitap {
+    steps: Vec<Step>,
+    note_value: NoteValue,
+    rhythmic_modifier: RhythmicModifier,
+    sample_rate: u32,
+    show_timeline: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectNoteValue(NoteValue),
+    SelectRhythmicModifier(RhythmicModifier),
+    AddStep,
+    RemoveStep(usize),
+    SelectSampleRate(u32),
+    ToggleTimeline,
+    Copy(Vec<f64>),
+}
+
+impl Default for Multitap {
+    fn default() -> Self {
+        Self {
+            steps: vec![
+                Step {
+                    note_value: NoteValue::Eighth,
+                    rhythmic_modifier: RhythmicModifier::Normal,
+                },
+                Step {
+                    note_value: NoteValue::Eighth,
+                    rhythmic_modifier: RhythmicModifier::Dotted,
+                },
+                Step {
+                    note_value: NoteValue::Quarter,
+                    rhythmic_modifier: RhythmicModifier::Triplet,
+                },
+            ],
+            note_value: NoteValue::Eighth,
+            rhythmic_modifier: RhythmicModifier::Normal,
+            sample_rate: 48_000,
+            show_timeline: true,
+        }
+    }
+}
+
+impl Multitap {
+    // Returns the tap times to copy
+    pub fn update(&mut self, message: Message) -> Option<Vec<f64>> {
+        match message {
+            Message::SelectNoteValue(note_value) => self.note_value = note_value,
+            Message::SelectRhythmicModifier(rhythmic_modifier) => {
+                self.rhythmic_modifier = rhythmic_modifier
+            }
+            Message::AddStep => self.steps.push(Step {
+                note_value: self.note_value,
+                rhythmic_modifier: self.rhythmic_modifier,
+            }),
+            Message::RemoveStep(index) => {
+                self.steps.remove(index);
+            }
+            Message::SelectSampleRate(sample_rate) => self.sample_rate = sample_rate,
+            Message::ToggleTimeline => self.show_timeline = !self.show_timeline,
+            Message::Copy(list) => return Some(list),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let tap_times = tempo.map(|tempo| tap_times(&self.steps, tempo));
+
+        let input_row = row![
+            pick_list(NOTE_VALUES, Some(self.note_value), Message::SelectNoteValue),
+            pick_list(
+                RHYTHMIC_MODIFIER,
+                Some(self.rhythmic_modifier),
+                Message::SelectRhythmicModifier
+            ),
+            button(tr("add-tap")).on_press(Message::AddStep),
+            text(tr("tool-sample-rate")),
+            pick_list(
+                SAMPLE_RATES,
+                Some(self.sample_rate),
+                Message::SelectSampleRate
+            ),
+            button(if self.show_timeline {
+                tr("hide-timeline")
+            } else {
+                tr("timeline")
+            })
+            .on_press(Message::ToggleTimeline),
+            button(tr("tool-copy")).on_press_maybe(tap_times.as_ref().map(|tap_times| {
+                Message::Copy(
+                    tap_times
+                        .iter()
+                        .map(|tap_time| round(*tap_time, ROUND_LIMIT))
+                        .collect(),
+                )
+            })),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let step_rows = self.steps.iter().enumerate().map(|(index, step)| {
+            let time = match &tap_times {
+                Some(tap_times) => {
+                    let milliseconds = tap_times[index];
+                    let samples = (milliseconds * f64::from(self.sample_rate) / 1000.0).round();
+                    tr_with(
+                        "tap-time",
+                        &[
+                            ("ms", &round(milliseconds, ROUND_LIMIT)),
+                            ("samples", &samples),
+                        ],
+                    )
+                }
+                None => String::new(),
+            };
+
+            row![
+                text(tr_with(
+                    "tap-step",
+                    &[
+                        ("tap", &(index + 1)),
+                        ("note", &step.note_value),
+                        ("modifier", &step.rhythmic_modifier),
+                    ],
+                ))
+                .width(LABEL_WIDTH),
+                text(time).width(240),
+                button(tr("remove")).on_press(Message::RemoveStep(index)),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        });
+
+        let mut column = column![
+            input_row,
+            Column::with_children(step_rows).spacing(SPACING / 2)
+        ]
+        .spacing(SPACING);
+
+        match tap_times {
+            Some(tap_times) if self.show_timeline && !tap_times.is_empty() => {
+                column = column.push(
+                    canvas_widget(Timeline { tap_times })
+                        .width(Length::Fill)
+                        .height(TIMELINE_HEIGHT),
+                );
+            }
+            None => column = column.push(text(tr("multitap-prompt"))),
+            _ => {}
+        }
+
+        column.into()
+    }
+}
+
+// Each tap follows the one before it, so the times are a running total
+fn tap_times(steps: &[Step], tempo: f64) -> Vec<f64> {
+    steps
+        .iter()
+        .scan(0.0, |time, step| {
+            *time += step.milliseconds(tempo);
+            Some(*time)
+        })
+        .collect()
+}
+
+// The dry signal at the left edge and each tap placed proportionally up to the last one
+struct Timeline {
+    tap_times: Vec<f64>,
+}
+
+impl<Message> canvas::Program<Message> for Timeline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let palette = theme.extended_palette();
+        let length = self.tap_times.last().copied().unwrap_or(1.0);
+        let middle = frame.height() / 2.0;
+        // Leaves room for the last tick's stroke
+        let width = frame.width() - 2.0;
+
+        frame.stroke(
+            &Path::line(Point::new(0.0, middle), Point::new(width, middle)),
+            Stroke::default()
+                .with_color(palette.background.strong.color)
+                .with_width(1.0),
+        );
+
+        let ticks = std::iter::once((0.0, palette.secondary.base.color)).chain(
+            self.tap_times
+                .iter()
+                .map(|tap_time| (tap_time / length, palette.primary.base.color)),
+        );
+
+        for (position, color) in ticks {
+            let x = 1.0 + position as f32 * width;
+            frame.stroke(
+                &Path::line(Point::new(x, 0.0), Point::new(x, frame.height())),
+                Stroke::default().with_color(color).with_width(2.0),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tap_times_accumulate() {
+        let steps = Multitap::default().steps;
+        let tap_times: Vec<_> = tap_times(&steps, 120.0)
+            .into_iter()
+            .map(|tap_time| round(tap_time, 3))
+            .collect();
+        assert_eq!(tap_times, [250.0, 625.0, 958.333]);
+    }
+}
@@ -0,0 +1,148 @@
+use iced::widget::{button, column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{NoteValue, RhythmicModifier, NOTE_VALUES, ROUND_LIMIT, SPACING};
+
+const LABEL_WIDTH: u16 = 160;
+
+pub struct PingPong {
+    note_value: NoteValue,
+    haas_offset_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectNoteValue(NoteValue),
+    StoreHaasOffset(String),
+    Copy(f64, f64),
+}
+
+impl Default for PingPong {
+    fn default() -> Self {
+        Self {
+            note_value: NoteValue::Eighth,
+            // Comfortably inside the window where both sides still fuse into one image
+            haas_offset_text: "15".to_string(),
+        }
+    }
+}
+
+impl PingPong {
+    // Returns the left and right times to copy
+    pub fn update(&mut self, message: Message) -> Option<(f64, f64)> {
+        match message {
+            Message::SelectNoteValue(note_value) => self.note_value = note_value,
+            Message::StoreHaasOffset(text) => self.haas_offset_text = text,
+            Message::Copy(left, right) => return Some((left, right)),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("base-note")),
+            pick_list(NOTE_VALUES, Some(self.note_value), Message::SelectNoteValue),
+            text(tr("haas-offset-ms")),
+            text_input("", &self.haas_offset_text)
+                .on_input(Message::StoreHaasOffset)
+                .width(60),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let Some(tempo) = tempo else {
+            return column![input_row, text(tr("ping-pong-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let base = 60_000.0 / tempo * self.note_value.beats();
+        let haas_offset = self
+            .haas_offset_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|offset| *offset > 0.0);
+
+        let pair_rows = pairs(base, haas_offset)
+            .into_iter()
+            .map(|(label, left, right)| {
+                let (left, right) = (round(left, ROUND_LIMIT), round(right, ROUND_LIMIT));
+
+                row![
+                    text(label).width(LABEL_WIDTH),
+                    text(tr_with("left-ms", &[("ms", &left)])).width(100),
+                    text(tr_with("right-ms", &[("ms", &right)])).width(100),
+                    button(tr("tool-copy")).on_press(Message::Copy(left, right)),
+                ]
+                .spacing(SPACING)
+                .align_y(Alignment::Center)
+                .into()
+            });
+
+        Column::with_children(std::iter::once(input_row.into()).chain(pair_rows))
+            .spacing(SPACING)
+            .into()
+    }
+}
+
+// Left and right times built on the base note, in ms
+fn pairs(base: f64, haas_offset: Option<f64>) -> Vec<(&'static str, f64, f64)> {
+    let mut pairs = vec![
+        (tr("pair-ping-pong"), base, base * 2.0),
+        (
+            tr("pair-straight-dotted"),
+            base,
+            base * RhythmicModifier::Dotted.factor(),
+        ),
+        (
+            tr("pair-straight-triplet"),
+            base,
+            base * RhythmicModifier::Triplet.factor(),
+        ),
+    ];
+
+    if let Some(offset) = haas_offset {
+        pairs.push((tr("pair-haas-widening"), base, base + offset));
+
+        if offset < base {
+            pairs.push((tr("pair-haas-spread"), base - offset, base + offset));
+        }
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_offset_the_right_side() {
+        // An eighth at 120 BPM
+        let pairs = pairs(250.0, Some(15.0));
+        let times: Vec<_> = pairs
+            .iter()
+            .map(|(_, left, right)| (*left, *right))
+            .collect();
+        assert_eq!(
+            times,
+            [
+                (250.0, 500.0),
+                (250.0, 375.0),
+                (250.0, 250.0 * 2.0 / 3.0),
+                (250.0, 265.0),
+                (235.0, 265.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn spread_needs_room_below_the_base() {
+        assert_eq!(pairs(250.0, None).len(), 3);
+        assert_eq!(pairs(10.0, Some(15.0)).len(), 4);
+    }
+}
@@ -0,0 +1,110 @@
+use iced::widget::{button, column, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{RhythmicModifier, ROUND_LIMIT, SPACING};
+
+const LABEL_WIDTH: u16 = 120;
+
+pub struct Polyrhythm {
+    ratio_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreRatio(String),
+    Copy(f64),
+}
+
+impl Default for Polyrhythm {
+    fn default() -> Self {
+        Self {
+            ratio_text: "3:2".to_string(),
+        }
+    }
+}
+
+impl Polyrhythm {
+    // Returns a duration in milliseconds to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::StoreRatio(text) => self.ratio_text = text,
+            Message::Copy(ms) => return Some(ms),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("polyrhythm")),
+            text_input("3:2", &self.ratio_text)
+                .on_input(Message::StoreRatio)
+                .width(80),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let Some(tempo) = tempo else {
+            return column![input_row, text(tr("polyrhythm-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+        let Some(RhythmicModifier::Tuplet(notes, beats)) =
+            RhythmicModifier::parse_tuplet(&self.ratio_text)
+        else {
+            return column![input_row, text(tr("polyrhythm-ratio-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let cycle_ms = cycle_length(tempo, beats);
+        let stream_row = |count: u8| {
+            let ms = cycle_ms / f64::from(count);
+            row![
+                text(tr_with("stream-notes", &[("count", &count)])).width(LABEL_WIDTH),
+                text(format!("{} ms", round(ms, ROUND_LIMIT))).width(100),
+                text(format!("{} Hz", round(1000.0 / ms, ROUND_LIMIT))).width(100),
+                button(tr("tool-copy")).on_press(Message::Copy(ms)),
+            ]
+            .spacing(SPACING)
+            .align_y(Alignment::Center)
+            .into()
+        };
+
+        column![
+            input_row,
+            text(tr_with(
+                "polyrhythm-cycle",
+                &[
+                    ("notes", &notes),
+                    ("beats", &beats),
+                    ("ms", &round(cycle_ms, ROUND_LIMIT)),
+                ],
+            )),
+            Column::with_children([stream_row(notes), stream_row(beats)]).spacing(SPACING / 2),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+// The second number of the ratio counts beats, so the cycle lasts that many of them
+fn cycle_length(tempo: f64, beats: u8) -> f64 {
+    60_000.0 / tempo * f64::from(beats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_lengths() {
+        // 3:2 at 120 BPM is 3 notes over a second against the two beats
+        let cycle = cycle_length(120.0, 2);
+        assert_eq!(cycle, 1000.0);
+        assert_eq!(round(cycle / 3.0, ROUND_LIMIT), 333.333);
+        assert_eq!(cycle_length(90.0, 4), 2000.0 + 2000.0 / 3.0);
+    }
+}
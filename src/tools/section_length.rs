@@ -0,0 +1,130 @@
+use iced::widget::{column, pick_list, row, text, text_input};
+use iced::{Alignment, Element};
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::SPACING;
+
+// Film, PAL, NTSC drop-frame, and NTSC non-drop
+pub const FRAME_RATES: [f64; 4] = [24.0, 25.0, 29.97, 30.0];
+
+pub struct SectionLength {
+    bars_text: String,
+    frame_rate: f64,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreBars(String),
+    SelectFrameRate(f64),
+}
+
+impl Default for SectionLength {
+    fn default() -> Self {
+        Self {
+            bars_text: "16".to_string(),
+            frame_rate: 24.0,
+        }
+    }
+}
+
+impl SectionLength {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::StoreBars(text) => self.bars_text = text,
+            Message::SelectFrameRate(frame_rate) => self.frame_rate = frame_rate,
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("bars")),
+            text_input("", &self.bars_text)
+                .on_input(Message::StoreBars)
+                .width(60),
+            text(tr_with(
+                "in-time-signature",
+                &[("time-signature", &time_signature)]
+            )),
+            text(tr("frame-rate")),
+            pick_list(FRAME_RATES, Some(self.frame_rate), Message::SelectFrameRate),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let bars = self
+            .bars_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|bars| *bars >= 0.0);
+
+        let result = match (bars, tempo) {
+            (Some(bars), Some(tempo)) => {
+                let (seconds, frames) = duration(bars, tempo, time_signature, self.frame_rate);
+
+                tr_with(
+                    "section-duration",
+                    &[
+                        ("duration", &minutes_and_seconds(seconds)),
+                        ("frames", &frames),
+                        ("fps", &self.frame_rate),
+                    ],
+                )
+            }
+            (None, _) => tr("bars-prompt").to_string(),
+            (_, None) => tr("section-length-prompt").to_string(),
+        };
+
+        column![input_row, text(result)].spacing(SPACING).into()
+    }
+}
+
+// e.g. "1:02.500"
+pub fn minutes_and_seconds(seconds: f64) -> String {
+    let milliseconds = (seconds * 1000.0).round() as u64;
+    format!(
+        "{}:{:02}.{:03}",
+        milliseconds / 60_000,
+        milliseconds / 1000 % 60,
+        milliseconds % 1000
+    )
+}
+
+// Seconds and whole frames spanned by a number of bars
+fn duration(bars: f64, tempo: f64, time_signature: TimeSignature, frame_rate: f64) -> (f64, f64) {
+    let seconds = bars * time_signature.seconds_per_bar(tempo);
+    (seconds, (seconds * frame_rate).round())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_in_seconds_and_frames() {
+        assert_eq!(
+            duration(16.0, 120.0, TimeSignature::default(), 24.0),
+            (32.0, 768.0)
+        );
+        // Seven bars of 7/8 at 100 BPM is 14.7 s, which isn't a whole number of NTSC frames
+        let (seconds, frames) = duration(
+            7.0,
+            100.0,
+            TimeSignature {
+                beats: 7,
+                note_value: 8,
+            },
+            29.97,
+        );
+        assert!((seconds - 14.7).abs() < 1e-9);
+        assert_eq!(frames, 441.0);
+    }
+
+    #[test]
+    fn minutes_pad_their_seconds() {
+        assert_eq!(minutes_and_seconds(62.5), "1:02.500");
+        assert_eq!(minutes_and_seconds(0.0004), "0:00.000");
+        assert_eq!(minutes_and_seconds(3599.9996), "60:00.000");
+    }
+}
@@ -0,0 +1,153 @@
+use iced::widget::{button, column, pick_list, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::tr;
+use crate::{NoteValue, ROUND_LIMIT, SPACING};
+
+const LABEL_WIDTH: u16 = 220;
+const PUMP_NOTE_VALUES: [NoteValue; 3] = [NoteValue::Half, NoteValue::Quarter, NoteValue::Eighth];
+
+pub struct SidechainPump {
+    note_value: NoteValue,
+    attack_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SelectNoteValue(NoteValue),
+    StoreAttack(String),
+    Copy(f64),
+}
+
+impl Default for SidechainPump {
+    fn default() -> Self {
+        Self {
+            // Four on the floor
+            note_value: NoteValue::Quarter,
+            attack_text: "5".to_string(),
+        }
+    }
+}
+
+impl SidechainPump {
+    // Returns the time to copy
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::SelectNoteValue(note_value) => self.note_value = note_value,
+            Message::StoreAttack(text) => self.attack_text = text,
+            Message::Copy(time) => return Some(time),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("pump-every")),
+            pick_list(
+                PUMP_NOTE_VALUES,
+                Some(self.note_value),
+                Message::SelectNoteValue
+            ),
+            text(tr("attack-ms")),
+            text_input("", &self.attack_text)
+                .on_input(Message::StoreAttack)
+                .width(60),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let Some(tempo) = tempo else {
+            return column![input_row, text(tr("sidechain-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let Some(attack) = self
+            .attack_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|attack| *attack >= 0.0)
+        else {
+            return column![input_row, text(tr("attack-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let pump = pump(tempo, self.note_value, attack);
+
+        let time_row = |label: &'static str, time: Option<f64>| -> Element<'_, Message> {
+            match time {
+                Some(time) => {
+                    let time = round(time, ROUND_LIMIT);
+                    row![
+                        text(label).width(LABEL_WIDTH),
+                        text(format!("{} ms", time)).width(100),
+                        button(tr("tool-copy")).on_press(Message::Copy(time)),
+                    ]
+                    .spacing(SPACING)
+                    .align_y(Alignment::Center)
+                    .into()
+                }
+                None => row![text(label).width(LABEL_WIDTH), text(tr("attack-too-long"))]
+                    .spacing(SPACING)
+                    .into(),
+            }
+        };
+
+        column![
+            input_row,
+            Column::new()
+                .push(time_row(tr("volume-shaper-length"), Some(pump.cycle)))
+                .push(time_row(tr("release-next-note"), pump.release))
+                .push(time_row(tr("release-halfway"), pump.half_release))
+                .spacing(SPACING / 2),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+struct Pump {
+    // One duck and recovery, in milliseconds
+    cycle: f64,
+    // Releases that end exactly on the next trigger or halfway to it, once the attack has run
+    release: Option<f64>,
+    half_release: Option<f64>,
+}
+
+fn pump(tempo: f64, note_value: NoteValue, attack: f64) -> Pump {
+    let cycle = 60_000.0 / tempo * note_value.beats();
+    let release = |length: f64| Some(length - attack).filter(|release| *release > 0.0);
+
+    Pump {
+        cycle,
+        release: release(cycle),
+        half_release: release(cycle / 2.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn releases_end_on_the_beat() {
+        let quarter = pump(120.0, NoteValue::Quarter, 5.0);
+        assert_eq!(quarter.cycle, 500.0);
+        assert_eq!(quarter.release, Some(495.0));
+        assert_eq!(quarter.half_release, Some(245.0));
+
+        let eighth = pump(128.0, NoteValue::Eighth, 0.0);
+        assert_eq!(eighth.release, Some(234.375));
+    }
+
+    #[test]
+    fn attack_longer_than_the_note() {
+        let pump = pump(120.0, NoteValue::Eighth, 300.0);
+        assert_eq!(pump.release, None);
+        assert_eq!(pump.half_release, None);
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt::Display;
+
+use iced::widget::{column, radio, row, text, text_input};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{ROUND_LIMIT, SPACING};
+
+const FEET_PER_METER: f64 = 3.28084;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceUnit {
+    Meters,
+    Feet,
+}
+
+impl Display for DistanceUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceUnit::Meters => write!(f, "m"),
+            DistanceUnit::Feet => write!(f, "ft"),
+        }
+    }
+}
+
+pub struct SpeakerAlignment {
+    distance_text: String,
+    delay_text: String,
+    temperature_text: String,
+    distance_unit: DistanceUnit,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreDistance(String),
+    StoreDelay(String),
+    StoreTemperature(String),
+    SetDistanceUnit(DistanceUnit),
+}
+
+impl Default for SpeakerAlignment {
+    fn default() -> Self {
+        let mut speaker_alignment = Self {
+            distance_text: "10".to_string(),
+            delay_text: String::new(),
+            temperature_text: "20".to_string(),
+            distance_unit: DistanceUnit::Meters,
+        };
+
+        speaker_alignment.update_delay();
+        speaker_alignment
+    }
+}
+
+impl SpeakerAlignment {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::StoreDistance(text) => {
+                self.distance_text = text;
+                self.update_delay();
+            }
+            Message::StoreDelay(text) => {
+                self.delay_text = text;
+                self.update_distance();
+            }
+            Message::StoreTemperature(text) => {
+                self.temperature_text = text;
+                self.update_delay();
+            }
+            Message::SetDistanceUnit(distance_unit) => {
+                self.distance_unit = distance_unit;
+                self.update_delay();
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let speed_of_sound = self
+            .speed_of_sound()
+            .map(|speed| tr_with("speed-of-sound", &[("speed", &round(speed, 1))]))
+            .unwrap_or_default();
+
+        let distance_row = row![
+            text(tr("distance")),
+            text_input("", &self.distance_text)
+                .on_input(Message::StoreDistance)
+                .width(80),
+            radio(
+                DistanceUnit::Meters.to_string(),
+                DistanceUnit::Meters,
+                Some(self.distance_unit),
+                Message::SetDistanceUnit
+            ),
+            radio(
+                DistanceUnit::Feet.to_string(),
+                DistanceUnit::Feet,
+                Some(self.distance_unit),
+                Message::SetDistanceUnit
+            ),
+            text(tr("temperature-celsius")),
+            text_input("", &self.temperature_text)
+                .on_input(Message::StoreTemperature)
+                .width(50),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let delay_row = row![
+            text(tr("tool-delay-ms")),
+            text_input("", &self.delay_text)
+                .on_input(Message::StoreDelay)
+                .width(80),
+            text(speed_of_sound),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        column![distance_row, delay_row].spacing(SPACING).into()
+    }
+
+    fn speed_of_sound(&self) -> Option<f64> {
+        self.temperature_text
+            .trim()
+            .parse()
+            .ok()
+            .map(speed_of_sound)
+    }
+
+    fn update_delay(&mut self) {
+        let distance = self.distance_text.trim().parse::<f64>().ok();
+
+        if let (Some(distance), Some(speed)) = (distance, self.speed_of_sound()) {
+            let meters = match self.distance_unit {
+                DistanceUnit::Meters => distance,
+                DistanceUnit::Feet => distance / FEET_PER_METER,
+            };
+            self.delay_text = round(meters / speed * 1000.0, ROUND_LIMIT).to_string();
+        }
+    }
+
+    fn update_distance(&mut self) {
+        let delay = self.delay_text.trim().parse::<f64>().ok();
+
+        if let (Some(delay), Some(speed)) = (delay, self.speed_of_sound()) {
+            let meters = delay / 1000.0 * speed;
+            let distance = match self.distance_unit {
+                DistanceUnit::Meters => meters,
+                DistanceUnit::Feet => meters * FEET_PER_METER,
+            };
+            self.distance_text = round(distance, ROUND_LIMIT).to_string();
+        }
+    }
+}
+
+// Linear approximation of the speed of sound in dry air, in m/s
+fn speed_of_sound(temperature_celsius: f64) -> f64 {
+    331.3 + 0.606 * temperature_celsius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_and_delay_follow_each_other() {
+        // 10 m at 20 °C, where sound travels at 343.42 m/s
+        let mut speaker_alignment = SpeakerAlignment::default();
+        assert_eq!(speaker_alignment.delay_text, "29.119");
+
+        speaker_alignment.update(Message::SetDistanceUnit(DistanceUnit::Feet));
+        assert_eq!(speaker_alignment.delay_text, "8.875");
+
+        speaker_alignment.update(Message::StoreDelay("29.119".to_string()));
+        assert_eq!(speaker_alignment.distance_text, "32.809");
+    }
+
+    #[test]
+    fn sound_is_faster_in_warm_air() {
+        assert_eq!(speed_of_sound(0.0), 331.3);
+        assert!(speed_of_sound(30.0) > speed_of_sound(20.0));
+    }
+}
@@ -0,0 +1,158 @@
+use iced::widget::{button, column, row, text, text_input, Column};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{
+    delay_time, delay_times, parse_tempo, Unit, NOTE_VALUES, RHYTHMIC_MODIFIER, ROUND_LIMIT,
+    SPACING,
+};
+
+const LABEL_WIDTH: u16 = 60;
+
+#[derive(Default)]
+pub struct TempoMatch {
+    source_text: String,
+    target_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreSource(String),
+    StoreTarget(String),
+    UseCurrent(f64),
+    ApplyTarget(f64),
+}
+
+impl TempoMatch {
+    // Returns a tempo for the main table to switch to
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::StoreSource(text) => self.source_text = text,
+            Message::StoreTarget(text) => self.target_text = text,
+            Message::UseCurrent(tempo) => self.source_text = round(tempo, ROUND_LIMIT).to_string(),
+            Message::ApplyTarget(tempo) => return Some(tempo),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let source = parse_tempo(&self.source_text);
+        let target = parse_tempo(&self.target_text);
+
+        let input_row = row![
+            text(tr("track-bpm")),
+            text_input("", &self.source_text)
+                .on_input(Message::StoreSource)
+                .width(80),
+            button(tr("tool-use-current")).on_press_maybe(tempo.map(Message::UseCurrent)),
+            text(tr("target-bpm")),
+            text_input("", &self.target_text)
+                .on_input(Message::StoreTarget)
+                .width(80),
+            button(tr("apply-target")).on_press_maybe(target.map(Message::ApplyTarget)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let (Some(source), Some(target)) = (source, target) else {
+            return column![input_row, text(tr("tempo-match-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let shift = PitchShift::new(source, target);
+        let result = text(tr_with(
+            "pitch-shift",
+            &[
+                ("percent", &format!("{:+}", round(shift.percentage, 2))),
+                ("semitones", &format!("{:+}", round(shift.semitones, 2))),
+            ],
+        ));
+        let stretch = text(tr_with(
+            "time-stretch",
+            &[
+                ("percent", &round(shift.length_ratio * 100.0, 2)),
+                ("ratio", &round(shift.length_ratio, 4)),
+            ],
+        ));
+
+        let header = row![text("").width(LABEL_WIDTH)]
+            .extend(
+                RHYTHMIC_MODIFIER
+                    .iter()
+                    .map(|rhythmic_modifier| text(rhythmic_modifier.to_string()).width(100).into()),
+            )
+            .spacing(SPACING);
+
+        let columns = RHYTHMIC_MODIFIER
+            .map(|rhythmic_modifier| delay_times(target, &rhythmic_modifier, &Unit::Milliseconds));
+
+        let value_rows = NOTE_VALUES.map(|note_value| {
+            row![text(format!("{}:", note_value)).width(LABEL_WIDTH)]
+                .extend(columns.iter().map(|delay_times| {
+                    text(format!(
+                        "{} ms",
+                        round(delay_time(delay_times, &note_value), ROUND_LIMIT)
+                    ))
+                    .width(100)
+                    .into()
+                }))
+                .spacing(SPACING)
+                .into()
+        });
+
+        column![
+            input_row,
+            result,
+            stretch,
+            text(tr_with(
+                "delay-times-at",
+                &[("tempo", &round(target, ROUND_LIMIT))]
+            )),
+            header,
+            Column::with_children(value_rows).spacing(SPACING / 2),
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+struct PitchShift {
+    // The pitch fader movement, as shown on a CDJ or turntable
+    percentage: f64,
+    semitones: f64,
+    // What time-stretching asks for: the new length over the old one
+    length_ratio: f64,
+}
+
+impl PitchShift {
+    fn new(source: f64, target: f64) -> Self {
+        let ratio = target / source;
+
+        Self {
+            percentage: (ratio - 1.0) * 100.0,
+            semitones: 12.0 * ratio.log2(),
+            length_ratio: source / target,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pitch_shift_between_tempos() {
+        let shift = PitchShift::new(125.0, 128.0);
+        assert_eq!(round(shift.percentage, 2), 2.4);
+        assert_eq!(round(shift.semitones, 2), 0.41);
+        assert_eq!(round(shift.length_ratio * 100.0, 2), 97.66);
+
+        let shift = PitchShift::new(128.0, 64.0);
+        assert_eq!(shift.percentage, -50.0);
+        assert_eq!(shift.semitones, -12.0);
+        assert_eq!(shift.length_ratio, 2.0);
+    }
+}
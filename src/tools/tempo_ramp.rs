@@ -0,0 +1,186 @@
+use iced::widget::{button, column, radio, row, text, text_input, Row};
+use iced::{Alignment, Element};
+use round::round;
+
+use super::section_length::minutes_and_seconds;
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{parse_tempo, ROUND_LIMIT, SPACING};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Bar,
+    Beat,
+}
+
+pub struct TempoRamp {
+    start_text: String,
+    end_text: String,
+    bars_text: String,
+    step: Step,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreStart(String),
+    StoreEnd(String),
+    StoreBars(String),
+    UseCurrent(f64),
+    SelectStep(Step),
+}
+
+impl Default for TempoRamp {
+    fn default() -> Self {
+        Self {
+            start_text: "120".to_string(),
+            end_text: "140".to_string(),
+            bars_text: "8".to_string(),
+            step: Step::Bar,
+        }
+    }
+}
+
+impl TempoRamp {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::StoreStart(text) => self.start_text = text,
+            Message::StoreEnd(text) => self.end_text = text,
+            Message::StoreBars(text) => self.bars_text = text,
+            Message::UseCurrent(tempo) => self.start_text = round(tempo, ROUND_LIMIT).to_string(),
+            Message::SelectStep(step) => self.step = step,
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        let input_row = row![
+            text(tr("ramp-from")),
+            text_input("", &self.start_text)
+                .on_input(Message::StoreStart)
+                .width(70),
+            button(tr("tool-use-current")).on_press_maybe(tempo.map(Message::UseCurrent)),
+            text(tr("ramp-to")),
+            text_input("", &self.end_text)
+                .on_input(Message::StoreEnd)
+                .width(70),
+            text(tr("ramp-bpm-over")),
+            text_input("", &self.bars_text)
+                .on_input(Message::StoreBars)
+                .width(50),
+            text(tr_with(
+                "ramp-bars-of",
+                &[("time-signature", &time_signature)]
+            )),
+            radio(
+                tr("per-bar"),
+                Step::Bar,
+                Some(self.step),
+                Message::SelectStep
+            ),
+            radio(
+                tr("per-beat"),
+                Step::Beat,
+                Some(self.step),
+                Message::SelectStep
+            ),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let start = parse_tempo(&self.start_text);
+        let end = parse_tempo(&self.end_text);
+        let bars = self
+            .bars_text
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|bars| *bars > 0);
+
+        let (Some(start), Some(end), Some(bars)) = (start, end, bars) else {
+            return column![input_row, text(tr("tempo-ramp-prompt"))]
+                .spacing(SPACING)
+                .into();
+        };
+
+        let ramp = ramp(start, end, bars, self.step, time_signature);
+        let label = match self.step {
+            Step::Bar => "ramp-bar-step",
+            Step::Beat => "ramp-beat-step",
+        };
+
+        let steps = Row::with_children(ramp.tempos.iter().enumerate().map(|(index, tempo)| {
+            text(tr_with(
+                label,
+                &[("step", &(index + 1)), ("tempo", &round(*tempo, 2))],
+            ))
+            .into()
+        }))
+        .spacing(SPACING * 2)
+        .wrap();
+
+        column![
+            input_row,
+            text(tr_with(
+                "total-duration",
+                &[("duration", &minutes_and_seconds(ramp.seconds))]
+            )),
+            steps,
+        ]
+        .spacing(SPACING)
+        .into()
+    }
+}
+
+struct Ramp {
+    tempos: Vec<f64>,
+    seconds: f64,
+}
+
+// Each bar or beat holds a single tempo, stepping evenly so the first is the start tempo and the
+// last is the end tempo, which is how DAW tempo tracks usually get drawn by hand
+fn ramp(start: f64, end: f64, bars: u32, step: Step, time_signature: TimeSignature) -> Ramp {
+    let steps_per_bar = match step {
+        Step::Bar => 1,
+        Step::Beat => u32::from(time_signature.beats),
+    };
+    let steps = bars * steps_per_bar;
+    let quarter_notes_per_step = time_signature.quarter_notes_per_bar() / f64::from(steps_per_bar);
+
+    let tempos: Vec<f64> = (0..steps)
+        .map(|index| match steps {
+            1 => start,
+            _ => start + (end - start) * f64::from(index) / f64::from(steps - 1),
+        })
+        .collect();
+
+    let seconds = tempos
+        .iter()
+        .map(|tempo| quarter_notes_per_step * 60.0 / tempo)
+        .sum();
+
+    Ramp { tempos, seconds }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ramps_per_bar() {
+        let ramp = ramp(100.0, 130.0, 4, Step::Bar, TimeSignature::default());
+        assert_eq!(ramp.tempos, [100.0, 110.0, 120.0, 130.0]);
+        assert_eq!(
+            ramp.seconds,
+            240.0 / 100.0 + 240.0 / 110.0 + 240.0 / 120.0 + 240.0 / 130.0
+        );
+    }
+
+    #[test]
+    fn ramps_per_beat() {
+        let steady = ramp(120.0, 120.0, 2, Step::Beat, TimeSignature::default());
+        assert_eq!(steady.tempos.len(), 8);
+        assert_eq!(steady.seconds, 4.0);
+
+        let single_step = ramp(90.0, 90.0, 1, Step::Bar, TimeSignature::default());
+        assert_eq!(single_step.tempos, [90.0]);
+    }
+}
@@ -0,0 +1,481 @@
+use std::fmt::Display;
+
+use iced::widget::{button, column, pick_list, row, text};
+use iced::{Alignment, Element, Subscription};
+
+use crate::i18n::{tr, tr_with};
+use crate::time_signature::TimeSignature;
+use crate::{midi, SPACING};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FrameRate {
+    Fps24,
+    Fps25,
+    Fps2997DropFrame,
+    Fps30,
+}
+
+impl FrameRate {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => FrameRate::Fps24,
+            1 => FrameRate::Fps25,
+            2 => FrameRate::Fps2997DropFrame,
+            _ => FrameRate::Fps30,
+        }
+    }
+
+    fn frames_per_second(&self) -> f64 {
+        match self {
+            FrameRate::Fps24 => 24.0,
+            FrameRate::Fps25 => 25.0,
+            FrameRate::Fps2997DropFrame => 29.97,
+            FrameRate::Fps30 => 30.0,
+        }
+    }
+}
+
+impl Display for FrameRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameRate::Fps24 => write!(f, "24 fps"),
+            FrameRate::Fps25 => write!(f, "25 fps"),
+            FrameRate::Fps2997DropFrame => write!(f, "{}", tr("fps-drop-frame")),
+            FrameRate::Fps30 => write!(f, "30 fps"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timecode {
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    frames: u8,
+    frame_rate: FrameRate,
+}
+
+impl Timecode {
+    fn in_seconds(&self) -> f64 {
+        let whole_seconds = f64::from(self.hours) * 3600.0
+            + f64::from(self.minutes) * 60.0
+            + f64::from(self.seconds);
+
+        match self.frame_rate {
+            // Drop-frame counts 30 labels a second but skips frames 0 and 1 each minute, except
+            // every tenth, so labels stay on the wall clock while frames run at 30000/1001
+            FrameRate::Fps2997DropFrame => {
+                let total_minutes = u32::from(self.hours) * 60 + u32::from(self.minutes);
+                let dropped = 2 * (total_minutes - total_minutes / 10);
+                let frame_number =
+                    whole_seconds * 30.0 + f64::from(self.frames) - f64::from(dropped);
+                frame_number * 1001.0 / 30_000.0
+            }
+            frame_rate => whole_seconds + f64::from(self.frames) / frame_rate.frames_per_second(),
+        }
+    }
+
+    // Frames 0 and 1 don't exist at the start of most drop-frame minutes
+    fn is_dropped(&self) -> bool {
+        self.frame_rate == FrameRate::Fps2997DropFrame
+            && self.seconds == 0
+            && self.frames < 2
+            && !self.minutes.is_multiple_of(10)
+    }
+}
+
+impl Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A semicolon before the frames is the usual mark of drop-frame
+        let separator = match self.frame_rate {
+            FrameRate::Fps2997DropFrame => ';',
+            _ => ':',
+        };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{}{:02} ({})",
+            self.hours, self.minutes, self.seconds, separator, self.frames, self.frame_rate
+        )
+    }
+}
+
+// MIDI Show Control commands, as sent by lighting desks and show controllers
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ShowCommand {
+    Go,
+    Stop,
+    Resume,
+    TimedGo,
+    Load,
+    SetClock,
+    Other(u8),
+}
+
+impl ShowCommand {
+    fn from_byte(byte: u8) -> Self {
+        match byte {
+            0x01 => ShowCommand::Go,
+            0x02 => ShowCommand::Stop,
+            0x03 => ShowCommand::Resume,
+            0x04 => ShowCommand::TimedGo,
+            0x05 => ShowCommand::Load,
+            0x18 => ShowCommand::SetClock,
+            byte => ShowCommand::Other(byte),
+        }
+    }
+
+    // These start with a time in the same layout as a full-frame timecode
+    fn has_time(&self) -> bool {
+        matches!(self, ShowCommand::TimedGo | ShowCommand::SetClock)
+    }
+}
+
+impl Display for ShowCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShowCommand::Go => write!(f, "GO"),
+            ShowCommand::Stop => write!(f, "STOP"),
+            ShowCommand::Resume => write!(f, "RESUME"),
+            ShowCommand::TimedGo => write!(f, "TIMED_GO"),
+            ShowCommand::Load => write!(f, "LOAD"),
+            ShowCommand::SetClock => write!(f, "SET_CLOCK"),
+            ShowCommand::Other(byte) => write!(
+                f,
+                "{}",
+                tr_with("show-command-other", &[("byte", &format!("{:#04x}", byte))])
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ShowControl {
+    command: ShowCommand,
+    // ASCII digits and dots, e.g. "12.5"
+    cue: Option<String>,
+    time: Option<Timecode>,
+}
+
+impl Display for ShowControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.command)?;
+        if let Some(cue) = &self.cue {
+            write!(f, " {}", tr_with("show-control-cue", &[("cue", cue)]))?;
+        }
+        if let Some(time) = &self.time {
+            write!(f, " {}", tr_with("show-control-at", &[("time", time)]))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct TimecodeChase {
+    port_names: Vec<String>,
+    port_name: Option<String>,
+    // The eight quarter-frame nibbles, assembled into a timecode once the last one arrives
+    quarter_frames: [u8; 8],
+    timecode: Option<Timecode>,
+    show_control: Option<ShowControl>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    RefreshPorts,
+    SelectPort(String),
+    Disconnect,
+    MidiReceived(Vec<u8>),
+}
+
+impl Default for TimecodeChase {
+    fn default() -> Self {
+        Self {
+            port_names: midi::input_port_names(),
+            port_name: None,
+            quarter_frames: [0; 8],
+            timecode: None,
+            show_control: None,
+        }
+    }
+}
+
+impl TimecodeChase {
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::RefreshPorts => self.port_names = midi::input_port_names(),
+            Message::SelectPort(port_name) => {
+                self.port_name = Some(port_name);
+                self.timecode = None;
+                self.show_control = None;
+            }
+            Message::Disconnect => self.port_name = None,
+            Message::MidiReceived(bytes) => self.receive(&bytes),
+        }
+    }
+
+    pub fn view(&self, tempo: Option<f64>, time_signature: TimeSignature) -> Element<'_, Message> {
+        if !midi::IS_SUPPORTED {
+            return text(tr_with(
+                "tool-needs-feature",
+                &[("tool", &tr("tool-timecode-chase")), ("feature", &"midi")],
+            ))
+            .into();
+        }
+
+        let port_row = row![
+            text(tr("mtc-msc-input")),
+            pick_list(
+                self.port_names.as_slice(),
+                self.port_name.clone(),
+                Message::SelectPort
+            ),
+            button(tr("tool-refresh")).on_press(Message::RefreshPorts),
+            button(tr("tool-disconnect"))
+                .on_press_maybe(self.port_name.as_ref().map(|_| Message::Disconnect)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let timecode_text = self
+            .timecode
+            .map(|timecode| tr_with("timecode", &[("timecode", &timecode)]))
+            .unwrap_or(tr("timecode-waiting").to_string());
+
+        let position_text = match (self.timecode, tempo) {
+            (Some(timecode), Some(tempo)) => {
+                let (bar, beat) = bars_and_beats(timecode.in_seconds(), tempo, time_signature);
+                tr_with("bar-and-beat", &[("bar", &bar), ("beat", &beat)])
+            }
+            _ => String::new(),
+        };
+
+        let show_control_text = self
+            .show_control
+            .as_ref()
+            .map(|show_control| tr_with("show-control", &[("command", show_control)]));
+
+        column![
+            port_row,
+            row![text(timecode_text), text(position_text)].spacing(SPACING)
+        ]
+        .push_maybe(show_control_text.map(text))
+        .spacing(SPACING)
+        .into()
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        match &self.port_name {
+            Some(port_name) => midi::listen(port_name.clone()).map(Message::MidiReceived),
+            None => Subscription::none(),
+        }
+    }
+
+    fn receive(&mut self, bytes: &[u8]) {
+        match bytes {
+            // Quarter frame: 0nnn dddd, where n is the piece index and d its nibble
+            [0xF1, data] => {
+                let piece = usize::from((data >> 4) & 0b111);
+                self.quarter_frames[piece] = data & 0x0F;
+
+                // The last piece completes a timecode that is two frames behind the sender
+                if piece == 7 {
+                    let pieces = self.quarter_frames;
+                    let timecode = Timecode {
+                        frames: pieces[0] | (pieces[1] & 0b1) << 4,
+                        seconds: pieces[2] | (pieces[3] & 0b11) << 4,
+                        minutes: pieces[4] | (pieces[5] & 0b11) << 4,
+                        hours: pieces[6] | (pieces[7] & 0b1) << 4,
+                        frame_rate: FrameRate::from_bits(pieces[7] >> 1),
+                    };
+                    self.timecode = Some(advance_frames(timecode, 2));
+                }
+            }
+            // Full frame, sent when the transport locates
+            [0xF0, 0x7F, _, 0x01, 0x01, hours, minutes, seconds, frames, 0xF7] => {
+                self.timecode = Some(Timecode {
+                    hours: hours & 0x1F,
+                    minutes: *minutes,
+                    seconds: *seconds,
+                    frames: *frames,
+                    frame_rate: FrameRate::from_bits(hours >> 5),
+                });
+            }
+            [0xF0, 0x7F, _, 0x02, ..] => {
+                if let Some(show_control) = parse_show_control(bytes) {
+                    if let Some(time) = show_control.time {
+                        self.timecode = Some(time);
+                    }
+                    self.show_control = Some(show_control);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// F0 7F <device> 02 <command format> <command> <data> F7
+fn parse_show_control(bytes: &[u8]) -> Option<ShowControl> {
+    let [0xF0, 0x7F, _, 0x02, _, command, data @ .., 0xF7] = bytes else {
+        return None;
+    };
+    let command = ShowCommand::from_byte(*command);
+
+    let (time, data) = match data {
+        [hours, minutes, seconds, frames, _, rest @ ..] if command.has_time() => (
+            Some(Timecode {
+                hours: hours & 0x1F,
+                minutes: minutes & 0x3F,
+                seconds: seconds & 0x3F,
+                frames: frames & 0x1F,
+                frame_rate: FrameRate::from_bits(hours >> 5),
+            }),
+            rest,
+        ),
+        _ if command.has_time() => return None,
+        data => (None, data),
+    };
+
+    // The cue number comes first; a 00 byte separates it from the cue list and path
+    let cue: String = data
+        .iter()
+        .take_while(|byte| **byte != 0x00)
+        .map(|byte| char::from(*byte))
+        .filter(|char| char.is_ascii_digit() || *char == '.')
+        .collect();
+
+    Some(ShowControl {
+        command,
+        cue: (!cue.is_empty()).then_some(cue),
+        time,
+    })
+}
+
+fn advance_frames(timecode: Timecode, frames: u8) -> Timecode {
+    let frames_per_second = timecode.frame_rate.frames_per_second().ceil() as u8;
+    let total_frames = timecode.frames + frames;
+    let total_seconds = timecode.seconds + total_frames / frames_per_second;
+    let total_minutes = timecode.minutes + total_seconds / 60;
+
+    let advanced = Timecode {
+        frames: total_frames % frames_per_second,
+        seconds: total_seconds % 60,
+        minutes: total_minutes % 60,
+        hours: timecode.hours + total_minutes / 60,
+        ..timecode
+    };
+
+    if advanced.is_dropped() {
+        Timecode {
+            frames: advanced.frames + 2,
+            ..advanced
+        }
+    } else {
+        advanced
+    }
+}
+
+// One-based, like a DAW's bar/beat ruler
+fn bars_and_beats(seconds: f64, tempo: f64, time_signature: TimeSignature) -> (u64, u64) {
+    let quarter_notes = seconds * tempo / 60.0;
+    let beats = quarter_notes * f64::from(time_signature.note_value) / 4.0;
+    let beats_per_bar = f64::from(time_signature.beats);
+    let bar = (beats / beats_per_bar).floor() as u64 + 1;
+    let beat = (beats % beats_per_bar).floor() as u64 + 1;
+    (bar, beat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timecode(
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+        frames: u8,
+        frame_rate: FrameRate,
+    ) -> Timecode {
+        Timecode {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            frame_rate,
+        }
+    }
+
+    #[test]
+    fn drop_frame_stays_on_the_clock() {
+        let drop_frame = FrameRate::Fps2997DropFrame;
+        // 00:01:00;02 is the 1800th frame, since ;00 and ;01 were skipped
+        assert_eq!(timecode(0, 1, 0, 2, drop_frame).in_seconds(), 60.06);
+        let hour = timecode(1, 0, 0, 0, drop_frame).in_seconds();
+        assert!((hour - 3600.0).abs() < 0.01);
+
+        assert_eq!(timecode(0, 0, 1, 12, FrameRate::Fps24).in_seconds(), 1.5);
+    }
+
+    #[test]
+    fn advancing_skips_dropped_frames() {
+        let drop_frame = FrameRate::Fps2997DropFrame;
+        assert_eq!(
+            advance_frames(timecode(0, 0, 59, 29, drop_frame), 2),
+            timecode(0, 1, 0, 3, drop_frame)
+        );
+        assert_eq!(
+            advance_frames(timecode(0, 9, 59, 29, drop_frame), 2),
+            timecode(0, 10, 0, 1, drop_frame)
+        );
+        assert_eq!(
+            advance_frames(timecode(0, 0, 59, 23, FrameRate::Fps24), 2),
+            timecode(0, 1, 0, 1, FrameRate::Fps24)
+        );
+    }
+
+    #[test]
+    fn quarter_frames_assemble_a_timecode() {
+        let mut chase = TimecodeChase::default();
+        // 01:02:03:04 at 25 fps, one nibble per message
+        let pieces = [0x4, 0x0, 0x3, 0x0, 0x2, 0x0, 0x1, 0b0010];
+        for (index, piece) in pieces.iter().enumerate() {
+            chase.receive(&[0xF1, (index as u8) << 4 | piece]);
+        }
+        assert_eq!(chase.timecode, Some(timecode(1, 2, 3, 6, FrameRate::Fps25)));
+    }
+
+    #[test]
+    fn show_control_messages() {
+        let go = [
+            0xF0, 0x7F, 0x01, 0x02, 0x01, 0x01, b'1', b'2', b'.', b'5', 0x00, b'3', 0xF7,
+        ];
+        assert_eq!(
+            parse_show_control(&go),
+            Some(ShowControl {
+                command: ShowCommand::Go,
+                cue: Some("12.5".to_string()),
+                time: None,
+            })
+        );
+
+        // 00:10:00;00 drop-frame, then cue 7
+        let timed_go = [
+            0xF0, 0x7F, 0x01, 0x02, 0x01, 0x04, 0x40, 0x0A, 0x00, 0x00, 0x00, b'7', 0xF7,
+        ];
+        let mut chase = TimecodeChase::default();
+        chase.receive(&timed_go);
+        assert_eq!(
+            chase.timecode,
+            Some(timecode(0, 10, 0, 0, FrameRate::Fps2997DropFrame))
+        );
+        assert_eq!(
+            chase
+                .show_control
+                .map(|show_control| show_control.to_string()),
+            Some("TIMED_GO cue 7 at 00:10:00;00 (29.97 fps drop)".to_string())
+        );
+
+        assert_eq!(
+            parse_show_control(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x04, 0xF7]),
+            None
+        );
+    }
+}
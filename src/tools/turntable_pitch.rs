@@ -0,0 +1,179 @@
+use std::fmt::Display;
+
+use iced::widget::{button, column, pick_list, row, text, text_input};
+use iced::{Alignment, Element};
+use round::round;
+
+use crate::i18n::{tr, tr_with};
+use crate::{ROUND_LIMIT, SPACING};
+
+// How far the pitch fader reaches either side of zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PitchRange {
+    Eight,
+    Sixteen,
+}
+
+const PITCH_RANGES: [PitchRange; 2] = [PitchRange::Eight, PitchRange::Sixteen];
+
+impl PitchRange {
+    fn percent(&self) -> f64 {
+        match self {
+            PitchRange::Eight => 8.0,
+            PitchRange::Sixteen => 16.0,
+        }
+    }
+}
+
+impl Display for PitchRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "±{}%", self.percent())
+    }
+}
+
+pub struct TurntablePitch {
+    record_tempo_text: String,
+    pitch_text: String,
+    range: PitchRange,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    StoreRecordTempo(String),
+    StorePitch(String),
+    SelectRange(PitchRange),
+    Load(f64),
+}
+
+impl Default for TurntablePitch {
+    fn default() -> Self {
+        Self {
+            record_tempo_text: String::new(),
+            pitch_text: "0".to_string(),
+            // The Technics SL-1200 range, which most decks copy
+            range: PitchRange::Eight,
+        }
+    }
+}
+
+impl TurntablePitch {
+    // Returns a tempo for the main table to switch to
+    pub fn update(&mut self, message: Message) -> Option<f64> {
+        match message {
+            Message::StoreRecordTempo(text) => self.record_tempo_text = text,
+            Message::StorePitch(text) => self.pitch_text = text,
+            Message::SelectRange(range) => self.range = range,
+            Message::Load(tempo) => return Some(tempo),
+        }
+
+        None
+    }
+
+    pub fn view(&self, tempo: Option<f64>) -> Element<'_, Message> {
+        let record_tempo = self.record_tempo();
+        let pitch = self.pitch();
+        let pitched_tempo = record_tempo
+            .zip(pitch)
+            .map(|(record_tempo, pitch)| record_tempo * (1.0 + pitch / 100.0));
+
+        let pitch_input_is_out_of_range =
+            self.pitch_text.trim().parse::<f64>().is_ok() && pitch.is_none();
+
+        let input_row = row![
+            text(tr("turntable-record-at")),
+            text_input("BPM", &self.record_tempo_text)
+                .on_input(Message::StoreRecordTempo)
+                .width(80),
+            text(tr("turntable-pitched")),
+            text_input("%", &self.pitch_text)
+                .on_input(Message::StorePitch)
+                .width(60),
+            text("%"),
+            pick_list(PITCH_RANGES, Some(self.range), Message::SelectRange),
+            button(tr("tool-load")).on_press_maybe(pitched_tempo.map(Message::Load)),
+        ]
+        .spacing(SPACING)
+        .align_y(Alignment::Center);
+
+        let result = match pitched_tempo {
+            Some(pitched_tempo) => tr_with(
+                "turntable-plays-at",
+                &[("tempo", &round(pitched_tempo, ROUND_LIMIT))],
+            ),
+            None if pitch_input_is_out_of_range => {
+                tr_with("turntable-out-of-range", &[("range", &self.range)])
+            }
+            None => tr("turntable-prompt").to_string(),
+        };
+
+        let match_result = match record_tempo.zip(tempo) {
+            Some((record_tempo, tempo)) => {
+                let pitch = pitch_to_match(record_tempo, tempo);
+                let signed_pitch = format!("{:+}", round(pitch, 2));
+                let tempo = round(tempo, ROUND_LIMIT);
+                if pitch.abs() <= self.range.percent() {
+                    tr_with(
+                        "turntable-match",
+                        &[("pitch", &signed_pitch), ("tempo", &tempo)],
+                    )
+                } else {
+                    tr_with(
+                        "turntable-beyond-range",
+                        &[
+                            ("tempo", &tempo),
+                            ("pitch", &signed_pitch),
+                            ("range", &self.range),
+                        ],
+                    )
+                }
+            }
+            None => tr("turntable-match-prompt").to_string(),
+        };
+
+        column![input_row, text(result), text(match_result)]
+            .spacing(SPACING)
+            .into()
+    }
+
+    fn record_tempo(&self) -> Option<f64> {
+        self.record_tempo_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|tempo| tempo.is_finite() && *tempo > 0.0)
+    }
+
+    fn pitch(&self) -> Option<f64> {
+        self.pitch_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|pitch| pitch.abs() <= self.range.percent())
+    }
+}
+
+// The fader changes speed, so tempo scales by the same percentage
+fn pitch_to_match(record_tempo: f64, tempo: f64) -> f64 {
+    (tempo / record_tempo - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pitches_follow_the_fader() {
+        let mut turntable = TurntablePitch::default();
+        let _ = turntable.update(Message::StoreRecordTempo("125".to_string()));
+        let _ = turntable.update(Message::StorePitch("+4".to_string()));
+        assert_eq!(turntable.pitch(), Some(4.0));
+
+        let _ = turntable.update(Message::StorePitch("-12".to_string()));
+        assert_eq!(turntable.pitch(), None);
+        let _ = turntable.update(Message::SelectRange(PitchRange::Sixteen));
+        assert_eq!(turntable.pitch(), Some(-12.0));
+
+        assert_eq!(round(pitch_to_match(125.0, 130.0), 2), 4.0);
+        assert_eq!(round(pitch_to_match(128.0, 120.0), 2), -6.25);
+    }
+}
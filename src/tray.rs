@@ -0,0 +1,187 @@
+// A tray / menu bar icon that keeps the app running with its window closed. Its menu shows the
+// latest copied value and leads back to the window; tray menus can't hold a text field, so quick
+// tempo entry opens the window in mini mode with the tempo field focused.
+//
+// The icon lives behind the `tray` feature, since on Linux it needs GTK and an app indicator
+// library at build time. Linux tray menus are GTK widgets, so there the icon runs on a thread of its
+// own with GTK's loop; elsewhere it has to be made on the main thread while the window's loop runs.
+
+pub const IS_SUPPORTED: bool = cfg!(all(feature = "tray", not(target_arch = "wasm32")));
+
+// Only sent by a real tray icon
+#[cfg_attr(
+    not(all(feature = "tray", not(target_arch = "wasm32"))),
+    allow(dead_code)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    ShowWindow,
+    QuickTempo,
+    Quit,
+}
+
+#[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+pub use imp::{listen, Tray};
+
+#[cfg(not(all(feature = "tray", not(target_arch = "wasm32"))))]
+pub struct Tray;
+
+#[cfg(not(all(feature = "tray", not(target_arch = "wasm32"))))]
+impl Tray {
+    pub fn new(_tooltip: &str, _latest: &str) -> Result<Tray, String> {
+        Err("this build doesn't include the tray feature".to_string())
+    }
+
+    pub fn set_latest(&self, _latest: &str) {}
+}
+
+#[cfg(not(all(feature = "tray", not(target_arch = "wasm32"))))]
+pub fn listen() -> iced::Subscription<Event> {
+    iced::Subscription::none()
+}
+
+#[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+mod imp {
+    use iced::Subscription;
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+    use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+    use super::Event;
+    use crate::i18n::tr;
+
+    const SHOW_WINDOW: &str = "show-window";
+    const QUICK_TEMPO: &str = "quick-tempo";
+    const QUIT: &str = "quit";
+    const ICON_SIZE: u32 = 32;
+
+    #[cfg(target_os = "linux")]
+    pub struct Tray {
+        latest: std::sync::mpsc::Sender<String>,
+    }
+
+    #[cfg(target_os = "linux")]
+    impl Tray {
+        pub fn new(tooltip: &str, latest: &str) -> Result<Tray, String> {
+            use gtk::glib;
+            use std::sync::mpsc;
+            use std::time::Duration;
+
+            let (sender, receiver) = mpsc::channel::<String>();
+            let (ready_sender, ready_receiver) = mpsc::channel();
+            let (tooltip, latest) = (tooltip.to_string(), latest.to_string());
+
+            std::thread::spawn(move || {
+                if let Err(error) = gtk::init() {
+                    let _ = ready_sender.send(Err(error.to_string()));
+                    return;
+                }
+                let (_tray_icon, latest_item) = match build(&tooltip, &latest) {
+                    Ok(built) => built,
+                    Err(error) => {
+                        let _ = ready_sender.send(Err(error));
+                        return;
+                    }
+                };
+                let _ = ready_sender.send(Ok(()));
+
+                glib::timeout_add_local(Duration::from_millis(250), move || {
+                    while let Ok(text) = receiver.try_recv() {
+                        latest_item.set_text(text);
+                    }
+                    glib::ControlFlow::Continue
+                });
+                gtk::main();
+            });
+
+            ready_receiver.recv().map_err(|error| error.to_string())??;
+            Ok(Tray { latest: sender })
+        }
+
+        pub fn set_latest(&self, latest: &str) {
+            let _ = self.latest.send(latest.to_string());
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub struct Tray {
+        _tray_icon: TrayIcon,
+        latest: MenuItem,
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    impl Tray {
+        pub fn new(tooltip: &str, latest: &str) -> Result<Tray, String> {
+            let (tray_icon, latest) = build(tooltip, latest)?;
+            Ok(Tray {
+                _tray_icon: tray_icon,
+                latest,
+            })
+        }
+
+        pub fn set_latest(&self, latest: &str) {
+            self.latest.set_text(latest);
+        }
+    }
+
+    // The icon and the menu item that shows the latest value, both kept for as long as the icon
+    // should stay up
+    fn build(tooltip: &str, latest: &str) -> Result<(TrayIcon, MenuItem), String> {
+        let latest_item = MenuItem::new(latest, false, None);
+        let menu = Menu::new();
+        menu.append_items(&[
+            &MenuItem::with_id(SHOW_WINDOW, tr("tray-show-window"), true, None),
+            &latest_item,
+            &MenuItem::with_id(QUICK_TEMPO, tr("tray-quick-tempo"), true, None),
+            &PredefinedMenuItem::separator(),
+            &MenuItem::with_id(QUIT, tr("tray-quit"), true, None),
+        ])
+        .map_err(|error| error.to_string())?;
+
+        let tray_icon = TrayIconBuilder::new()
+            .with_menu(Box::new(menu))
+            .with_icon(icon()?)
+            .with_tooltip(tooltip)
+            .build()
+            .map_err(|error| error.to_string())?;
+
+        Ok((tray_icon, latest_item))
+    }
+
+    // A filled circle, drawn here so there's no image file to ship
+    fn icon() -> Result<Icon, String> {
+        let center = (ICON_SIZE as f32 - 1.0) / 2.0;
+        let radius = ICON_SIZE as f32 / 2.0 - 2.0;
+        let rgba = (0..ICON_SIZE * ICON_SIZE)
+            .flat_map(|index| {
+                let x = (index % ICON_SIZE) as f32 - center;
+                let y = (index / ICON_SIZE) as f32 - center;
+                // Half a pixel of falloff keeps the edge from looking jagged
+                let coverage = (radius - x.hypot(y) + 0.5).clamp(0.0, 1.0);
+                [0x3a, 0x7b, 0xd5, (coverage * 255.0) as u8]
+            })
+            .collect();
+
+        Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).map_err(|error| error.to_string())
+    }
+
+    // Menu clicks arrive on a channel shared by the whole process, so one thread waits on it
+    pub fn listen() -> Subscription<Event> {
+        Subscription::run_with_id(
+            "tray",
+            iced::stream::channel(10, |mut output| async move {
+                std::thread::spawn(move || {
+                    while let Ok(event) = MenuEvent::receiver().recv() {
+                        let event = match event.id.as_ref() {
+                            SHOW_WINDOW => Event::ShowWindow,
+                            QUICK_TEMPO => Event::QuickTempo,
+                            QUIT => Event::Quit,
+                            _ => continue,
+                        };
+                        let _ = output.try_send(event);
+                    }
+                });
+                std::future::pending::<()>().await;
+            }),
+        )
+    }
+}
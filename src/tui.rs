@@ -0,0 +1,388 @@
+// The table in a terminal, for SSH sessions and headless studio machines, started with `--tui`.
+// It's drawn with ratatui, and crossterm handles raw mode and decodes keys, so keys it doesn't bind,
+// like the function keys, are ignored rather than typed. Copies go out as OSC 52 escapes, which the
+// terminal hands to the clipboard of the machine it runs on, even over SSH. Tempos come from taps,
+// nudges, or typing one and pressing enter.
+
+use std::io::Write;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Cell, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+use round::round;
+
+use crate::pipe::{Session, Target};
+use crate::settings::Settings;
+use crate::tempo_action::TempoAction;
+use crate::{
+    delay_time, delay_times, parse_tempo, Unit, NOTE_VALUES, NOT_APPLICABLE, RHYTHMIC_MODIFIER,
+};
+
+const NOTE_WIDTH: u16 = 8;
+const COLUMN_WIDTH: u16 = 16;
+
+const HELP: &str = "space tap · r reset taps · arrows move · enter copy · u unit · +/- nudge · / * halve/double · q quit";
+
+pub fn is_enabled(mut args: impl Iterator<Item = String>) -> bool {
+    args.any(|arg| arg == "--tui")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Key {
+    Tap,
+    ResetTaps,
+    Up,
+    Down,
+    Left,
+    Right,
+    // Submits a typed tempo, or copies the selected cell when nothing's typed
+    Enter,
+    Copy,
+    Digit(char),
+    Backspace,
+    NextUnit,
+    Tempo(TempoAction),
+    Quit,
+}
+
+fn key(event: KeyEvent) -> Option<Key> {
+    // Some terminals report releases and repeats too
+    if event.kind == KeyEventKind::Release {
+        return None;
+    }
+    // Raw mode delivers ctrl-c as a key rather than a signal
+    if event.modifiers.contains(KeyModifiers::CONTROL) {
+        return (event.code == KeyCode::Char('c')).then_some(Key::Quit);
+    }
+
+    match event.code {
+        KeyCode::Up => Some(Key::Up),
+        KeyCode::Down => Some(Key::Down),
+        KeyCode::Left => Some(Key::Left),
+        KeyCode::Right => Some(Key::Right),
+        KeyCode::Enter => Some(Key::Enter),
+        KeyCode::Backspace => Some(Key::Backspace),
+        KeyCode::Esc => Some(Key::Quit),
+        KeyCode::Char(character) => match character {
+            ' ' | 't' => Some(Key::Tap),
+            'r' => Some(Key::ResetTaps),
+            'k' => Some(Key::Up),
+            'j' => Some(Key::Down),
+            'h' => Some(Key::Left),
+            'l' => Some(Key::Right),
+            'c' => Some(Key::Copy),
+            '0'..='9' | '.' => Some(Key::Digit(character)),
+            'u' => Some(Key::NextUnit),
+            '+' | '=' => Some(Key::Tempo(TempoAction::NudgeBy(1.0))),
+            '-' => Some(Key::Tempo(TempoAction::NudgeBy(-1.0))),
+            '/' => Some(Key::Tempo(TempoAction::Halve)),
+            '*' => Some(Key::Tempo(TempoAction::Double)),
+            'q' => Some(Key::Quit),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+struct Tui {
+    session: Session,
+    ppqn: u16,
+    sample_rate: u32,
+    custom_unit: Unit,
+    precision: i32,
+    // Column, then row
+    selected: (usize, usize),
+    typed_tempo: String,
+    status: String,
+}
+
+impl Tui {
+    fn new(settings: &Settings) -> Self {
+        Self {
+            session: Session::new(settings),
+            ppqn: settings.ppqn,
+            sample_rate: settings.sample_rate,
+            custom_unit: settings.custom_as_unit(),
+            precision: settings.precision(),
+            selected: (0, 0),
+            typed_tempo: String::new(),
+            status: String::new(),
+        }
+    }
+
+    fn value(&self, column: usize, row: usize) -> Option<f64> {
+        self.session.tempo().map(|tempo| {
+            let delay_times = delay_times(tempo, &RHYTHMIC_MODIFIER[column], &self.session.unit());
+            delay_time(&delay_times, &NOTE_VALUES[row])
+        })
+    }
+
+    fn next_unit(&self) -> Unit {
+        match self.session.unit() {
+            Unit::Milliseconds | Unit::Both => Unit::Hertz,
+            Unit::Hertz => Unit::Ticks(self.ppqn),
+            Unit::Ticks(_) => Unit::Samples(self.sample_rate),
+            Unit::Samples(_) => self.custom_unit.clone(),
+            Unit::Custom(_) => Unit::Milliseconds,
+        }
+    }
+
+    // Returns text to copy
+    fn update(&mut self, key: Key) -> Option<String> {
+        let (column, row) = self.selected;
+        self.status.clear();
+
+        match key {
+            Key::Tap => self.session.tap(),
+            Key::ResetTaps => {
+                self.session.reset_taps();
+                self.status = "Taps reset".to_string();
+            }
+            Key::Up => self.selected.1 = row.saturating_sub(1),
+            Key::Down => self.selected.1 = (row + 1).min(NOTE_VALUES.len() - 1),
+            Key::Left => self.selected.0 = column.saturating_sub(1),
+            Key::Right => self.selected.0 = (column + 1).min(RHYTHMIC_MODIFIER.len() - 1),
+            Key::Enter if !self.typed_tempo.is_empty() => {
+                match parse_tempo(&self.typed_tempo) {
+                    Some(tempo) => self.session.set_tempo(tempo),
+                    None => self.status = format!("{} isn't a tempo", self.typed_tempo),
+                }
+                self.typed_tempo.clear();
+            }
+            Key::Enter | Key::Copy => match self.value(column, row) {
+                Some(value) => {
+                    self.status = format!(
+                        "Copied {} {}",
+                        round(value, self.precision),
+                        self.session.unit()
+                    );
+                    return Some(value.to_string());
+                }
+                None => self.status = "Tap or type a tempo first".to_string(),
+            },
+            Key::Digit(digit) => self.typed_tempo.push(digit),
+            Key::Backspace => {
+                self.typed_tempo.pop();
+            }
+            Key::NextUnit => self.session.set_unit(self.next_unit()),
+            Key::Tempo(action) => {
+                if let Some(tempo) = action.apply(self.session.tempo()) {
+                    self.session.set_tempo(tempo);
+                }
+            }
+            Key::Quit => {}
+        }
+
+        None
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [heading_area, table_area, footer_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .areas(frame.area());
+
+        let tempo = match self.session.tempo() {
+            Some(tempo) => format!("{} BPM", round(tempo, self.precision)),
+            None => NOT_APPLICABLE.to_string(),
+        };
+        let heading = Paragraph::new(vec![
+            Line::from(format!(
+                "Delay Time Calculator · {} · {}",
+                tempo,
+                self.session.unit()
+            )),
+            Line::from(format!("Tempo: {}", self.typed_tempo)),
+        ]);
+
+        let header = Row::new(
+            std::iter::once(String::new()).chain(
+                RHYTHMIC_MODIFIER
+                    .iter()
+                    .map(|rhythmic_modifier| rhythmic_modifier.to_string()),
+            ),
+        )
+        .bold();
+        let rows = NOTE_VALUES.iter().enumerate().map(|(row, note_value)| {
+            Row::new(std::iter::once(Cell::from(note_value.to_string())).chain(
+                (0..RHYTHMIC_MODIFIER.len()).map(|column| {
+                    let cell = Cell::from(match self.value(column, row) {
+                        Some(value) => {
+                            format!("{} {}", round(value, self.precision), self.session.unit())
+                        }
+                        None => NOT_APPLICABLE.to_string(),
+                    });
+                    if (column, row) == self.selected {
+                        cell.style(Style::new().reversed())
+                    } else {
+                        cell
+                    }
+                }),
+            ))
+        });
+        let widths = std::iter::once(Constraint::Length(NOTE_WIDTH)).chain(std::iter::repeat_n(
+            Constraint::Length(COLUMN_WIDTH),
+            RHYTHMIC_MODIFIER.len(),
+        ));
+
+        let footer = Paragraph::new(vec![
+            Line::default(),
+            Line::from(self.status.as_str()),
+            Line::from(HELP).dim(),
+        ]);
+
+        frame.render_widget(heading, heading_area);
+        frame.render_widget(Table::new(rows, widths).header(header), table_area);
+        frame.render_widget(footer, footer_area);
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    bytes
+        .chunks(3)
+        .flat_map(|chunk| {
+            let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| {
+                bits | (u32::from(*byte) << (16 - 8 * index))
+            });
+            (0..4).map(move |index| {
+                if index <= chunk.len() {
+                    char::from(ALPHABET[((bits >> (18 - 6 * index)) & 0x3f) as usize])
+                } else {
+                    '='
+                }
+            })
+        })
+        .collect()
+}
+
+// Asks the terminal to put the text on the clipboard
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn event_loop(tui: &mut Tui, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+    loop {
+        terminal.draw(|frame| tui.draw(frame))?;
+
+        // Anything else, like a resize, only needs the redraw
+        let Event::Key(event) = event::read()? else {
+            continue;
+        };
+        let Some(key) = key(event) else {
+            continue;
+        };
+        if key == Key::Quit {
+            return Ok(());
+        }
+        if let Some(text) = tui.update(key) {
+            let backend = terminal.backend_mut();
+            backend.write_all(osc52(&text).as_bytes())?;
+            backend.flush()?;
+        }
+    }
+}
+
+// Puts the terminal back however the UI stops, including a panic
+pub fn run(settings: Settings) -> std::io::Result<()> {
+    let mut tui = Tui::new(&settings);
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut tui, &mut terminal);
+    ratatui::restore();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Modifier;
+    use ratatui::Terminal;
+
+    use super::*;
+    use crate::NoteValue;
+
+    fn press(tui: &mut Tui, text: &str) {
+        for character in text.chars() {
+            let code = match character {
+                '\r' => KeyCode::Enter,
+                character => KeyCode::Char(character),
+            };
+            if let Some(key) = key(KeyEvent::new(code, KeyModifiers::NONE)) {
+                tui.update(key);
+            }
+        }
+    }
+
+    #[test]
+    fn maps_keys() {
+        let key = |code, modifiers| key(KeyEvent::new(code, modifiers));
+
+        assert_eq!(key(KeyCode::Char(' '), KeyModifiers::NONE), Some(Key::Tap));
+        assert_eq!(key(KeyCode::Char('j'), KeyModifiers::NONE), Some(Key::Down));
+        assert_eq!(key(KeyCode::Down, KeyModifiers::NONE), Some(Key::Down));
+        assert_eq!(key(KeyCode::Esc, KeyModifiers::NONE), Some(Key::Quit));
+        assert_eq!(
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(Key::Quit)
+        );
+        assert_eq!(key(KeyCode::Char('c'), KeyModifiers::NONE), Some(Key::Copy));
+
+        // F5 arrives as `ESC [ 1 5 ~`, which shouldn't type a 15
+        assert_eq!(key(KeyCode::F(5), KeyModifiers::NONE), None);
+        assert_eq!(key(KeyCode::Home, KeyModifiers::NONE), None);
+    }
+
+    #[test]
+    fn encodes_clipboard_text() {
+        assert_eq!(base64(b"hello"), "aGVsbG8=");
+        assert_eq!(base64(b"375"), "Mzc1");
+        assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn types_tempos_and_copies() {
+        let mut tui = Tui::new(&Settings::default());
+
+        press(&mut tui, "120\r");
+        assert_eq!(tui.session.tempo(), Some(120.0));
+
+        // The first row is the longest note, so move down to a quarter
+        let quarter = NOTE_VALUES
+            .iter()
+            .position(|note_value| *note_value == NoteValue::Quarter)
+            .unwrap();
+        for _ in 0..quarter {
+            tui.update(Key::Down);
+        }
+        assert_eq!(tui.update(Key::Enter), Some("500".to_string()));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| tui.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let (x, y) = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .find(|&(x, y)| {
+                // A space before it so this isn't the end of 1500 ms
+                let text: String = (x..buffer.area.width.min(x + 7))
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect();
+                text == " 500 ms"
+            })
+            .expect("the quarter note is on screen");
+        assert!(buffer[(x + 1, y)].modifier.contains(Modifier::REVERSED));
+
+        tui.update(Key::NextUnit);
+        assert_eq!(tui.update(Key::Copy), Some("2".to_string()));
+
+        // Unparseable tempos are dropped
+        press(&mut tui, "..\r");
+        assert_eq!(tui.session.tempo(), Some(120.0));
+    }
+}
@@ -0,0 +1,131 @@
+// Looks up the latest GitHub release once per launch, once the first launch has asked and the user
+// agreed. The app has no HTTPS client of its own, so the request goes through `curl`, which ships
+// with macOS, Windows 10 and later, and most Linux desktops; without it checking isn't offered. Any
+// failure just means no notice this time. The web build is always the latest version, so it never
+// checks.
+#![cfg_attr(target_arch = "wasm32", allow(dead_code))]
+
+use iced::Subscription;
+use serde::Deserialize;
+
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/JosephTLyons/delay_time_calculator/releases/latest";
+const TIMEOUT_SECONDS: &str = "10";
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    pub html_url: String,
+    // Markdown, shown as-is
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+// Version numbers compare part by part, so 0.10.0 is newer than 0.9.0. Tags that aren't version
+// numbers are never newer.
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+pub fn is_newer(tag_name: &str, current_version: &str) -> bool {
+    match (parse_version(tag_name), parse_version(current_version)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn is_available() -> bool {
+    std::process::Command::new("curl")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn is_available() -> bool {
+    false
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn fetch_latest_release() -> Result<Release, String> {
+    let output = std::process::Command::new("curl")
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--max-time",
+            TIMEOUT_SECONDS,
+            "--header",
+            "Accept: application/vnd.github+json",
+            // GitHub rejects API requests without one
+            "--user-agent",
+            env!("CARGO_PKG_NAME"),
+            LATEST_RELEASE_URL,
+        ])
+        .output()
+        .map_err(|error| format!("Couldn't run curl: {}", error))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|error| error.to_string())
+}
+
+// Reports once, then stays idle for as long as checking is on
+#[cfg(not(target_arch = "wasm32"))]
+pub fn check() -> Subscription<Result<Release, String>> {
+    use iced::futures::SinkExt;
+
+    Subscription::run_with_id(
+        "update-check",
+        iced::stream::channel(1, |mut output| async move {
+            let (sender, receiver) = iced::futures::channel::oneshot::channel();
+            std::thread::spawn(move || {
+                let _ = sender.send(fetch_latest_release());
+            });
+
+            if let Ok(result) = receiver.await {
+                let _ = output.send(result).await;
+            }
+
+            std::future::pending::<()>().await;
+        }),
+    )
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn check() -> Subscription<Result<Release, String>> {
+    Subscription::none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_versions() {
+        assert!(is_newer("v0.2.0", "0.1.0"));
+        assert!(is_newer("0.10.0", "0.9.3"));
+        assert!(!is_newer("v0.1.0", "0.1.0"));
+        assert!(!is_newer("v0.0.9", "0.1.0"));
+        assert!(!is_newer("nightly", "0.1.0"));
+    }
+
+    #[test]
+    fn reads_releases() {
+        let release: Release = serde_json::from_str(
+            r#"{"tag_name": "v0.2.0", "html_url": "https://example.com", "body": "Fixes", "draft": false}"#,
+        )
+        .unwrap();
+        assert_eq!(release.tag_name, "v0.2.0");
+        assert_eq!(release.body.as_deref(), Some("Fixes"));
+    }
+}